@@ -0,0 +1,440 @@
+//! Traceability coverage: gaps along source → thesis → requirement → implementation.
+//!
+//! Each metric counts the nodes it applies to and how many of them are
+//! covered, broken down by category and priority, and can be held to a
+//! minimum percentage through `coverage_thresholds` in config.yaml.
+
+use crate::graph::{ReverseIndex, lookup_node};
+use crate::types::{LatticeNode, NodeIndex, NodeMeta, NodeType, Resolution, Status};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// One link in the traceability chain that a node can be missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoverageMetric {
+    /// Theses with at least one supporting source.
+    ThesesSupported,
+    /// Requirements that derive from a thesis.
+    RequirementsDerived,
+    /// Requirements satisfied by at least one implementation.
+    RequirementsImplemented,
+    /// Implementations that bind at least one file.
+    ImplementationsWithFiles,
+    /// Requirements with acceptance tests.
+    RequirementsWithAcceptance,
+    /// Messages grounded in an active thesis.
+    MessagesGrounded,
+}
+
+impl CoverageMetric {
+    pub const ALL: [CoverageMetric; 6] = [
+        CoverageMetric::ThesesSupported,
+        CoverageMetric::RequirementsDerived,
+        CoverageMetric::RequirementsImplemented,
+        CoverageMetric::ImplementationsWithFiles,
+        CoverageMetric::RequirementsWithAcceptance,
+        CoverageMetric::MessagesGrounded,
+    ];
+
+    /// Key used in JSON output and in `coverage_thresholds`.
+    pub fn key(&self) -> &'static str {
+        match self {
+            CoverageMetric::ThesesSupported => "theses_supported",
+            CoverageMetric::RequirementsDerived => "requirements_derived",
+            CoverageMetric::RequirementsImplemented => "requirements_implemented",
+            CoverageMetric::ImplementationsWithFiles => "implementations_with_files",
+            CoverageMetric::RequirementsWithAcceptance => "requirements_with_acceptance",
+            CoverageMetric::MessagesGrounded => "messages_grounded",
+        }
+    }
+
+    /// Description of the gap, i.e. what an uncovered node lacks.
+    pub fn gap_label(&self) -> &'static str {
+        match self {
+            CoverageMetric::ThesesSupported => "Theses with no supporting source",
+            CoverageMetric::RequirementsDerived => "Requirements with no deriving thesis",
+            CoverageMetric::RequirementsImplemented => {
+                "Requirements with no satisfying implementation"
+            }
+            CoverageMetric::ImplementationsWithFiles => "Implementations with no files",
+            CoverageMetric::RequirementsWithAcceptance => "Requirements without acceptance tests",
+            CoverageMetric::MessagesGrounded => "Messages not grounded in an active thesis",
+        }
+    }
+
+    fn applies_to(&self) -> NodeType {
+        match self {
+            CoverageMetric::ThesesSupported => NodeType::Thesis,
+            CoverageMetric::RequirementsDerived
+            | CoverageMetric::RequirementsImplemented
+            | CoverageMetric::RequirementsWithAcceptance => NodeType::Requirement,
+            CoverageMetric::ImplementationsWithFiles => NodeType::Implementation,
+            CoverageMetric::MessagesGrounded => NodeType::Message,
+        }
+    }
+}
+
+impl fmt::Display for CoverageMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+impl FromStr for CoverageMetric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CoverageMetric::ALL
+            .into_iter()
+            .find(|m| m.key() == s)
+            .ok_or_else(|| {
+                let keys: Vec<_> = CoverageMetric::ALL.iter().map(|m| m.key()).collect();
+                format!(
+                    "Unknown coverage metric '{}' (expected one of: {})",
+                    s,
+                    keys.join(", ")
+                )
+            })
+    }
+}
+
+/// Covered out of total for one slice of a metric.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Tally {
+    pub total: usize,
+    pub covered: usize,
+}
+
+impl Tally {
+    /// Percentage covered; 100 when there is nothing to cover.
+    pub fn percent(&self) -> f64 {
+        if self.total == 0 {
+            100.0
+        } else {
+            self.covered as f64 * 100.0 / self.total as f64
+        }
+    }
+}
+
+/// Coverage for a single metric.
+#[derive(Debug, Clone)]
+pub struct MetricCoverage {
+    pub metric: CoverageMetric,
+    pub tally: Tally,
+    /// IDs of uncovered nodes, sorted.
+    pub gaps: Vec<String>,
+    pub by_category: BTreeMap<String, Tally>,
+    pub by_priority: BTreeMap<String, Tally>,
+    /// Minimum percentage from config, if one is set.
+    pub threshold: Option<f64>,
+}
+
+impl MetricCoverage {
+    pub fn passes(&self) -> bool {
+        self.threshold.is_none_or(|t| self.tally.percent() >= t)
+    }
+}
+
+/// Coverage across every metric.
+#[derive(Debug, Clone)]
+pub struct CoverageReport {
+    pub metrics: Vec<MetricCoverage>,
+}
+
+impl CoverageReport {
+    /// Metrics below their configured threshold.
+    pub fn failures(&self) -> Vec<&MetricCoverage> {
+        self.metrics.iter().filter(|m| !m.passes()).collect()
+    }
+}
+
+/// Parse `coverage_thresholds` from config, rejecting unknown metric names.
+pub fn parse_thresholds(
+    raw: &BTreeMap<String, f64>,
+) -> Result<BTreeMap<CoverageMetric, f64>, String> {
+    raw.iter()
+        .map(|(key, pct)| Ok((key.parse::<CoverageMetric>()?, *pct)))
+        .collect()
+}
+
+/// Compute coverage for every metric. Deprecated and superseded nodes, and
+/// requirements resolved as wontfix, are left out.
+pub fn compute_coverage(
+    index: &NodeIndex,
+    reverse: &ReverseIndex,
+    thresholds: &BTreeMap<CoverageMetric, f64>,
+) -> CoverageReport {
+    let mut nodes: Vec<&LatticeNode> = index
+        .values()
+        .filter(|n| !matches!(n.status, Status::Deprecated | Status::Superseded))
+        .filter(|n| {
+            !matches!(
+                n.resolution.as_ref().map(|r| &r.status),
+                Some(Resolution::Wontfix)
+            )
+        })
+        .collect();
+    nodes.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let metrics = CoverageMetric::ALL
+        .into_iter()
+        .map(|metric| {
+            let mut coverage = MetricCoverage {
+                metric,
+                tally: Tally::default(),
+                gaps: Vec::new(),
+                by_category: BTreeMap::new(),
+                by_priority: BTreeMap::new(),
+                threshold: thresholds.get(&metric).copied(),
+            };
+            for node in nodes.iter().filter(|n| n.node_type == metric.applies_to()) {
+                let covered = is_covered(metric, node, index, reverse);
+                let category = category_of(node);
+                let priority = node
+                    .priority
+                    .as_ref()
+                    .map_or_else(|| "none".to_string(), |p| format!("{:?}", p));
+                for tally in [
+                    &mut coverage.tally,
+                    coverage.by_category.entry(category).or_default(),
+                    coverage.by_priority.entry(priority).or_default(),
+                ] {
+                    tally.total += 1;
+                    if covered {
+                        tally.covered += 1;
+                    }
+                }
+                if !covered {
+                    coverage.gaps.push(node.id.clone());
+                }
+            }
+            coverage
+        })
+        .collect();
+
+    CoverageReport { metrics }
+}
+
+fn is_covered(
+    metric: CoverageMetric,
+    node: &LatticeNode,
+    index: &NodeIndex,
+    reverse: &ReverseIndex,
+) -> bool {
+    let edges = node.edges.as_ref();
+    let targets_type = |refs: Option<&Vec<crate::types::EdgeReference>>, ty: NodeType| {
+        refs.into_iter().flatten().any(|e| {
+            lookup_node(index, &e.target).is_some_and(|t| {
+                t.node_type == ty && !matches!(t.status, Status::Deprecated | Status::Superseded)
+            })
+        })
+    };
+    match metric {
+        CoverageMetric::ThesesSupported => targets_type(
+            edges.and_then(|e| e.supported_by.as_ref()),
+            NodeType::Source,
+        ),
+        CoverageMetric::RequirementsDerived => targets_type(
+            edges.and_then(|e| e.derives_from.as_ref()),
+            NodeType::Thesis,
+        ),
+        CoverageMetric::RequirementsImplemented => {
+            reverse.get(&node.id).into_iter().flatten().any(|r| {
+                r.edge_type == "satisfies"
+                    && r.from_type == "implementation"
+                    && index.get(&r.from_id).is_some_and(|imp| {
+                        !matches!(imp.status, Status::Deprecated | Status::Superseded)
+                    })
+            })
+        }
+        CoverageMetric::ImplementationsWithFiles => matches!(
+            &node.meta,
+            Some(NodeMeta::Implementation(meta))
+                if meta.files.as_ref().is_some_and(|f| !f.is_empty())
+        ),
+        CoverageMetric::RequirementsWithAcceptance => {
+            node.acceptance.as_ref().is_some_and(|a| !a.is_empty())
+        }
+        CoverageMetric::MessagesGrounded => edges
+            .and_then(|e| e.grounded_in.as_ref())
+            .into_iter()
+            .flatten()
+            .any(|e| {
+                lookup_node(index, &e.target)
+                    .is_some_and(|t| t.node_type == NodeType::Thesis && t.status == Status::Active)
+            }),
+    }
+}
+
+/// Category used to group a node: its own category, a thesis's category from
+/// its metadata, or "uncategorized".
+fn category_of(node: &LatticeNode) -> String {
+    if let Some(category) = &node.category {
+        return category.clone();
+    }
+    if let Some(NodeMeta::Thesis(meta)) = &node.meta
+        && let Ok(serde_json::Value::String(s)) = serde_json::to_value(&meta.category)
+    {
+        return s;
+    }
+    "uncategorized".to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::build_reverse_index;
+    use crate::types::{
+        AcceptanceTest, EdgeReference, Edges, FileRef, ImplementationMeta, Priority,
+        ResolutionInfo, ThesisCategory, ThesisMeta,
+    };
+
+    fn fixture() -> NodeIndex {
+        let mut index = NodeIndex::new();
+        index.insert(
+            "SRC-A".into(),
+            LatticeNode::test_node("SRC-A", NodeType::Source),
+        );
+
+        let mut thesis = LatticeNode::test_node("THX-A", NodeType::Thesis);
+        thesis.meta = Some(NodeMeta::Thesis(ThesisMeta {
+            category: ThesisCategory::ValueProp,
+            confidence: None,
+            confidence_history: Vec::new(),
+            last_researched: None,
+            research_scope: None,
+            agent_directive: None,
+        }));
+        thesis.edges = Some(Edges {
+            supported_by: Some(vec![EdgeReference::test_edge("SRC-A", "1.0.0")]),
+            ..Default::default()
+        });
+        index.insert("THX-A".into(), thesis);
+        let mut contested = LatticeNode::test_node("THX-B", NodeType::Thesis);
+        contested.status = Status::Contested;
+        index.insert("THX-B".into(), contested);
+
+        let mut req_a = LatticeNode::test_node("REQ-A", NodeType::Requirement);
+        req_a.category = Some("CORE".into());
+        req_a.priority = Some(Priority::P0);
+        req_a.edges = Some(Edges {
+            derives_from: Some(vec![EdgeReference::test_edge("THX-A", "1.0.0")]),
+            ..Default::default()
+        });
+        req_a.acceptance = Some(vec![AcceptanceTest {
+            id: "T1".into(),
+            given: "g".into(),
+            when: "w".into(),
+            then: "t".into(),
+            verification: None,
+        }]);
+        index.insert("REQ-A".into(), req_a);
+        let mut req_b = LatticeNode::test_node("REQ-B", NodeType::Requirement);
+        req_b.category = Some("CLI".into());
+        req_b.priority = Some(Priority::P1);
+        index.insert("REQ-B".into(), req_b);
+        let mut dropped = LatticeNode::test_node("REQ-C", NodeType::Requirement);
+        dropped.resolution = Some(ResolutionInfo {
+            status: Resolution::Wontfix,
+            reason: None,
+            resolved_at: "2026-01-01T00:00:00Z".into(),
+            resolved_by: "test".into(),
+        });
+        index.insert("REQ-C".into(), dropped);
+
+        let mut imp = LatticeNode::test_node("IMP-A", NodeType::Implementation);
+        imp.meta = Some(NodeMeta::Implementation(ImplementationMeta {
+            language: None,
+            files: Some(vec![FileRef {
+                path: "src/a.rs".into(),
+                functions: None,
+            }]),
+            test_command: None,
+        }));
+        imp.edges = Some(Edges {
+            satisfies: Some(vec![EdgeReference::test_edge("REQ-A", "1.0.0")]),
+            ..Default::default()
+        });
+        index.insert("IMP-A".into(), imp);
+        let mut retired = LatticeNode::test_node("IMP-B", NodeType::Implementation);
+        retired.status = Status::Deprecated;
+        retired.edges = Some(Edges {
+            satisfies: Some(vec![EdgeReference::test_edge("REQ-B", "1.0.0")]),
+            ..Default::default()
+        });
+        index.insert("IMP-B".into(), retired);
+
+        let mut msg = LatticeNode::test_node("MSG-A", NodeType::Message);
+        msg.edges = Some(Edges {
+            grounded_in: Some(vec![EdgeReference::test_edge("THX-B", "1.0.0")]),
+            ..Default::default()
+        });
+        index.insert("MSG-A".into(), msg);
+        index
+    }
+
+    #[test]
+    fn test_compute_coverage_counts_gaps() {
+        let index = fixture();
+        let report = compute_coverage(&index, &build_reverse_index(&index), &BTreeMap::new());
+        let get = |m: CoverageMetric| report.metrics.iter().find(|c| c.metric == m).unwrap();
+
+        let theses = get(CoverageMetric::ThesesSupported);
+        assert_eq!(
+            theses.tally,
+            Tally {
+                total: 2,
+                covered: 1
+            }
+        );
+        assert_eq!(theses.gaps, vec!["THX-B"]);
+        assert_eq!(theses.by_category["value_prop"].covered, 1);
+
+        let implemented = get(CoverageMetric::RequirementsImplemented);
+        assert_eq!(
+            implemented.tally,
+            Tally {
+                total: 2,
+                covered: 1
+            }
+        );
+        // REQ-B is only satisfied by a deprecated implementation
+        assert_eq!(implemented.gaps, vec!["REQ-B"]);
+        assert_eq!(implemented.by_priority["P0"].percent(), 100.0);
+        assert_eq!(implemented.by_category["CLI"].percent(), 0.0);
+
+        assert_eq!(get(CoverageMetric::RequirementsDerived).gaps, vec!["REQ-B"]);
+        assert_eq!(
+            get(CoverageMetric::RequirementsWithAcceptance).gaps,
+            vec!["REQ-B"]
+        );
+        assert!(
+            get(CoverageMetric::ImplementationsWithFiles)
+                .gaps
+                .is_empty()
+        );
+        // Grounded only in a contested thesis
+        assert_eq!(get(CoverageMetric::MessagesGrounded).gaps, vec!["MSG-A"]);
+    }
+
+    #[test]
+    fn test_thresholds_drive_failures() {
+        let index = fixture();
+        let reverse = build_reverse_index(&index);
+        let thresholds = parse_thresholds(&BTreeMap::from([
+            ("requirements_implemented".to_string(), 50.0),
+            ("messages_grounded".to_string(), 10.0),
+        ]))
+        .unwrap();
+        let report = compute_coverage(&index, &reverse, &thresholds);
+        let failed: Vec<_> = report.failures().iter().map(|m| m.metric).collect();
+        assert_eq!(failed, vec![CoverageMetric::MessagesGrounded]);
+
+        let err = parse_thresholds(&BTreeMap::from([("nope".to_string(), 1.0)])).unwrap_err();
+        assert!(err.contains("Unknown coverage metric 'nope'"));
+        assert_eq!(Tally::default().percent(), 100.0);
+    }
+}
@@ -2,8 +2,12 @@
 //!
 //! Linked requirements: REQ-CLI-006
 
-use crate::storage::load_node;
-use crate::types::{LatticeNode, NodeType};
+use crate::graph::{DriftExplanation, DriftReport};
+use crate::search::cache_dir;
+use crate::storage::{ARCHIVE_DIR, LATTICE_DIR, find_node_path, load_node};
+use crate::types::{AcceptanceTest, LatticeNode, NodeType};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use thiserror::Error;
@@ -106,8 +110,9 @@ pub fn compute_changed_fields(old: &LatticeNode, new: &LatticeNode) -> Vec<Strin
 }
 
 /// Compute the merge-base between HEAD and the given ref.
-fn git_merge_base(base_ref: &str) -> Result<String, DiffError> {
+fn git_merge_base(repo_dir: &Path, base_ref: &str) -> Result<String, DiffError> {
     let output = Command::new("git")
+        .current_dir(repo_dir)
         .args(["merge-base", "HEAD", base_ref])
         .output()
         .map_err(|e| DiffError::GitError(format!("failed to run git merge-base: {}", e)))?;
@@ -123,18 +128,21 @@ fn git_merge_base(base_ref: &str) -> Result<String, DiffError> {
     Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
 }
 
-/// Run `git diff --name-status <ref> -- .lattice/` to find changed files.
+/// Run `git diff --name-status <ref> -- .lattice/` to find changed files,
+/// with paths relative to the lattice root.
 fn git_diff_name_status(
+    lattice_root: &Path,
     since_ref: &str,
-    lattice_dir: &Path,
-) -> Result<Vec<(String, PathBuf)>, DiffError> {
+) -> Result<Vec<(String, PathBuf, PathBuf)>, DiffError> {
     let output = Command::new("git")
+        .current_dir(lattice_root)
         .args([
             "diff",
             "--name-status",
+            "--relative",
             since_ref,
             "--",
-            &lattice_dir.to_string_lossy(),
+            LATTICE_DIR,
         ])
         .output()
         .map_err(|e| DiffError::GitError(format!("failed to run git diff: {}", e)))?;
@@ -147,7 +155,13 @@ fn git_diff_name_status(
         )));
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_name_status(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Parse `git diff --name-status` output into (change, path, old path)
+/// triples for node files. The old path differs from the path only for
+/// renames, which count as modifications of the node at the old path.
+fn parse_name_status(stdout: &str) -> Vec<(String, PathBuf, PathBuf)> {
     let mut results = Vec::new();
 
     for line in stdout.lines() {
@@ -164,27 +178,43 @@ fn git_diff_name_status(
         }
 
         let status = parts[0];
-        let path = PathBuf::from(parts[parts.len() - 1]); // Use last path (handles renames)
+        let old_path = PathBuf::from(parts[1]);
+        let mut path = PathBuf::from(parts[parts.len() - 1]); // Use last path (handles renames)
+
+        // A node moved into the archive has left the graph: report it as deleted
+        let archived = status.starts_with('R') && is_archived_file(&path);
+        if archived {
+            path = old_path.clone();
+        }
 
         // Only consider YAML files in node type directories
         if !is_node_file(&path) {
             continue;
         }
 
-        let change = if status.starts_with('A') || status.starts_with('R') {
-            "A".to_string()
+        let change = if archived || status.starts_with('D') {
+            "D"
+        } else if status.starts_with('A') {
+            "A"
         } else if status.starts_with('M') {
-            "M".to_string()
-        } else if status.starts_with('D') {
-            "D".to_string()
+            "M"
+        } else if status.starts_with('R') {
+            // Moved into place from outside the graph: new to the graph
+            if is_node_file(&old_path) { "M" } else { "A" }
         } else {
             continue;
         };
 
-        results.push((change, path));
+        results.push((change.to_string(), path, old_path));
     }
 
-    Ok(results)
+    results
+}
+
+/// Check if a path lives under `.lattice/archive/`.
+fn is_archived_file(path: &Path) -> bool {
+    path.to_string_lossy()
+        .contains(&format!("{}/{}/", LATTICE_DIR, ARCHIVE_DIR))
 }
 
 /// Check if a path is a lattice node YAML file (in sources/, theses/, requirements/, implementations/).
@@ -195,6 +225,11 @@ fn is_node_file(path: &Path) -> bool {
         return false;
     }
 
+    // Archived nodes are no longer part of the graph
+    if is_archived_file(path) {
+        return false;
+    }
+
     // Must be inside a node type directory
     for dir in &["sources/", "theses/", "requirements/", "implementations/"] {
         if path_str.contains(dir) {
@@ -224,8 +259,16 @@ fn node_to_entry(node: &LatticeNode, change_type: ChangeType) -> DiffEntry {
 }
 
 /// Get the content of a file at a specific git ref.
-fn git_show_at_ref(git_ref: &str, path: &Path) -> Result<Option<String>, DiffError> {
+///
+/// `path` is relative to the repository root; `repo_dir` is any directory
+/// inside the repository.
+fn git_show_at_ref(
+    repo_dir: &Path,
+    git_ref: &str,
+    path: &Path,
+) -> Result<Option<String>, DiffError> {
     let output = Command::new("git")
+        .current_dir(repo_dir)
         .args(["show", &format!("{}:{}", git_ref, path.to_string_lossy())])
         .output()
         .map_err(|e| DiffError::GitError(format!("failed to run git show: {}", e)))?;
@@ -238,6 +281,103 @@ fn git_show_at_ref(git_ref: &str, path: &Path) -> Result<Option<String>, DiffErr
     Ok(Some(String::from_utf8_lossy(&output.stdout).to_string()))
 }
 
+/// How many `--at` snapshots to keep in the cache before evicting the least
+/// recently used.
+pub const AT_REF_SNAPSHOTS_KEPT: usize = 8;
+
+/// Materialize the `.lattice/` tree as it was at a git ref and return a root
+/// directory that can be passed to any loader in place of the working tree.
+///
+/// The tree is read straight from the object database with `git archive`, so
+/// nothing is checked out. Snapshots are keyed by commit SHA under the
+/// project cache directory and reused on later calls; only the
+/// [`AT_REF_SNAPSHOTS_KEPT`] most recently used are kept.
+pub fn lattice_at_ref(lattice_root: &Path, git_ref: &str) -> Result<PathBuf, DiffError> {
+    let output = Command::new("git")
+        .current_dir(lattice_root)
+        .args(["rev-parse", "--verify", &format!("{}^{{commit}}", git_ref)])
+        .output()
+        .map_err(|e| DiffError::GitError(format!("failed to run git rev-parse: {}", e)))?;
+    if !output.status.success() {
+        return Err(DiffError::GitError(format!("unknown git ref: {}", git_ref)));
+    }
+    let commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    let snapshots = cache_dir(lattice_root)
+        .unwrap_or_else(|_| std::env::temp_dir().join("lattice"))
+        .join("at");
+    let snapshot = snapshots.join(&commit);
+    if snapshot.join(LATTICE_DIR).is_dir() {
+        touch(&snapshot);
+        return Ok(snapshot);
+    }
+
+    let output = Command::new("git")
+        .current_dir(lattice_root)
+        .args([
+            "archive",
+            "--format=tar",
+            &format!("{}:./{}", commit, LATTICE_DIR),
+        ])
+        .output()
+        .map_err(|e| DiffError::GitError(format!("failed to run git archive: {}", e)))?;
+    if !output.status.success() {
+        return Err(DiffError::GitError(format!(
+            "no {}/ directory at {}",
+            LATTICE_DIR, git_ref
+        )));
+    }
+
+    // Unpack into a staging directory, then rename so readers never see a partial tree
+    let staging = snapshots.join(format!("{}.{}.tmp", commit, std::process::id()));
+    let _ = fs::remove_dir_all(&staging);
+    if let Err(e) = tar::Archive::new(output.stdout.as_slice()).unpack(staging.join(LATTICE_DIR)) {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(DiffError::LoadError(format!(
+            "failed to unpack {}: {}",
+            git_ref, e
+        )));
+    }
+    if fs::rename(&staging, &snapshot).is_err() {
+        // Another process finished the same snapshot first
+        let _ = fs::remove_dir_all(&staging);
+        if !snapshot.join(LATTICE_DIR).is_dir() {
+            return Err(DiffError::LoadError(format!(
+                "failed to write snapshot {}",
+                snapshot.display()
+            )));
+        }
+    }
+    touch(&snapshot);
+    prune_snapshots(&snapshots, AT_REF_SNAPSHOTS_KEPT);
+
+    Ok(snapshot)
+}
+
+/// Mark a snapshot as just used, for least-recently-used eviction.
+fn touch(snapshot: &Path) {
+    if let Ok(dir) = fs::File::open(snapshot) {
+        let _ = dir.set_modified(std::time::SystemTime::now());
+    }
+}
+
+/// Delete all but the `keep` most recently used snapshots in `dir`. Staging
+/// directories of in-flight snapshots are left alone.
+fn prune_snapshots(dir: &Path, keep: usize) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut snapshots: Vec<(std::time::SystemTime, PathBuf)> = entries
+        .flatten()
+        .filter(|e| !e.file_name().to_string_lossy().ends_with(".tmp"))
+        .filter_map(|e| Some((e.metadata().ok()?.modified().ok()?, e.path())))
+        .collect();
+    snapshots.sort_by_key(|s| std::cmp::Reverse(s.0));
+    for (_, path) in snapshots.into_iter().skip(keep) {
+        let _ = fs::remove_dir_all(path);
+    }
+}
+
 /// Parse a YAML string into a LatticeNode.
 fn parse_node_yaml(yaml: &str) -> Result<LatticeNode, DiffError> {
     serde_yaml::from_str(yaml)
@@ -245,11 +385,11 @@ fn parse_node_yaml(yaml: &str) -> Result<LatticeNode, DiffError> {
 }
 
 /// Resolve the base git ref, falling back to merge-base with main/master.
-fn resolve_base_ref(since: Option<&str>) -> Result<String, DiffError> {
+fn resolve_base_ref(lattice_root: &Path, since: Option<&str>) -> Result<String, DiffError> {
     match since {
         Some(r) => Ok(r.to_string()),
-        None => git_merge_base("main")
-            .or_else(|_| git_merge_base("master"))
+        None => git_merge_base(lattice_root, "main")
+            .or_else(|_| git_merge_base(lattice_root, "master"))
             .map_err(|_| {
                 DiffError::GitError("could not find merge-base with main or master".to_string())
             }),
@@ -260,17 +400,20 @@ fn resolve_base_ref(since: Option<&str>) -> Result<String, DiffError> {
 ///
 /// If `since` is None, defaults to merge-base with `main`.
 pub fn lattice_diff(lattice_root: &Path, since: Option<&str>) -> Result<DiffResult, DiffError> {
-    let lattice_dir = lattice_root.join(".lattice");
-    let base_ref = resolve_base_ref(since)?;
+    let base_ref = resolve_base_ref(lattice_root, since)?;
 
-    let changes = git_diff_name_status(&base_ref, &lattice_dir)?;
+    let changes = git_diff_name_status(lattice_root, &base_ref)?;
 
     let mut added = Vec::new();
     let mut modified = Vec::new();
     let mut resolved = Vec::new();
     let mut deleted = Vec::new();
 
-    for (status, path) in &changes {
+    for (status, rel, old_rel) in &changes {
+        let path = &lattice_root.join(rel);
+        // `<ref>:./<path>` is read relative to the lattice root; a renamed
+        // node is read from where it used to be
+        let at_base = Path::new(".").join(old_rel);
         match status.as_str() {
             "A" => {
                 // Added: load current file
@@ -286,7 +429,7 @@ pub fn lattice_diff(lattice_root: &Path, since: Option<&str>) -> Result<DiffResu
             "M" => {
                 // Modified: load current file, check if newly resolved
                 if let Ok(node) = load_node(path) {
-                    let old_node = git_show_at_ref(&base_ref, path)
+                    let old_node = git_show_at_ref(lattice_root, &base_ref, &at_base)
                         .ok()
                         .flatten()
                         .and_then(|yaml| parse_node_yaml(&yaml).ok());
@@ -312,7 +455,7 @@ pub fn lattice_diff(lattice_root: &Path, since: Option<&str>) -> Result<DiffResu
             }
             "D" => {
                 // Deleted: try to load from old ref
-                if let Ok(Some(old_yaml)) = git_show_at_ref(&base_ref, path)
+                if let Ok(Some(old_yaml)) = git_show_at_ref(lattice_root, &base_ref, &at_base)
                     && let Ok(node) = parse_node_yaml(&old_yaml)
                 {
                     deleted.push(node_to_entry(&node, ChangeType::Deleted));
@@ -337,6 +480,254 @@ pub fn lattice_diff(lattice_root: &Path, since: Option<&str>) -> Result<DiffResu
     })
 }
 
+/// IDs of nodes added, modified or resolved since a git ref (as in
+/// `lattice_diff`), plus node files git does not track yet.
+pub fn changed_node_ids(
+    lattice_root: &Path,
+    since: Option<&str>,
+) -> Result<BTreeSet<String>, DiffError> {
+    let diff = lattice_diff(lattice_root, since)?;
+    let mut ids: BTreeSet<String> = diff
+        .added
+        .iter()
+        .chain(&diff.modified)
+        .chain(&diff.resolved)
+        .map(|e| e.id.clone())
+        .collect();
+
+    let output = Command::new("git")
+        .current_dir(lattice_root)
+        .args([
+            "ls-files",
+            "--others",
+            "--exclude-standard",
+            "--",
+            LATTICE_DIR,
+        ])
+        .output()
+        .map_err(|e| DiffError::GitError(format!("failed to run git ls-files: {}", e)))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(DiffError::GitError(format!(
+            "git ls-files failed: {}",
+            stderr.trim()
+        )));
+    }
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let path = Path::new(line.trim());
+        if is_node_file(path)
+            && let Ok(node) = load_node(&lattice_root.join(path))
+        {
+            ids.insert(node.id);
+        }
+    }
+    Ok(ids)
+}
+
+/// One committed revision of a node's YAML file.
+#[derive(Debug, Clone)]
+pub struct NodeRevision {
+    pub commit: String,
+    pub author: String,
+    /// Commit date (ISO 8601).
+    pub date: String,
+    pub subject: String,
+    /// The node as it was at this commit.
+    pub node: LatticeNode,
+    /// Fields changed relative to the previous revision (empty for the first).
+    pub fields: Vec<String>,
+}
+
+/// Walk the git history of a node's file, newest first.
+///
+/// Follows file renames, so history survives `lattice rename` and category
+/// moves. Commits where the file does not parse as a node are skipped.
+pub fn node_history(lattice_root: &Path, node_id: &str) -> Result<Vec<NodeRevision>, DiffError> {
+    let path =
+        find_node_path(lattice_root, node_id).map_err(|e| DiffError::LoadError(e.to_string()))?;
+
+    // Each commit prints a header line followed by the file's path at that commit
+    let output = Command::new("git")
+        .current_dir(lattice_root)
+        .args([
+            "log",
+            "--follow",
+            "--name-only",
+            "--format=%x1e%H%x1f%an%x1f%aI%x1f%s",
+            "--",
+        ])
+        .arg(&path)
+        .output()
+        .map_err(|e| DiffError::GitError(format!("failed to run git log: {}", e)))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(DiffError::GitError(format!(
+            "git log failed: {}",
+            stderr.trim()
+        )));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut revisions: Vec<NodeRevision> = Vec::new();
+    for record in stdout.split('\x1e').filter(|r| !r.trim().is_empty()) {
+        let mut lines = record.lines();
+        let header: Vec<&str> = lines.next().unwrap_or_default().split('\x1f').collect();
+        let Some(file) = lines.map(str::trim).find(|l| !l.is_empty()) else {
+            continue;
+        };
+        if header.len() < 4 {
+            return Err(DiffError::ParseError(format!(
+                "unexpected git log header: {}",
+                header.join(" ")
+            )));
+        }
+
+        let Some(node) = git_show_at_ref(lattice_root, header[0], Path::new(file))?
+            .and_then(|yaml| parse_node_yaml(&yaml).ok())
+        else {
+            continue;
+        };
+
+        revisions.push(NodeRevision {
+            commit: header[0].to_string(),
+            author: header[1].to_string(),
+            date: header[2].to_string(),
+            subject: header[3].to_string(),
+            node,
+            fields: Vec::new(),
+        });
+    }
+
+    // Newest first: each revision is compared against the one after it
+    for i in 0..revisions.len().saturating_sub(1) {
+        let fields = compute_changed_fields(&revisions[i + 1].node, &revisions[i].node);
+        revisions[i].fields = fields;
+    }
+
+    Ok(revisions)
+}
+
+/// Find the node as it last stood at a given version in its history.
+pub fn node_at_version<'a>(
+    revisions: &'a [NodeRevision],
+    version: &str,
+) -> Option<&'a NodeRevision> {
+    revisions.iter().find(|r| r.node.version == version)
+}
+
+/// Line-level diff between two texts. Each output line is prefixed with
+/// `-` (removed), `+` (added) or ` ` (unchanged).
+pub fn line_diff(old: &str, new: &str) -> Vec<String> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+
+    // Longest common subsequence table, filled from the end
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            out.push(format!(" {}", a[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            out.push(format!("-{}", a[i]));
+            i += 1;
+        } else {
+            out.push(format!("+{}", b[j]));
+            j += 1;
+        }
+    }
+    out.extend(a[i..].iter().map(|l| format!("-{}", l)));
+    out.extend(b[j..].iter().map(|l| format!("+{}", l)));
+    out
+}
+
+/// Render acceptance tests one per line for diffing.
+fn acceptance_text(tests: &[AcceptanceTest]) -> String {
+    tests
+        .iter()
+        .map(|t| {
+            format!(
+                "{}: given {}; when {}; then {}",
+                t.id, t.given, t.when, t.then
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Build the explanation for one target: its content at `bound_version`
+/// (from git history) against its current content.
+fn explain_target(
+    revisions: &[NodeRevision],
+    bound_version: &str,
+    current: &LatticeNode,
+) -> Option<DriftExplanation> {
+    let bound = node_at_version(revisions, bound_version)?;
+    let old = &bound.node;
+    let old_acceptance = old.acceptance.clone().unwrap_or_default();
+    let new_acceptance = current.acceptance.clone().unwrap_or_default();
+
+    let mut diffs = BTreeMap::new();
+    for (field, before, after) in [
+        ("title", old.title.clone(), current.title.clone()),
+        ("body", old.body.clone(), current.body.clone()),
+        (
+            "acceptance",
+            acceptance_text(&old_acceptance),
+            acceptance_text(&new_acceptance),
+        ),
+    ] {
+        if before != after {
+            diffs.insert(field.to_string(), line_diff(&before, &after));
+        }
+    }
+
+    Some(DriftExplanation {
+        bound_commit: bound.commit.clone(),
+        changed_fields: compute_changed_fields(old, current),
+        old_title: old.title.clone(),
+        new_title: current.title.clone(),
+        old_body: old.body.clone(),
+        new_body: current.body.clone(),
+        old_acceptance,
+        new_acceptance,
+        diffs,
+    })
+}
+
+/// Attach a `DriftExplanation` to every drift item whose bound version can
+/// be found in the git history of the target's file. Items whose bound
+/// version was never committed (or outside a git repository) are left as-is.
+pub fn explain_drift(lattice_root: &Path, reports: &mut [DriftReport]) {
+    let mut targets: HashMap<String, Option<(LatticeNode, Vec<NodeRevision>)>> = HashMap::new();
+
+    for item in reports.iter_mut().flat_map(|r| r.drift_items.iter_mut()) {
+        let target = targets.entry(item.target_id.clone()).or_insert_with(|| {
+            let current = find_node_path(lattice_root, &item.target_id)
+                .and_then(|p| load_node(&p))
+                .ok()?;
+            let revisions = node_history(lattice_root, &item.target_id).ok()?;
+            Some((current, revisions))
+        });
+        if let Some((current, revisions)) = target {
+            item.explanation = explain_target(revisions, &item.bound_version, current);
+        }
+    }
+}
+
 /// Format a DiffEntry as a display line.
 fn format_entry(entry: &DiffEntry) -> String {
     let mut parts = vec![format!("{}: {}", entry.id, entry.title)];
@@ -405,14 +796,14 @@ pub fn format_entry_text(entry: &DiffEntry) -> String {
 
 /// Produce a raw git diff of `.lattice/` files since a given ref.
 pub fn git_diff_raw(lattice_root: &Path, since: Option<&str>) -> Result<String, DiffError> {
-    let lattice_dir = lattice_root.join(".lattice");
-    let base_ref = resolve_base_ref(since)?;
+    let base_ref = resolve_base_ref(lattice_root, since)?;
 
     let output = Command::new("git")
+        .current_dir(lattice_root)
         .arg("diff")
         .arg(&base_ref)
         .arg("--")
-        .arg(&lattice_dir)
+        .arg(LATTICE_DIR)
         .output()
         .map_err(|e| DiffError::GitError(format!("failed to run git diff: {}", e)))?;
 
@@ -448,6 +839,77 @@ mod tests {
         assert!(!is_node_file(Path::new(
             ".lattice/requirements/cli/001.txt"
         )));
+        assert!(!is_node_file(Path::new(
+            ".lattice/archive/requirements/cli/001-init.yaml"
+        )));
+    }
+
+    #[test]
+    fn test_parse_name_status_renames() {
+        let out = "M\t.lattice/requirements/cli/001-init.yaml\n\
+                   R087\t.lattice/requirements/cli/002-old.yaml\t.lattice/requirements/cli/002-new.yaml\n\
+                   R100\t.lattice/requirements/cli/003-gone.yaml\t.lattice/archive/requirements/cli/003-gone.yaml\n\
+                   R100\t.lattice/archive/requirements/cli/004-back.yaml\t.lattice/requirements/cli/004-back.yaml\n\
+                   A\t.lattice/config.yaml\n";
+        let changes: Vec<(String, String, String)> = parse_name_status(out)
+            .into_iter()
+            .map(|(c, p, o)| {
+                (
+                    c,
+                    p.to_string_lossy().to_string(),
+                    o.to_string_lossy().to_string(),
+                )
+            })
+            .collect();
+        let row = |c: &str, p: &str, o: &str| (c.to_string(), p.to_string(), o.to_string());
+        assert_eq!(
+            changes,
+            vec![
+                row(
+                    "M",
+                    ".lattice/requirements/cli/001-init.yaml",
+                    ".lattice/requirements/cli/001-init.yaml"
+                ),
+                row(
+                    "M",
+                    ".lattice/requirements/cli/002-new.yaml",
+                    ".lattice/requirements/cli/002-old.yaml"
+                ),
+                row(
+                    "D",
+                    ".lattice/requirements/cli/003-gone.yaml",
+                    ".lattice/requirements/cli/003-gone.yaml"
+                ),
+                row(
+                    "A",
+                    ".lattice/requirements/cli/004-back.yaml",
+                    ".lattice/archive/requirements/cli/004-back.yaml"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lattice_diff_reports_renamed_node_as_modified() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let root = tmp.path();
+        init_log_lattice(root);
+        let dir = root.join(LATTICE_DIR).join("requirements/log");
+        let old = dir.join("001-logged.yaml");
+        let new = dir.join("001-renamed.yaml");
+        let yaml = fs::read_to_string(&old)
+            .unwrap()
+            .replace("title: Logged", "title: Renamed");
+        fs::remove_file(&old).unwrap();
+        fs::write(&new, yaml).unwrap();
+        git(root, &["add", "-A"]);
+        git(root, &["commit", "-q", "-m", "Rename"]);
+
+        let diff = lattice_diff(root, Some("HEAD~1")).unwrap();
+        assert!(diff.added.is_empty() && diff.deleted.is_empty());
+        assert_eq!(diff.modified.len(), 1);
+        assert_eq!(diff.modified[0].id, "REQ-LOG-001");
+        assert_eq!(diff.modified[0].fields, Some(vec!["title".to_string()]));
     }
 
     #[test]
@@ -619,23 +1081,9 @@ mod tests {
 
     fn make_test_node(id: &str, title: &str) -> LatticeNode {
         LatticeNode {
-            id: id.to_string(),
-            node_type: NodeType::Requirement,
             title: title.to_string(),
             body: "test body".to_string(),
-            status: crate::types::Status::Active,
-            version: "1.0.0".to_string(),
-            created_at: "2025-01-01".to_string(),
-            created_by: "test".to_string(),
-            requested_by: None,
-            priority: None,
-            category: None,
-            tags: None,
-            acceptance: None,
-            visibility: None,
-            resolution: None,
-            meta: None,
-            edges: None,
+            ..LatticeNode::test_node(id, NodeType::Requirement)
         }
     }
 
@@ -682,4 +1130,285 @@ mod tests {
             Some(vec!["title".to_string(), "body".to_string()])
         );
     }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .current_dir(dir)
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    /// Create a git repo with one committed requirement, REQ-LOG-001 v1.0.0.
+    fn init_log_lattice(root: &Path) {
+        use crate::storage::{AddRequirementOptions, add_requirement, init_lattice};
+
+        git(root, &["init", "-q"]);
+        init_lattice(root, false).unwrap();
+        add_requirement(
+            root,
+            AddRequirementOptions {
+                id: "REQ-LOG-001".to_string(),
+                title: "Logged".to_string(),
+                body: "First".to_string(),
+                priority: crate::types::Priority::P1,
+                estimate: None,
+                category: "LOG".to_string(),
+                tags: None,
+                derives_from: None,
+                depends_on: None,
+                status: crate::types::Status::Active,
+                created_by: "test".to_string(),
+            },
+        )
+        .unwrap();
+        git(root, &["add", "-A"]);
+        git(root, &["commit", "-q", "-m", "Add requirement"]);
+    }
+
+    #[test]
+    fn test_node_history_lists_revisions() {
+        use crate::storage::{EditNodeOptions, edit_node};
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let root = tmp.path();
+        init_log_lattice(root);
+
+        edit_node(
+            root,
+            EditNodeOptions {
+                node_id: "REQ-LOG-001".to_string(),
+                title: None,
+                body: Some("Second".to_string()),
+                status: None,
+                priority: None,
+                estimate: None,
+                confidence: None,
+                tags: None,
+                category: None,
+                files: None,
+                test_command: None,
+                bump: None,
+            },
+        )
+        .unwrap();
+        git(root, &["commit", "-q", "-am", "Edit body"]);
+
+        let revisions = node_history(root, "REQ-LOG-001").unwrap();
+        assert_eq!(revisions.len(), 2);
+        assert_eq!(revisions[0].subject, "Edit body");
+        assert_eq!(revisions[0].author, "Test");
+        assert_eq!(revisions[0].node.version, "1.0.1");
+        assert!(revisions[0].fields.contains(&"body".to_string()));
+        assert!(revisions[0].fields.contains(&"version".to_string()));
+        assert!(revisions[1].fields.is_empty());
+
+        let first = node_at_version(&revisions, "1.0.0").unwrap();
+        assert_eq!(first.node.body, "First");
+        assert!(node_at_version(&revisions, "9.9.9").is_none());
+    }
+
+    #[test]
+    fn test_changed_node_ids_covers_committed_edited_and_untracked() {
+        use crate::storage::{AddRequirementOptions, EditNodeOptions, add_requirement, edit_node};
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let root = tmp.path();
+        init_log_lattice(root);
+        let add = |id: &str, title: &str| {
+            add_requirement(
+                root,
+                AddRequirementOptions {
+                    id: id.to_string(),
+                    title: title.to_string(),
+                    body: "Body".to_string(),
+                    priority: crate::types::Priority::P1,
+                    estimate: None,
+                    category: "LOG".to_string(),
+                    tags: None,
+                    derives_from: None,
+                    depends_on: None,
+                    status: crate::types::Status::Active,
+                    created_by: "test".to_string(),
+                },
+            )
+            .unwrap();
+        };
+        add("REQ-LOG-002", "Committed");
+        git(root, &["add", "-A"]);
+        git(root, &["commit", "-q", "-m", "Add second"]);
+        add("REQ-LOG-003", "Untracked");
+
+        let ids = changed_node_ids(root, Some("HEAD~1")).unwrap();
+        assert_eq!(
+            ids.into_iter().collect::<Vec<_>>(),
+            vec!["REQ-LOG-002", "REQ-LOG-003"]
+        );
+
+        // An uncommitted edit counts too
+        edit_node(
+            root,
+            EditNodeOptions {
+                node_id: "REQ-LOG-001".to_string(),
+                title: None,
+                body: Some("Edited".to_string()),
+                status: None,
+                priority: None,
+                estimate: None,
+                confidence: None,
+                tags: None,
+                category: None,
+                files: None,
+                test_command: None,
+                bump: None,
+            },
+        )
+        .unwrap();
+        let ids = changed_node_ids(root, Some("HEAD")).unwrap();
+        assert_eq!(
+            ids.into_iter().collect::<Vec<_>>(),
+            vec!["REQ-LOG-001", "REQ-LOG-003"]
+        );
+        let diff = lattice_diff(root, Some("HEAD")).unwrap();
+        assert!(
+            diff.modified[0]
+                .fields
+                .as_ref()
+                .unwrap()
+                .contains(&"body".to_string())
+        );
+    }
+
+    #[test]
+    fn test_lattice_at_ref_reads_past_tree() {
+        use crate::storage::{EditNodeOptions, edit_node, load_node};
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let root = tmp.path();
+        init_log_lattice(root);
+        edit_node(
+            root,
+            EditNodeOptions {
+                node_id: "REQ-LOG-001".to_string(),
+                title: None,
+                body: Some("Second".to_string()),
+                status: None,
+                priority: None,
+                estimate: None,
+                confidence: None,
+                tags: None,
+                category: None,
+                files: None,
+                test_command: None,
+                bump: None,
+            },
+        )
+        .unwrap();
+        git(root, &["commit", "-q", "-am", "Edit body"]);
+
+        let snapshot = lattice_at_ref(root, "HEAD~1").unwrap();
+        let path = snapshot
+            .join(LATTICE_DIR)
+            .join("requirements/log/001-logged.yaml");
+        let node = load_node(&path).unwrap();
+        assert_eq!(node.version, "1.0.0");
+        assert_eq!(node.body, "First");
+
+        // Second call reuses the snapshot
+        assert_eq!(lattice_at_ref(root, "HEAD~1").unwrap(), snapshot);
+        assert!(lattice_at_ref(root, "no-such-ref").is_err());
+    }
+
+    #[test]
+    fn test_prune_snapshots_keeps_most_recent() {
+        use std::time::{Duration, SystemTime};
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let dir = tmp.path();
+        let now = SystemTime::now();
+        for (i, name) in ["a", "b", "c", "d"].iter().enumerate() {
+            fs::create_dir(dir.join(name)).unwrap();
+            fs::File::open(dir.join(name))
+                .unwrap()
+                .set_modified(now - Duration::from_secs(100 * (4 - i as u64)))
+                .unwrap();
+        }
+        fs::create_dir(dir.join("e.123.tmp")).unwrap();
+        // Using "a" again makes it the most recent
+        touch(&dir.join("a"));
+
+        prune_snapshots(dir, 2);
+        let mut left: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        left.sort();
+        assert_eq!(left, vec!["a", "d", "e.123.tmp"]);
+    }
+
+    #[test]
+    fn test_line_diff() {
+        let diff = line_diff("a\nb\nc", "a\nx\nc\nd");
+        assert_eq!(diff, vec![" a", "-b", "+x", " c", "+d"]);
+        assert_eq!(line_diff("same", "same"), vec![" same"]);
+        assert_eq!(line_diff("", "new"), vec!["+new"]);
+    }
+
+    #[test]
+    fn test_explain_drift_shows_bound_content() {
+        use crate::graph::find_drift;
+        use crate::storage::{
+            AddImplementationOptions, EditNodeOptions, add_implementation, edit_node,
+        };
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let root = tmp.path();
+        init_log_lattice(root);
+        add_implementation(
+            root,
+            AddImplementationOptions {
+                id: "IMP-LOG-001".to_string(),
+                title: "Impl".to_string(),
+                body: "Impl".to_string(),
+                language: None,
+                files: None,
+                test_command: None,
+                satisfies: Some(vec!["REQ-LOG-001".to_string()]),
+                status: crate::types::Status::Active,
+                created_by: "test".to_string(),
+            },
+        )
+        .unwrap();
+        edit_node(
+            root,
+            EditNodeOptions {
+                node_id: "REQ-LOG-001".to_string(),
+                title: None,
+                body: Some("Second".to_string()),
+                status: None,
+                priority: None,
+                estimate: None,
+                confidence: None,
+                tags: None,
+                category: None,
+                files: None,
+                test_command: None,
+                bump: None,
+            },
+        )
+        .unwrap();
+
+        let mut reports = find_drift(root).unwrap();
+        explain_drift(root, &mut reports);
+        assert_eq!(reports.len(), 1);
+        let explanation = reports[0].drift_items[0].explanation.as_ref().unwrap();
+        assert_eq!(explanation.old_body, "First");
+        assert_eq!(explanation.new_body, "Second");
+        assert!(explanation.changed_fields.contains(&"body".to_string()));
+        assert_eq!(explanation.diffs["body"], vec!["-First", "+Second"]);
+        assert!(!explanation.diffs.contains_key("title"));
+    }
 }
@@ -451,6 +451,7 @@ mod tests {
     fn make_thesis(id: &str, title: &str, body: &str) -> LatticeNode {
         LatticeNode {
             id: id.to_string(),
+            aliases: None,
             node_type: NodeType::Thesis,
             title: title.to_string(),
             body: body.to_string(),
@@ -460,10 +461,12 @@ mod tests {
             created_by: "test".to_string(),
             requested_by: None,
             priority: None,
+            estimate: None,
             category: None,
             tags: None,
             acceptance: None,
             visibility: None,
+            lint_ignore: None,
             resolution: None,
             meta: None,
             edges: None,
@@ -478,6 +481,7 @@ mod tests {
     ) -> LatticeNode {
         LatticeNode {
             id: id.to_string(),
+            aliases: None,
             node_type: NodeType::Requirement,
             title: title.to_string(),
             body: "Requirement body text".to_string(),
@@ -487,10 +491,12 @@ mod tests {
             created_by: "test".to_string(),
             requested_by: None,
             priority: Some(priority),
+            estimate: None,
             category: Some("CORE".to_string()),
             tags: None,
             acceptance: None,
             visibility: None,
+            lint_ignore: None,
             resolution: resolution.map(|s| ResolutionInfo {
                 status: s,
                 reason: None,
@@ -522,6 +528,7 @@ mod tests {
         };
         LatticeNode {
             id: id.to_string(),
+            aliases: None,
             node_type: NodeType::Implementation,
             title: title.to_string(),
             body: "Implementation body".to_string(),
@@ -531,10 +538,12 @@ mod tests {
             created_by: "test".to_string(),
             requested_by: None,
             priority: None,
+            estimate: None,
             category: None,
             tags: None,
             acceptance: None,
             visibility: None,
+            lint_ignore: None,
             resolution: None,
             meta: None,
             edges,
@@ -2,9 +2,12 @@
 //!
 //! Linked requirements: REQ-CORE-003, REQ-CORE-005, REQ-CLI-007
 
-use crate::storage::load_all_nodes;
-use crate::types::{LatticeNode, NodeIndex, Resolution};
-use std::collections::{HashMap, HashSet};
+use crate::storage::{IncomingEdge, load_all_nodes};
+use crate::types::{
+    AcceptanceTest, LatticeNode, NodeIndex, NodeMeta, NodeType, Priority, Resolution, Status,
+};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::Path;
 
 /// Severity of version drift.
@@ -22,6 +25,25 @@ pub struct DriftItem {
     pub bound_version: String,
     pub current_version: String,
     pub severity: DriftSeverity,
+    /// What changed since the bound version; filled in by `diff::explain_drift`.
+    pub explanation: Option<DriftExplanation>,
+}
+
+/// Content of a drifted target at its bound version versus now.
+#[derive(Debug, Clone, Serialize)]
+pub struct DriftExplanation {
+    /// Commit holding the bound version of the target.
+    pub bound_commit: String,
+    /// Top-level fields that differ between the bound and current node.
+    pub changed_fields: Vec<String>,
+    pub old_title: String,
+    pub new_title: String,
+    pub old_body: String,
+    pub new_body: String,
+    pub old_acceptance: Vec<AcceptanceTest>,
+    pub new_acceptance: Vec<AcceptanceTest>,
+    /// Line diffs for title, body and acceptance, present only for those that changed.
+    pub diffs: BTreeMap<String, Vec<String>>,
 }
 
 /// A drift report for a single node.
@@ -42,6 +64,92 @@ pub fn build_node_index(root: &Path) -> Result<NodeIndex, crate::storage::Storag
     Ok(index)
 }
 
+/// Look up a node by ID, falling back to nodes that list `id` as a former
+/// ID (alias) after a `lattice rename`.
+pub fn lookup_node<'a>(index: &'a NodeIndex, id: &str) -> Option<&'a LatticeNode> {
+    index
+        .get(id)
+        .or_else(|| index.values().find(|n| n.answers_to(id)))
+}
+
+/// An edge as seen from its target: which node points here, and how.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IncomingRef {
+    pub from_id: String,
+    pub from_type: String,
+    pub edge_type: String,
+    /// Version of the target the edge is bound to.
+    pub version: Option<String>,
+}
+
+/// Incoming edges keyed by target node ID. `LatticeNode.edges` only stores
+/// outgoing edges; this is the other direction.
+pub type ReverseIndex = HashMap<String, Vec<IncomingRef>>;
+
+/// Build the node index and its reverse-edge index in one load.
+pub fn build_indexes(
+    root: &Path,
+) -> Result<(NodeIndex, ReverseIndex), crate::storage::StorageError> {
+    let index = build_node_index(root)?;
+    let reverse = build_reverse_index(&index);
+    Ok((index, reverse))
+}
+
+/// Invert every edge in the index. Edges addressed to a former ID are filed
+/// under the node that now answers to it; dangling edges and self-edges are
+/// skipped. Each target's list is sorted by source ID then edge type.
+pub fn build_reverse_index(index: &NodeIndex) -> ReverseIndex {
+    let mut reverse = ReverseIndex::new();
+    for node in index.values() {
+        let from_type = format!("{:?}", node.node_type).to_lowercase();
+        for (edge_type, edge) in node.typed_edges() {
+            let Some(target) = lookup_node(index, &edge.target) else {
+                continue;
+            };
+            if target.id == node.id {
+                continue;
+            }
+            reverse
+                .entry(target.id.clone())
+                .or_default()
+                .push(IncomingRef {
+                    from_id: node.id.clone(),
+                    from_type: from_type.clone(),
+                    edge_type: edge_type.to_string(),
+                    version: edge.version.clone(),
+                });
+        }
+    }
+    for refs in reverse.values_mut() {
+        refs.sort_by(|a, b| (&a.from_id, &a.edge_type).cmp(&(&b.from_id, &b.edge_type)));
+    }
+    reverse
+}
+
+/// List every edge on another node that targets `node_id` or one of its
+/// former IDs, one entry per source node and edge type.
+pub fn find_incoming_edges(
+    root: &Path,
+    node_id: &str,
+) -> Result<Vec<IncomingEdge>, crate::storage::StorageError> {
+    let (index, reverse) = build_indexes(root)?;
+    let Some(node) = lookup_node(&index, node_id) else {
+        return Ok(Vec::new());
+    };
+    let mut incoming: Vec<IncomingEdge> = reverse
+        .get(&node.id)
+        .into_iter()
+        .flatten()
+        .map(|r| IncomingEdge {
+            from_id: r.from_id.clone(),
+            edge_type: r.edge_type.clone(),
+            version: r.version.clone(),
+        })
+        .collect();
+    incoming.dedup_by(|a, b| a.from_id == b.from_id && a.edge_type == b.edge_type);
+    Ok(incoming)
+}
+
 /// Compare two semantic versions and return the severity of change.
 fn compare_versions(old: &str, new: &str) -> Option<DriftSeverity> {
     let parse = |v: &str| -> Option<(u64, u64, u64)> {
@@ -73,6 +181,11 @@ fn compare_versions(old: &str, new: &str) -> Option<DriftSeverity> {
 /// Find all drift in the lattice.
 pub fn find_drift(root: &Path) -> Result<Vec<DriftReport>, crate::storage::StorageError> {
     let index = build_node_index(root)?;
+    Ok(drift_reports(&index))
+}
+
+/// Compute direct drift for every node in an already-loaded index.
+pub fn drift_reports(index: &NodeIndex) -> Vec<DriftReport> {
     let mut reports = Vec::new();
 
     for node in index.values() {
@@ -87,6 +200,7 @@ pub fn find_drift(root: &Path) -> Result<Vec<DriftReport>, crate::storage::Stora
                         bound_version: bound_version.to_string(),
                         current_version: target_node.version.clone(),
                         severity,
+                        explanation: None,
                     });
                 }
             }
@@ -101,7 +215,371 @@ pub fn find_drift(root: &Path) -> Result<Vec<DriftReport>, crate::storage::Stora
         }
     }
 
-    Ok(reports)
+    reports
+}
+
+/// A node affected by an upstream change through one or more intermediate nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndirectDrift {
+    pub node_id: String,
+    pub node_type: String,
+    /// The changed upstream node the drift originates from.
+    pub source_id: String,
+    /// Node IDs from this node down to `source_id`, following edges.
+    pub path: Vec<String>,
+    /// Number of edges between this node and `source_id`.
+    pub hops: usize,
+}
+
+/// Propagate direct drift downstream: every node that reaches a directly
+/// drifted node through edges is indirectly drifted on the same upstream
+/// change. Reports the shortest path per (node, changed source), skipping
+/// nodes already directly drifted on that source.
+pub fn find_transitive_drift(index: &NodeIndex, direct: &[DriftReport]) -> Vec<IndirectDrift> {
+    let reverse = build_reverse_index(index);
+
+    let direct_pairs: HashSet<(&str, &str)> = direct
+        .iter()
+        .flat_map(|r| {
+            r.drift_items
+                .iter()
+                .map(move |i| (r.node_id.as_str(), i.target_id.as_str()))
+        })
+        .collect();
+
+    let mut found: BTreeMap<(String, String), Vec<String>> = BTreeMap::new();
+    for report in direct {
+        for item in &report.drift_items {
+            let source = item.target_id.as_str();
+            let mut parent: HashMap<&str, &str> = HashMap::new();
+            parent.insert(report.node_id.as_str(), source);
+            let mut queue = std::collections::VecDeque::from([report.node_id.as_str()]);
+
+            while let Some(current) = queue.pop_front() {
+                let sources = reverse.get(current).into_iter().flatten();
+                for from in sources.map(|r| r.from_id.as_str()) {
+                    if from == source || parent.contains_key(from) {
+                        continue;
+                    }
+                    parent.insert(from, current);
+                    queue.push_back(from);
+                    if direct_pairs.contains(&(from, source)) {
+                        continue;
+                    }
+
+                    let mut path = vec![from.to_string()];
+                    let mut step = from;
+                    while step != source {
+                        step = parent[step];
+                        path.push(step.to_string());
+                    }
+                    let key = (from.to_string(), source.to_string());
+                    if found.get(&key).is_none_or(|p| path.len() < p.len()) {
+                        found.insert(key, path);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut result: Vec<IndirectDrift> = found
+        .into_iter()
+        .map(|((node_id, source_id), path)| IndirectDrift {
+            node_type: index
+                .get(&node_id)
+                .map(|n| format!("{:?}", n.node_type).to_lowercase())
+                .unwrap_or_default(),
+            hops: path.len() - 1,
+            node_id,
+            source_id,
+            path,
+        })
+        .collect();
+    result.sort_by(|a, b| (a.hops, &a.node_id).cmp(&(b.hops, &b.node_id)));
+    result
+}
+
+/// A node reached by walking incoming edges back from a changed node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImpactedNode {
+    pub node_id: String,
+    pub node_type: String,
+    pub title: String,
+    /// Number of edges between this node and the changed node.
+    pub distance: usize,
+    /// The node this one points at on its shortest path to the changed node.
+    pub via: String,
+    /// Edge type from this node to `via`.
+    pub edge_type: String,
+}
+
+/// Everything that depends, directly or transitively, on a node.
+#[derive(Debug, Clone)]
+pub struct Impact {
+    pub root_id: String,
+    /// Affected nodes, ordered by distance then ID.
+    pub nodes: Vec<ImpactedNode>,
+    /// Files bound by the changed node and affected implementations.
+    pub files: Vec<String>,
+}
+
+impl Impact {
+    /// Affected nodes grouped by distance from the changed node.
+    pub fn by_distance(&self) -> BTreeMap<usize, Vec<&ImpactedNode>> {
+        let mut levels: BTreeMap<usize, Vec<&ImpactedNode>> = BTreeMap::new();
+        for node in &self.nodes {
+            levels.entry(node.distance).or_default().push(node);
+        }
+        levels
+    }
+}
+
+/// Blast radius of a change to `root_id`: walk every incoming edge type
+/// breadth-first, so each affected node is reported at its shortest distance.
+/// `max_depth` limits how many edges to follow. Returns `None` if the node
+/// does not exist.
+pub fn find_impact(root_id: &str, index: &NodeIndex, max_depth: Option<usize>) -> Option<Impact> {
+    let root = lookup_node(index, root_id)?;
+
+    let reverse = build_reverse_index(index);
+
+    let mut seen: HashSet<&str> = HashSet::from([root.id.as_str()]);
+    let mut frontier = vec![root.id.as_str()];
+    let mut nodes = Vec::new();
+    let mut distance = 0;
+
+    while !frontier.is_empty() && max_depth.is_none_or(|d| distance < d) {
+        distance += 1;
+        let mut next = Vec::new();
+        for current in frontier {
+            for incoming in reverse.get(current).into_iter().flatten() {
+                let from = incoming.from_id.as_str();
+                if !seen.insert(from) {
+                    continue;
+                }
+                let node = &index[from];
+                nodes.push(ImpactedNode {
+                    node_id: from.to_string(),
+                    node_type: format!("{:?}", node.node_type).to_lowercase(),
+                    title: node.title.clone(),
+                    distance,
+                    via: current.to_string(),
+                    edge_type: incoming.edge_type.clone(),
+                });
+                next.push(from);
+            }
+        }
+        frontier = next;
+    }
+    nodes.sort_by(|a, b| (a.distance, &a.node_id).cmp(&(b.distance, &b.node_id)));
+
+    let mut files: Vec<String> = std::iter::once(root)
+        .chain(nodes.iter().map(|n| &index[&n.node_id]))
+        .filter_map(|n| match &n.meta {
+            Some(NodeMeta::Implementation(meta)) => meta.files.as_ref(),
+            _ => None,
+        })
+        .flatten()
+        .map(|f| f.path.clone())
+        .collect();
+    files.sort();
+    files.dedup();
+
+    Some(Impact {
+        root_id: root.id.clone(),
+        nodes,
+        files,
+    })
+}
+
+/// One edge along a trace, read in the direction it is stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceHop {
+    pub from: String,
+    pub edge_type: String,
+    pub to: String,
+    /// Version of `to` the edge is bound to.
+    pub bound_version: String,
+    pub current_version: String,
+    pub rationale: Option<String>,
+    /// Set when the binding is stale: `to` has moved past `bound_version`.
+    pub drift: Option<DriftSeverity>,
+}
+
+/// Outgoing edges of a node that resolve to another node in the index.
+fn outgoing_hops(node: &LatticeNode, index: &NodeIndex) -> Vec<TraceHop> {
+    node.typed_edges()
+        .into_iter()
+        .filter_map(|(edge_type, edge)| {
+            let target = lookup_node(index, &edge.target)?;
+            if target.id == node.id {
+                return None;
+            }
+            let bound_version = edge.version_or_default();
+            Some(TraceHop {
+                from: node.id.clone(),
+                edge_type: edge_type.to_string(),
+                to: target.id.clone(),
+                bound_version: bound_version.to_string(),
+                current_version: target.version.clone(),
+                rationale: edge.rationale.clone(),
+                drift: compare_versions(bound_version, &target.version),
+            })
+        })
+        .collect()
+}
+
+/// Partial paths `trace_paths` may expand in `all` mode before it stops, so
+/// dense graphs stay bounded.
+pub const TRACE_MAX_EXPANSIONS: usize = 100_000;
+
+/// Paths from `from` along outgoing edges, e.g. IMP -> satisfies -> REQ ->
+/// derives_from -> THX -> supported_by -> SRC.
+///
+/// With `to`, paths end at that node; without it, they end at every
+/// reachable node that has no outgoing edges of its own (typically sources),
+/// answering "why does this node exist?". By default one shortest path per
+/// end node is returned; with `all`, simple paths are enumerated shortest
+/// first until `limit` are found, `max_depth` hops are reached, or
+/// `TRACE_MAX_EXPANSIONS` partial paths have been tried.
+/// Paths are ordered by length, then by end node. IDs may be former aliases.
+pub fn trace_paths(
+    from: &str,
+    to: Option<&str>,
+    index: &NodeIndex,
+    all: bool,
+    limit: usize,
+    max_depth: Option<usize>,
+) -> Vec<Vec<TraceHop>> {
+    let Some(start) = lookup_node(index, from) else {
+        return Vec::new();
+    };
+    let end = match to {
+        Some(id) => match lookup_node(index, id) {
+            Some(node) => Some(node.id.as_str()),
+            None => return Vec::new(),
+        },
+        None => None,
+    };
+
+    let mut hops: HashMap<&str, Vec<TraceHop>> = HashMap::new();
+    for node in index.values() {
+        hops.insert(node.id.as_str(), outgoing_hops(node, index));
+    }
+    let is_end = |id: &str| match end {
+        Some(end) => id == end,
+        None => id != start.id && hops[id].is_empty(),
+    };
+
+    let mut paths = Vec::new();
+    if all {
+        // Only nodes that can still reach an end node are worth extending
+        let mut predecessors: HashMap<&str, Vec<&str>> = HashMap::new();
+        for (id, out) in &hops {
+            for hop in out {
+                predecessors.entry(hop.to.as_str()).or_default().push(id);
+            }
+        }
+        let mut useful: HashSet<&str> = hops.keys().copied().filter(|id| is_end(id)).collect();
+        let mut queue: Vec<&str> = useful.iter().copied().collect();
+        while let Some(id) = queue.pop() {
+            for prev in predecessors.get(id).into_iter().flatten() {
+                if useful.insert(prev) {
+                    queue.push(prev);
+                }
+            }
+        }
+
+        // Expand one hop per level, so every path of length n is found
+        // before any of length n + 1. Partial paths are parent links into
+        // `arena`: (node, hop that reached it, parent index).
+        let mut arena: Vec<(&str, Option<&TraceHop>, usize)> = vec![(&start.id, None, 0)];
+        let on_path = |arena: &[(&str, Option<&TraceHop>, usize)], mut i: usize, id: &str| loop {
+            if arena[i].0 == id {
+                return true;
+            }
+            if i == 0 {
+                return false;
+            }
+            i = arena[i].2;
+        };
+        let mut frontier = vec![0];
+        let mut depth = 0;
+        'levels: while !frontier.is_empty()
+            && paths.len() < limit
+            && max_depth.is_none_or(|max| depth < max)
+        {
+            depth += 1;
+            let mut next = Vec::new();
+            for i in frontier {
+                for hop in &hops[arena[i].0] {
+                    let to = hop.to.as_str();
+                    if !useful.contains(to) || on_path(&arena, i, to) {
+                        continue;
+                    }
+                    if arena.len() > TRACE_MAX_EXPANSIONS {
+                        break 'levels;
+                    }
+                    arena.push((to, Some(hop), i));
+                    if is_end(to) {
+                        let mut path = Vec::new();
+                        let mut step = arena.len() - 1;
+                        while let (_, Some(hop), parent) = arena[step] {
+                            path.push(hop.clone());
+                            step = parent;
+                        }
+                        path.reverse();
+                        paths.push(path);
+                    } else {
+                        next.push(arena.len() - 1);
+                    }
+                }
+            }
+            frontier = next;
+        }
+    } else {
+        // Breadth-first, remembering the hop that first reached each node
+        let mut parent: HashMap<&str, &TraceHop> = HashMap::new();
+        let mut queue = std::collections::VecDeque::from([start.id.as_str()]);
+        let mut reached = Vec::new();
+        while let Some(current) = queue.pop_front() {
+            if current != start.id && is_end(current) {
+                reached.push(current);
+                if end.is_some() {
+                    break;
+                }
+                continue;
+            }
+            for hop in &hops[current] {
+                let next = hop.to.as_str();
+                if next != start.id && !parent.contains_key(next) {
+                    parent.insert(next, hop);
+                    queue.push_back(next);
+                }
+            }
+        }
+        for id in reached {
+            let mut path = Vec::new();
+            let mut step = id;
+            while step != start.id {
+                let hop = parent[step];
+                path.push(hop.clone());
+                step = hop.from.as_str();
+            }
+            path.reverse();
+            paths.push(path);
+        }
+    }
+
+    if let Some(max) = max_depth {
+        paths.retain(|p| p.len() <= max);
+    }
+    paths.sort_by(|a, b| {
+        let end_of = |p: &Vec<TraceHop>| p.last().map(|h| h.to.clone());
+        (a.len(), end_of(a)).cmp(&(b.len(), end_of(b)))
+    });
+    paths.truncate(limit);
+    paths
 }
 
 /// Traverse the graph starting from a node.
@@ -129,15 +607,66 @@ pub fn traverse_from(
     result
 }
 
+/// How far along a planned requirement is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanState {
+    /// No implementation satisfies it yet.
+    NotStarted,
+    /// An implementation exists but the requirement is not verified.
+    InProgress,
+    /// Resolved as verified.
+    Verified,
+}
+
+impl PlanState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlanState::NotStarted => "not_started",
+            PlanState::InProgress => "in_progress",
+            PlanState::Verified => "verified",
+        }
+    }
+}
+
+/// A loop in requirement dependencies, which makes an order impossible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyCycle {
+    /// Requirement IDs along the loop, each depending on the next; the first
+    /// ID is repeated at the end.
+    pub path: Vec<String>,
+}
+
+impl std::fmt::Display for DependencyCycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Dependency cycle: {}", self.path.join(" -> "))
+    }
+}
+
+impl std::error::Error for DependencyCycle {}
+
 /// A planned requirement with its dependencies and status.
 #[derive(Debug, Clone)]
 pub struct PlannedItem {
     pub id: String,
     pub title: String,
+    pub priority: Option<Priority>,
+    pub category: Option<String>,
+    pub estimate: Option<f64>,
     pub resolution: Option<Resolution>,
+    pub state: PlanState,
+    /// Implementations with a `satisfies` edge to this requirement.
+    pub implementations: Vec<String>,
     pub depends_on: Vec<String>,
     pub blocked_by: Vec<String>, // Unresolved dependencies
     pub order: usize,
+    /// Wave (0-based) in which this item can start, if it can be scheduled.
+    pub wave: Option<usize>,
+    /// Number of planned items waiting on this one, directly or transitively.
+    pub unblocks: usize,
+    /// Estimated effort that must be finished before this item can start.
+    /// `None` for items without a wave: verified, blocked, deferred or
+    /// won't-do items, and anything waiting on the last three.
+    pub earliest_start: Option<f64>,
 }
 
 /// A plan for implementing requirements.
@@ -147,6 +676,73 @@ pub struct Plan {
     pub ready: Vec<String>,    // Can be implemented now
     pub blocked: Vec<String>,  // Waiting on dependencies
     pub verified: Vec<String>, // Already done
+    /// Unverified items that already have an implementation.
+    pub in_progress: Vec<String>,
+    /// Unverified items grouped so each wave only depends on earlier waves;
+    /// items within a wave can be worked on in parallel. Ordered by priority,
+    /// then by how many items each unblocks.
+    pub waves: Vec<Vec<String>>,
+    /// Longest chain of remaining work by total estimate (then by number of
+    /// items), from its first dependency to its last dependent.
+    pub critical_path: Vec<String>,
+    /// Total estimate along `critical_path`.
+    pub critical_path_effort: f64,
+    /// Remaining estimated effort per category; requirements without a
+    /// category are counted under "uncategorized".
+    pub effort_by_category: BTreeMap<String, f64>,
+    /// Remaining items without an estimate, counted as zero effort.
+    pub unestimated: Vec<String>,
+    /// Remaining items ordered by earliest start, then dependency order.
+    pub schedule: Vec<String>,
+}
+
+/// Which requirements `lattice plan --all` covers.
+#[derive(Debug, Default, Clone)]
+pub struct PlanFilter {
+    pub category: Option<String>,
+    pub tag: Option<String>,
+    pub priority: Option<Priority>,
+}
+
+/// Every unresolved, non-superseded requirement matching the filter, sorted by ID.
+pub fn unresolved_requirements(index: &NodeIndex, filter: &PlanFilter) -> Vec<String> {
+    let mut ids: Vec<String> = index
+        .values()
+        .filter(|n| n.node_type == NodeType::Requirement && n.resolution.is_none())
+        .filter(|n| !matches!(n.status, Status::Superseded | Status::Deprecated))
+        .filter(|n| {
+            filter.category.as_ref().is_none_or(|c| {
+                n.category
+                    .as_ref()
+                    .is_some_and(|nc| nc.eq_ignore_ascii_case(c))
+            })
+        })
+        .filter(|n| {
+            filter
+                .tag
+                .as_ref()
+                .is_none_or(|t| n.tags.iter().flatten().any(|nt| nt.eq_ignore_ascii_case(t)))
+        })
+        .filter(|n| {
+            filter
+                .priority
+                .as_ref()
+                .is_none_or(|p| n.priority.as_ref() == Some(p))
+        })
+        .map(|n| n.id.clone())
+        .collect();
+    ids.sort();
+    ids
+}
+
+/// Sort rank for a priority: P0 first, unprioritized last.
+fn priority_rank(priority: Option<&Priority>) -> u8 {
+    match priority {
+        Some(Priority::P0) => 0,
+        Some(Priority::P1) => 1,
+        Some(Priority::P2) => 2,
+        None => 3,
+    }
 }
 
 /// Find all upstream dependencies (what this node depends on).
@@ -209,8 +805,12 @@ fn collect_all_deps(node_ids: &[String], index: &NodeIndex, collected: &mut Hash
     }
 }
 
-/// Topological sort of requirements based on dependencies.
-fn topological_sort(node_ids: &[String], index: &NodeIndex) -> Vec<String> {
+/// Topological sort of requirements based on dependencies. Fails with the
+/// loop's path if the dependencies form a cycle.
+fn topological_sort(
+    node_ids: &[String],
+    index: &NodeIndex,
+) -> Result<Vec<String>, DependencyCycle> {
     let mut in_degree: HashMap<String, usize> = HashMap::new();
     let mut graph: HashMap<String, Vec<String>> = HashMap::new();
 
@@ -256,11 +856,48 @@ fn topological_sort(node_ids: &[String], index: &NodeIndex) -> Vec<String> {
         }
     }
 
-    result
+    if result.len() < node_ids.len() {
+        return Err(find_cycle(&in_degree, index));
+    }
+    Ok(result)
+}
+
+/// After Kahn's algorithm stalls, every node left with a nonzero in-degree
+/// waits on another such node, so following dependencies from any of them
+/// must loop. Walk from the smallest ID and cut out the loop.
+fn find_cycle(in_degree: &HashMap<String, usize>, index: &NodeIndex) -> DependencyCycle {
+    let remaining: HashSet<&str> = in_degree
+        .iter()
+        .filter(|(_, deg)| **deg > 0)
+        .map(|(id, _)| id.as_str())
+        .collect();
+    let Some(start) = remaining.iter().min() else {
+        return DependencyCycle { path: Vec::new() };
+    };
+
+    let mut path: Vec<String> = Vec::new();
+    let mut current = start.to_string();
+    loop {
+        if let Some(pos) = path.iter().position(|id| *id == current) {
+            let mut cycle = path.split_off(pos);
+            cycle.push(current);
+            return DependencyCycle { path: cycle };
+        }
+        path.push(current.clone());
+        let mut deps = find_dependencies(&current, index);
+        deps.sort();
+        match deps.into_iter().find(|d| remaining.contains(d.as_str())) {
+            Some(next) => current = next,
+            None => return DependencyCycle { path },
+        }
+    }
 }
 
 /// Generate a plan for implementing requirements.
-pub fn generate_plan(requirement_ids: &[String], index: &NodeIndex) -> Plan {
+pub fn generate_plan(
+    requirement_ids: &[String],
+    index: &NodeIndex,
+) -> Result<Plan, DependencyCycle> {
     // Collect all dependencies
     let mut all_ids: HashSet<String> = HashSet::new();
     collect_all_deps(requirement_ids, index, &mut all_ids);
@@ -277,13 +914,15 @@ pub fn generate_plan(requirement_ids: &[String], index: &NodeIndex) -> Plan {
         .collect();
 
     // Topological sort
-    let sorted = topological_sort(&valid_ids, index);
+    let sorted = topological_sort(&valid_ids, index)?;
+    let reverse = build_reverse_index(index);
 
     // Build planned items
     let mut items = Vec::new();
     let mut ready = Vec::new();
     let mut blocked = Vec::new();
     let mut verified = Vec::new();
+    let mut in_progress = Vec::new();
 
     for (order, id) in sorted.iter().enumerate() {
         if let Some(node) = index.get(id) {
@@ -306,13 +945,43 @@ pub fn generate_plan(requirement_ids: &[String], index: &NodeIndex) -> Plan {
                 .cloned()
                 .collect();
 
+            let implementations: Vec<String> = reverse
+                .get(id)
+                .into_iter()
+                .flatten()
+                .filter(|r| r.edge_type == "satisfies" && r.from_type == "implementation")
+                // Retired implementations no longer count, as in coverage
+                .filter(|r| {
+                    index.get(&r.from_id).is_some_and(|imp| {
+                        !matches!(imp.status, Status::Deprecated | Status::Superseded)
+                    })
+                })
+                .map(|r| r.from_id.clone())
+                .collect();
+            let state = match (&resolution, implementations.is_empty()) {
+                (Some(Resolution::Verified), _) => PlanState::Verified,
+                (_, false) => PlanState::InProgress,
+                (_, true) => PlanState::NotStarted,
+            };
+            if state == PlanState::InProgress {
+                in_progress.push(id.clone());
+            }
+
             let item = PlannedItem {
                 id: id.clone(),
                 title: node.title.clone(),
+                priority: node.priority.clone(),
+                category: node.category.clone(),
+                estimate: node.estimate,
                 resolution: resolution.clone(),
+                state,
+                implementations,
                 depends_on: deps,
                 blocked_by: blocked_by.clone(),
                 order,
+                wave: None,
+                unblocks: 0,
+                earliest_start: None,
             };
 
             // Categorize
@@ -332,12 +1001,159 @@ pub fn generate_plan(requirement_ids: &[String], index: &NodeIndex) -> Plan {
         }
     }
 
-    Plan {
+    // Waves: an item starts one wave after the last of its unverified
+    // dependencies. Items resolved as blocked, deferred or wontfix, and anything
+    // waiting on them, cannot be scheduled. Items are in topological order,
+    // so dependencies are always assigned first.
+    let mut waves_by_id: HashMap<String, usize> = HashMap::new();
+    for item in &mut items {
+        if matches!(
+            item.resolution,
+            Some(
+                Resolution::Verified
+                    | Resolution::Blocked
+                    | Resolution::Deferred
+                    | Resolution::Wontfix
+            )
+        ) {
+            continue;
+        }
+        let dep_waves: Option<Vec<usize>> = item
+            .blocked_by
+            .iter()
+            .map(|dep| waves_by_id.get(dep).copied())
+            .collect();
+        if let Some(dep_waves) = dep_waves {
+            let wave = dep_waves.into_iter().max().map_or(0, |w| w + 1);
+            waves_by_id.insert(item.id.clone(), wave);
+            item.wave = Some(wave);
+        }
+    }
+
+    // How many planned items each item (transitively) holds up
+    let mut waiting_on: HashMap<&str, Vec<&str>> = HashMap::new();
+    for item in &items {
+        for dep in &item.blocked_by {
+            waiting_on
+                .entry(dep.as_str())
+                .or_default()
+                .push(item.id.as_str());
+        }
+    }
+    let unblocks: HashMap<String, usize> = items
+        .iter()
+        .map(|item| {
+            let mut seen: HashSet<&str> = HashSet::new();
+            let mut stack = vec![item.id.as_str()];
+            while let Some(id) = stack.pop() {
+                for &next in waiting_on.get(id).into_iter().flatten() {
+                    if seen.insert(next) {
+                        stack.push(next);
+                    }
+                }
+            }
+            (item.id.clone(), seen.len())
+        })
+        .collect();
+    for item in &mut items {
+        item.unblocks = unblocks[&item.id];
+    }
+
+    // Effort: an item can start once all of its remaining dependencies have
+    // finished. Track (effort, items) for the longest chain ending at each
+    // item so that without estimates the critical path is the longest chain.
+    let longer = |a: (f64, usize), b: (f64, usize)| a.0 > b.0 || (a.0 == b.0 && a.1 > b.1);
+    let mut finish: HashMap<String, (f64, usize)> = HashMap::new();
+    let mut chain_prev: HashMap<String, String> = HashMap::new();
+    let mut effort_by_category: BTreeMap<String, f64> = BTreeMap::new();
+    let mut unestimated = Vec::new();
+    for item in &mut items {
+        // Same exclusions as the waves: nothing that cannot be scheduled
+        if item.wave.is_none() {
+            continue;
+        }
+        let mut start = (0.0, 0);
+        for dep in &item.blocked_by {
+            if let Some(&dep_finish) = finish.get(dep)
+                && longer(dep_finish, start)
+            {
+                start = dep_finish;
+                chain_prev.insert(item.id.clone(), dep.clone());
+            }
+        }
+        item.earliest_start = Some(start.0);
+        let estimate = item.estimate.unwrap_or(0.0);
+        finish.insert(item.id.clone(), (start.0 + estimate, start.1 + 1));
+
+        let category = item.category.as_deref().unwrap_or("uncategorized");
+        *effort_by_category.entry(category.to_string()).or_default() += estimate;
+        if item.estimate.is_none() {
+            unestimated.push(item.id.clone());
+        }
+    }
+    let mut critical_path = Vec::new();
+    let mut critical_path_effort = 0.0;
+    let mut end: Option<(&String, (f64, usize))> = None;
+    for item in &items {
+        if let Some(&f) = finish.get(&item.id)
+            && end.is_none_or(|(_, best)| longer(f, best))
+        {
+            end = Some((&item.id, f));
+        }
+    }
+    if let Some((id, (effort, _))) = end {
+        critical_path_effort = effort;
+        let mut current = Some(id.clone());
+        while let Some(id) = current {
+            current = chain_prev.get(&id).cloned();
+            critical_path.push(id);
+        }
+        critical_path.reverse();
+    }
+    let mut scheduled: Vec<&PlannedItem> = items
+        .iter()
+        .filter(|i| i.earliest_start.is_some())
+        .collect();
+    scheduled.sort_by(|a, b| {
+        a.earliest_start
+            .partial_cmp(&b.earliest_start)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.order.cmp(&b.order))
+    });
+    let schedule = scheduled.into_iter().map(|i| i.id.clone()).collect();
+
+    let rank = |id: &String| {
+        let node = &index[id];
+        (
+            priority_rank(node.priority.as_ref()),
+            std::cmp::Reverse(unblocks[id]),
+            id.clone(),
+        )
+    };
+    ready.sort_by_key(rank);
+
+    let wave_count = waves_by_id.values().max().map_or(0, |w| w + 1);
+    let mut waves: Vec<Vec<String>> = vec![Vec::new(); wave_count];
+    for (id, wave) in waves_by_id {
+        waves[wave].push(id);
+    }
+    for wave in &mut waves {
+        wave.sort_by_key(rank);
+    }
+
+    Ok(Plan {
         items,
         ready,
         blocked,
         verified,
-    }
+        in_progress,
+        waves,
+        critical_path,
+        critical_path_effort,
+        effort_by_category,
+        unestimated,
+        schedule,
+    })
 }
 
 #[cfg(test)]
@@ -354,6 +1170,7 @@ mod tests {
     ) -> LatticeNode {
         LatticeNode {
             id: id.to_string(),
+            aliases: None,
             node_type: NodeType::Requirement,
             title: format!("Test {id}"),
             body: String::new(),
@@ -363,10 +1180,12 @@ mod tests {
             created_by: "test".to_string(),
             requested_by: None,
             priority: Some(Priority::P1),
+            estimate: None,
             category: None,
             tags: None,
             acceptance: None,
             visibility: None,
+            lint_ignore: None,
             resolution: resolution.map(|s| ResolutionInfo {
                 status: s,
                 reason: None,
@@ -570,7 +1389,7 @@ mod tests {
         );
 
         let ids: Vec<String> = vec!["REQ-A".into(), "REQ-B".into(), "REQ-C".into()];
-        let sorted = topological_sort(&ids, &index);
+        let sorted = topological_sort(&ids, &index).unwrap();
 
         let pos_a = sorted.iter().position(|x| x == "REQ-A").unwrap();
         let pos_b = sorted.iter().position(|x| x == "REQ-B").unwrap();
@@ -609,7 +1428,7 @@ mod tests {
             "REQ-C".into(),
             "REQ-D".into(),
         ];
-        let sorted = topological_sort(&ids, &index);
+        let sorted = topological_sort(&ids, &index).unwrap();
 
         let pos_a = sorted.iter().position(|x| x == "REQ-A").unwrap();
         let pos_b = sorted.iter().position(|x| x == "REQ-B").unwrap();
@@ -629,7 +1448,7 @@ mod tests {
         index.insert("REQ-B".into(), req("REQ-B", "1.0.0", None, None));
 
         let ids: Vec<String> = vec!["REQ-A".into(), "REQ-B".into()];
-        let sorted = topological_sort(&ids, &index);
+        let sorted = topological_sort(&ids, &index).unwrap();
 
         assert_eq!(sorted.len(), 2);
         assert!(sorted.contains(&"REQ-A".to_string()));
@@ -654,7 +1473,7 @@ mod tests {
             req("REQ-C", "1.0.0", depends_on(&[("REQ-B", "1.0.0")]), None),
         );
 
-        let plan = generate_plan(&["REQ-C".into()], &index);
+        let plan = generate_plan(&["REQ-C".into()], &index).unwrap();
 
         assert!(plan.verified.contains(&"REQ-A".to_string()));
         assert!(plan.ready.contains(&"REQ-B".to_string()));
@@ -673,7 +1492,7 @@ mod tests {
             req("REQ-B", "1.0.0", depends_on(&[("REQ-A", "1.0.0")]), None),
         );
 
-        let plan = generate_plan(&["REQ-B".into()], &index);
+        let plan = generate_plan(&["REQ-B".into()], &index).unwrap();
 
         assert!(plan.ready.contains(&"REQ-B".to_string()));
         assert!(plan.blocked.is_empty());
@@ -687,16 +1506,96 @@ mod tests {
             req("REQ-A", "1.0.0", None, Some(Resolution::Blocked)),
         );
 
-        let plan = generate_plan(&["REQ-A".into()], &index);
+        let plan = generate_plan(&["REQ-A".into()], &index).unwrap();
 
         assert!(plan.blocked.contains(&"REQ-A".to_string()));
         assert!(plan.ready.is_empty());
     }
 
+    #[test]
+    fn test_generate_plan_waves_and_ordering() {
+        // A <- B <- D, C independent; E waits on the blocked F
+        let mut index = NodeIndex::new();
+        index.insert("REQ-A".into(), req("REQ-A", "1.0.0", None, None));
+        index.insert(
+            "REQ-B".into(),
+            req("REQ-B", "1.0.0", depends_on(&[("REQ-A", "1.0.0")]), None),
+        );
+        let mut c = req("REQ-C", "1.0.0", None, None);
+        c.priority = Some(Priority::P0);
+        index.insert("REQ-C".into(), c);
+        index.insert(
+            "REQ-D".into(),
+            req("REQ-D", "1.0.0", depends_on(&[("REQ-B", "1.0.0")]), None),
+        );
+        index.insert(
+            "REQ-F".into(),
+            req("REQ-F", "1.0.0", None, Some(Resolution::Blocked)),
+        );
+        index.insert(
+            "REQ-E".into(),
+            req("REQ-E", "1.0.0", depends_on(&[("REQ-F", "1.0.0")]), None),
+        );
+
+        let ids: Vec<String> = ["REQ-A", "REQ-B", "REQ-C", "REQ-D", "REQ-E"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let plan = generate_plan(&ids, &index).unwrap();
+
+        // C is P0 so it leads; A (P1) follows and unblocks B and D
+        assert_eq!(plan.ready, vec!["REQ-C", "REQ-A"]);
+        assert_eq!(
+            plan.waves,
+            vec![vec!["REQ-C", "REQ-A"], vec!["REQ-B"], vec!["REQ-D"]]
+        );
+        let a = plan.items.iter().find(|i| i.id == "REQ-A").unwrap();
+        assert_eq!((a.wave, a.unblocks), (Some(0), 2));
+        let e = plan.items.iter().find(|i| i.id == "REQ-E").unwrap();
+        assert_eq!(e.wave, None);
+    }
+
+    #[test]
+    fn test_unresolved_requirements_filters() {
+        let mut index = NodeIndex::new();
+        let mut a = req("REQ-A", "1.0.0", None, None);
+        a.category = Some("CORE".to_string());
+        a.tags = Some(vec!["auth".to_string()]);
+        index.insert("REQ-A".into(), a);
+        let mut b = req("REQ-B", "1.0.0", None, None);
+        b.priority = Some(Priority::P0);
+        index.insert("REQ-B".into(), b);
+        let mut superseded = req("REQ-S", "1.0.0", None, None);
+        superseded.status = Status::Superseded;
+        index.insert("REQ-S".into(), superseded);
+        index.insert(
+            "REQ-V".into(),
+            req("REQ-V", "1.0.0", None, Some(Resolution::Verified)),
+        );
+
+        let all = unresolved_requirements(&index, &PlanFilter::default());
+        assert_eq!(all, vec!["REQ-A", "REQ-B"]);
+        let core = PlanFilter {
+            category: Some("core".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(unresolved_requirements(&index, &core), vec!["REQ-A"]);
+        let tagged = PlanFilter {
+            tag: Some("AUTH".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(unresolved_requirements(&index, &tagged), vec!["REQ-A"]);
+        let p0 = PlanFilter {
+            priority: Some(Priority::P0),
+            ..Default::default()
+        };
+        assert_eq!(unresolved_requirements(&index, &p0), vec!["REQ-B"]);
+    }
+
     #[test]
     fn test_generate_plan_empty_input() {
         let index = NodeIndex::new();
-        let plan = generate_plan(&[], &index);
+        let plan = generate_plan(&[], &index).unwrap();
 
         assert!(plan.items.is_empty());
         assert!(plan.ready.is_empty());
@@ -704,6 +1603,141 @@ mod tests {
         assert!(plan.verified.is_empty());
     }
 
+    #[test]
+    fn test_generate_plan_tracks_implementation_state() {
+        let mut index = NodeIndex::new();
+        index.insert("REQ-A".into(), req("REQ-A", "1.0.0", None, None));
+        index.insert("REQ-B".into(), req("REQ-B", "1.0.0", None, None));
+        index.insert(
+            "REQ-C".into(),
+            req("REQ-C", "1.0.0", None, Some(Resolution::Verified)),
+        );
+        let mut imp = req(
+            "IMP-A",
+            "1.0.0",
+            Some(Edges {
+                satisfies: Some(vec![edge_ref("REQ-A", "1.0.0")]),
+                ..Default::default()
+            }),
+            None,
+        );
+        imp.node_type = NodeType::Implementation;
+        index.insert("IMP-A".into(), imp);
+        // A retired implementation does not start REQ-B
+        let mut retired = req(
+            "IMP-B",
+            "1.0.0",
+            Some(Edges {
+                satisfies: Some(vec![edge_ref("REQ-B", "1.0.0")]),
+                ..Default::default()
+            }),
+            None,
+        );
+        retired.node_type = NodeType::Implementation;
+        retired.status = Status::Superseded;
+        index.insert("IMP-B".into(), retired);
+
+        let plan =
+            generate_plan(&["REQ-A".into(), "REQ-B".into(), "REQ-C".into()], &index).unwrap();
+        let state = |id: &str| plan.items.iter().find(|i| i.id == id).unwrap().state;
+
+        assert_eq!(state("REQ-A"), PlanState::InProgress);
+        assert_eq!(state("REQ-B"), PlanState::NotStarted);
+        assert_eq!(state("REQ-C"), PlanState::Verified);
+        assert_eq!(plan.in_progress, vec!["REQ-A"]);
+        assert!(plan.ready.contains(&"REQ-A".to_string()));
+    }
+
+    #[test]
+    fn test_generate_plan_critical_path_and_effort() {
+        // A(2) <- B(3) <- D(1); A <- C(5, other category); E verified, F unestimated, G blocked
+        let sized = |id: &str, estimate: Option<f64>, deps: &[(&str, &str)], cat: &str| {
+            let mut node = req(id, "1.0.0", depends_on(deps), None);
+            node.estimate = estimate;
+            node.category = Some(cat.to_string());
+            node
+        };
+        let mut index = NodeIndex::new();
+        index.insert("REQ-A".into(), sized("REQ-A", Some(2.0), &[], "CORE"));
+        index.insert(
+            "REQ-B".into(),
+            sized("REQ-B", Some(3.0), &[("REQ-A", "1.0.0")], "CORE"),
+        );
+        index.insert(
+            "REQ-C".into(),
+            sized("REQ-C", Some(5.0), &[("REQ-A", "1.0.0")], "UI"),
+        );
+        index.insert(
+            "REQ-D".into(),
+            sized("REQ-D", Some(1.0), &[("REQ-B", "1.0.0")], "CORE"),
+        );
+        let mut verified = sized("REQ-E", Some(8.0), &[], "CORE");
+        verified.resolution = req("REQ-E", "1.0.0", None, Some(Resolution::Verified)).resolution;
+        index.insert("REQ-E".into(), verified);
+        index.insert("REQ-F".into(), sized("REQ-F", None, &[], "UI"));
+        // G is blocked, so neither it nor H (waiting on it) is scheduled
+        let mut blocked = sized("REQ-G", Some(20.0), &[], "CORE");
+        blocked.resolution = req("REQ-G", "1.0.0", None, Some(Resolution::Blocked)).resolution;
+        index.insert("REQ-G".into(), blocked);
+        index.insert(
+            "REQ-H".into(),
+            sized("REQ-H", Some(1.0), &[("REQ-G", "1.0.0")], "UI"),
+        );
+
+        let ids: Vec<String> = ["REQ-C", "REQ-D", "REQ-E", "REQ-F", "REQ-H"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let plan = generate_plan(&ids, &index).unwrap();
+
+        assert_eq!(plan.critical_path, vec!["REQ-A", "REQ-C"]);
+        assert_eq!(plan.critical_path_effort, 7.0);
+        assert_eq!(plan.effort_by_category["CORE"], 6.0);
+        assert_eq!(plan.effort_by_category["UI"], 5.0);
+        assert_eq!(plan.unestimated, vec!["REQ-F"]);
+        let start = |id: &str| {
+            plan.items
+                .iter()
+                .find(|i| i.id == id)
+                .unwrap()
+                .earliest_start
+        };
+        assert_eq!(start("REQ-D"), Some(5.0));
+        assert_eq!(start("REQ-E"), None);
+        assert_eq!(start("REQ-G"), None);
+        assert_eq!(start("REQ-H"), None);
+        assert_eq!(plan.schedule.last().unwrap(), "REQ-D");
+        for id in ["REQ-E", "REQ-G", "REQ-H"] {
+            assert!(!plan.schedule.contains(&id.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_generate_plan_reports_dependency_cycle() {
+        // A -> B -> C -> B, with D depending on A
+        let mut index = NodeIndex::new();
+        index.insert(
+            "REQ-A".into(),
+            req("REQ-A", "1.0.0", depends_on(&[("REQ-B", "1.0.0")]), None),
+        );
+        index.insert(
+            "REQ-B".into(),
+            req("REQ-B", "1.0.0", depends_on(&[("REQ-C", "1.0.0")]), None),
+        );
+        index.insert(
+            "REQ-C".into(),
+            req("REQ-C", "1.0.0", depends_on(&[("REQ-B", "1.0.0")]), None),
+        );
+        index.insert(
+            "REQ-D".into(),
+            req("REQ-D", "1.0.0", depends_on(&[("REQ-A", "1.0.0")]), None),
+        );
+
+        let err = generate_plan(&["REQ-D".into()], &index).unwrap_err();
+        assert_eq!(err.path, vec!["REQ-B", "REQ-C", "REQ-B"]);
+        assert_eq!(err.to_string(), "Dependency cycle: REQ-B -> REQ-C -> REQ-B");
+    }
+
     // --- find_drift (file-based, using tempdir) ---
 
     #[test]
@@ -752,4 +1786,330 @@ mod tests {
         let reports = find_drift(root).unwrap();
         assert!(reports.is_empty());
     }
+
+    #[test]
+    fn test_lookup_node_resolves_alias() {
+        let mut renamed = req("REQ-NEW-001", "1.0.0", None, None);
+        renamed.aliases = Some(vec!["REQ-OLD-001".to_string()]);
+        let mut index = NodeIndex::new();
+        index.insert(renamed.id.clone(), renamed);
+
+        assert_eq!(
+            lookup_node(&index, "REQ-NEW-001").unwrap().id,
+            "REQ-NEW-001"
+        );
+        assert_eq!(
+            lookup_node(&index, "REQ-OLD-001").unwrap().id,
+            "REQ-NEW-001"
+        );
+        assert!(lookup_node(&index, "REQ-MISSING").is_none());
+    }
+
+    // --- find_transitive_drift ---
+
+    #[test]
+    fn test_find_transitive_drift_propagates_downstream() {
+        // IMP -> REQ -> THX -> SRC, with only the THX -> SRC binding stale
+        let mut index = NodeIndex::new();
+        index.insert("SRC".into(), req("SRC", "1.1.0", None, None));
+        index.insert(
+            "THX".into(),
+            req("THX", "1.0.0", depends_on(&[("SRC", "1.0.0")]), None),
+        );
+        index.insert(
+            "REQ".into(),
+            req("REQ", "1.0.0", depends_on(&[("THX", "1.0.0")]), None),
+        );
+        index.insert(
+            "IMP".into(),
+            req("IMP", "1.0.0", depends_on(&[("REQ", "1.0.0")]), None),
+        );
+
+        let direct = drift_reports(&index);
+        assert_eq!(direct.len(), 1);
+        assert_eq!(direct[0].node_id, "THX");
+
+        let indirect = find_transitive_drift(&index, &direct);
+        assert_eq!(indirect.len(), 2);
+        assert_eq!(indirect[0].node_id, "REQ");
+        assert_eq!(indirect[0].hops, 2);
+        assert_eq!(indirect[0].path, vec!["REQ", "THX", "SRC"]);
+        assert_eq!(indirect[1].node_id, "IMP");
+        assert_eq!(indirect[1].source_id, "SRC");
+        assert_eq!(indirect[1].hops, 3);
+    }
+
+    #[test]
+    fn test_find_transitive_drift_skips_direct_and_cycles() {
+        // A and B both bind the changed C directly; B also depends on A; A and B form a cycle
+        let mut index = NodeIndex::new();
+        index.insert("C".into(), req("C", "2.0.0", None, None));
+        index.insert(
+            "A".into(),
+            req(
+                "A",
+                "1.0.0",
+                depends_on(&[("C", "1.0.0"), ("B", "1.0.0")]),
+                None,
+            ),
+        );
+        index.insert(
+            "B".into(),
+            req(
+                "B",
+                "1.0.0",
+                depends_on(&[("C", "1.0.0"), ("A", "1.0.0")]),
+                None,
+            ),
+        );
+
+        let direct = drift_reports(&index);
+        assert_eq!(direct.len(), 2);
+        assert!(find_transitive_drift(&index, &direct).is_empty());
+    }
+
+    // --- find_impact ---
+
+    #[test]
+    fn test_find_impact_walks_all_incoming_edge_types() {
+        // THX <-derives_from- REQ <-satisfies- IMP (binds src/a.rs); OTHER depends_on REQ
+        let mut index = NodeIndex::new();
+        index.insert("THX".into(), req("THX", "1.0.0", None, None));
+        index.insert(
+            "REQ".into(),
+            req(
+                "REQ",
+                "1.0.0",
+                Some(Edges {
+                    derives_from: Some(vec![edge_ref("THX", "1.0.0")]),
+                    ..Default::default()
+                }),
+                None,
+            ),
+        );
+        let mut imp = req(
+            "IMP",
+            "1.0.0",
+            Some(Edges {
+                satisfies: Some(vec![edge_ref("REQ", "1.0.0")]),
+                ..Default::default()
+            }),
+            None,
+        );
+        imp.node_type = NodeType::Implementation;
+        imp.meta = Some(NodeMeta::Implementation(ImplementationMeta {
+            language: None,
+            files: Some(vec![FileRef {
+                path: "src/a.rs".to_string(),
+                functions: None,
+            }]),
+            test_command: None,
+        }));
+        index.insert("IMP".into(), imp);
+        index.insert(
+            "OTHER".into(),
+            req("OTHER", "1.0.0", depends_on(&[("REQ", "1.0.0")]), None),
+        );
+
+        let impact = find_impact("THX", &index, None).unwrap();
+        let ids: Vec<_> = impact.nodes.iter().map(|n| n.node_id.as_str()).collect();
+        assert_eq!(ids, vec!["REQ", "IMP", "OTHER"]);
+        assert_eq!(impact.nodes[0].edge_type, "derives_from");
+        assert_eq!(impact.nodes[1].distance, 2);
+        assert_eq!(impact.nodes[1].via, "REQ");
+        assert_eq!(impact.nodes[1].node_type, "implementation");
+        assert_eq!(impact.files, vec!["src/a.rs"]);
+        assert_eq!(impact.by_distance()[&2].len(), 2);
+
+        let shallow = find_impact("THX", &index, Some(1)).unwrap();
+        assert_eq!(shallow.nodes.len(), 1);
+        assert!(shallow.files.is_empty());
+    }
+
+    #[test]
+    fn test_find_impact_handles_cycles_and_missing_root() {
+        let mut index = NodeIndex::new();
+        index.insert(
+            "A".into(),
+            req("A", "1.0.0", depends_on(&[("B", "1.0.0")]), None),
+        );
+        index.insert(
+            "B".into(),
+            req("B", "1.0.0", depends_on(&[("A", "1.0.0")]), None),
+        );
+
+        let impact = find_impact("A", &index, None).unwrap();
+        assert_eq!(impact.nodes.len(), 1);
+        assert_eq!(impact.nodes[0].node_id, "B");
+        assert!(find_impact("MISSING", &index, None).is_none());
+    }
+
+    // --- build_reverse_index ---
+
+    #[test]
+    fn test_build_reverse_index_inverts_edges() {
+        // B and C point at A (C via A's former ID); A points at itself
+        let mut a = req("A", "1.0.0", depends_on(&[("A", "1.0.0")]), None);
+        a.aliases = Some(vec!["OLD-A".to_string()]);
+        let mut index = NodeIndex::new();
+        index.insert("A".into(), a);
+        index.insert(
+            "C".into(),
+            req("C", "1.0.0", depends_on(&[("OLD-A", "1.0.0")]), None),
+        );
+        index.insert(
+            "B".into(),
+            req(
+                "B",
+                "1.0.0",
+                Some(Edges {
+                    depends_on: Some(vec![edge_ref("A", "1.0.0"), edge_ref("GONE", "1.0.0")]),
+                    satisfies: Some(vec![edge_ref("A", "0.9.0")]),
+                    ..Default::default()
+                }),
+                None,
+            ),
+        );
+
+        let reverse = build_reverse_index(&index);
+        let incoming: Vec<_> = reverse["A"]
+            .iter()
+            .map(|r| (r.from_id.as_str(), r.edge_type.as_str()))
+            .collect();
+        assert_eq!(
+            incoming,
+            vec![("B", "depends_on"), ("B", "satisfies"), ("C", "depends_on")]
+        );
+        assert_eq!(reverse["A"][1].version.as_deref(), Some("0.9.0"));
+        assert_eq!(reverse["A"][0].from_type, "requirement");
+        assert!(!reverse.contains_key("GONE"));
+        assert!(!reverse.contains_key("B"));
+    }
+
+    // --- trace_paths ---
+
+    /// IMP -satisfies-> REQ -derives_from-> THX -supported_by-> SRC, plus a
+    /// shortcut IMP -depends_on-> THX bound to an old THX version.
+    fn trace_index() -> NodeIndex {
+        let mut index = NodeIndex::new();
+        index.insert("SRC".into(), req("SRC", "1.0.0", None, None));
+        index.insert(
+            "THX".into(),
+            req(
+                "THX",
+                "1.1.0",
+                Some(Edges {
+                    supported_by: Some(vec![edge_ref("SRC", "1.0.0")]),
+                    ..Default::default()
+                }),
+                None,
+            ),
+        );
+        index.insert(
+            "REQ".into(),
+            req(
+                "REQ",
+                "1.0.0",
+                Some(Edges {
+                    derives_from: Some(vec![EdgeReference {
+                        target: "THX".to_string(),
+                        version: Some("1.1.0".to_string()),
+                        rationale: Some("core idea".to_string()),
+                    }]),
+                    ..Default::default()
+                }),
+                None,
+            ),
+        );
+        index.insert(
+            "IMP".into(),
+            req(
+                "IMP",
+                "1.0.0",
+                Some(Edges {
+                    satisfies: Some(vec![edge_ref("REQ", "1.0.0")]),
+                    depends_on: Some(vec![edge_ref("THX", "1.0.0")]),
+                    ..Default::default()
+                }),
+                None,
+            ),
+        );
+        index
+    }
+
+    #[test]
+    fn test_trace_paths_shortest_and_all() {
+        let index = trace_index();
+
+        let shortest = trace_paths("IMP", Some("SRC"), &index, false, 10, None);
+        assert_eq!(shortest.len(), 1);
+        let hops: Vec<_> = shortest[0]
+            .iter()
+            .map(|h| (h.from.as_str(), h.edge_type.as_str(), h.to.as_str()))
+            .collect();
+        assert_eq!(
+            hops,
+            vec![("IMP", "depends_on", "THX"), ("THX", "supported_by", "SRC")]
+        );
+        assert_eq!(shortest[0][0].drift, Some(DriftSeverity::Minor));
+        assert_eq!(shortest[0][1].drift, None);
+
+        let all = trace_paths("IMP", Some("SRC"), &index, true, 10, None);
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[1].len(), 3);
+        assert_eq!(all[1][1].rationale.as_deref(), Some("core idea"));
+        assert_eq!(
+            trace_paths("IMP", Some("SRC"), &index, true, 1, None).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_trace_paths_all_is_bounded_and_shortest_first() {
+        // 20 nodes that all depend on each other, with one exit to SRC from
+        // N19: far too many simple paths to enumerate
+        let mut index = trace_index();
+        let ids: Vec<String> = (0..20).map(|i| format!("N{i:02}")).collect();
+        for (i, id) in ids.iter().enumerate() {
+            let mut targets: Vec<EdgeReference> = ids
+                .iter()
+                .filter(|other| *other != id)
+                .map(|other| edge_ref(other, "1.0.0"))
+                .collect();
+            if i == 19 {
+                targets.push(edge_ref("SRC", "1.0.0"));
+            }
+            let edges = Edges {
+                depends_on: Some(targets),
+                ..Default::default()
+            };
+            index.insert(id.clone(), req(id, "1.0.0", Some(edges), None));
+        }
+
+        let paths = trace_paths("N00", Some("SRC"), &index, true, 5, None);
+        assert_eq!(paths.len(), 5);
+        assert_eq!(paths[0].len(), 2);
+        assert!(paths.windows(2).all(|w| w[0].len() <= w[1].len()));
+        assert!(paths.iter().all(|p| p.len() <= 3));
+        // The expansion budget stops an exhaustive search
+        assert!(!trace_paths("N00", Some("SRC"), &index, true, usize::MAX, None).is_empty());
+
+        assert_eq!(
+            trace_paths("N00", Some("SRC"), &index, true, 50, Some(2)).len(),
+            1
+        );
+        assert!(trace_paths("IMP", Some("SRC"), &index, false, 10, Some(1)).is_empty());
+    }
+
+    #[test]
+    fn test_trace_paths_without_target_ends_at_leaves() {
+        let index = trace_index();
+
+        let paths = trace_paths("REQ", None, &index, false, 10, None);
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].last().unwrap().to, "SRC");
+        assert!(trace_paths("SRC", None, &index, false, 10, None).is_empty());
+        assert!(trace_paths("SRC", Some("IMP"), &index, true, 10, None).is_empty());
+        assert!(trace_paths("MISSING", None, &index, false, 10, None).is_empty());
+    }
 }
@@ -0,0 +1,752 @@
+//! Graph export for Lattice: DOT, Mermaid, GraphML and Cytoscape.js.
+//!
+//! Renders nodes with a shape and color per node type, edges with a style per
+//! edge type (from `push::flatten_edges`, plus the debate edges the push
+//! payload leaves out), and highlights drifted and
+//! contested nodes. Can be limited to the neighbourhood of one node.
+
+use crate::export::LatticeData;
+use crate::graph::{drift_reports, lookup_node};
+use crate::push::{FlatEdge, flatten_edges};
+use crate::types::{LatticeNode, NodeIndex, NodeType, Status};
+use serde_json::json;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Write as _;
+
+const DRIFTED_COLOR: &str = "#ea580c";
+const CONTESTED_COLOR: &str = "#dc2626";
+
+/// Graph output format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+    GraphMl,
+    Cytoscape,
+}
+
+impl std::str::FromStr for GraphFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "dot" => Ok(GraphFormat::Dot),
+            "mermaid" => Ok(GraphFormat::Mermaid),
+            "graphml" => Ok(GraphFormat::GraphMl),
+            "cytoscape" => Ok(GraphFormat::Cytoscape),
+            _ => Err(format!("Invalid graph format: {}", s)),
+        }
+    }
+}
+
+/// Options for graph export.
+pub struct GraphExportOptions {
+    pub format: GraphFormat,
+    pub title: String,
+    pub include_internal: bool,
+    /// Only export nodes connected to this one (edges followed both ways).
+    pub root: Option<String>,
+    /// With `root`, only follow this many edges from it.
+    pub depth: Option<usize>,
+}
+
+struct GraphNode<'a> {
+    node: &'a LatticeNode,
+    drifted: bool,
+    contested: bool,
+}
+
+struct GraphEdge {
+    source: String,
+    target: String,
+    edge_type: String,
+}
+
+fn type_name(node_type: &NodeType) -> String {
+    format!("{:?}", node_type).to_lowercase()
+}
+
+/// (DOT shape, fill color, border color) for a node type.
+fn node_style(node_type: &NodeType) -> (&'static str, &'static str, &'static str) {
+    match node_type {
+        NodeType::Source => ("note", "#dbeafe", "#2563eb"),
+        NodeType::Thesis => ("ellipse", "#ede9fe", "#7c3aed"),
+        NodeType::Requirement => ("box", "#dcfce7", "#16a34a"),
+        NodeType::Implementation => ("component", "#fef3c7", "#d97706"),
+        NodeType::Message => ("cds", "#f3f4f6", "#6b7280"),
+    }
+}
+
+/// (line style, color) for a flat edge type.
+fn edge_style(edge_type: &str) -> (&'static str, &'static str) {
+    match edge_type {
+        "supports" | "grounded-in" => ("solid", "#2563eb"),
+        "derives-from" => ("solid", "#7c3aed"),
+        "satisfies" | "validates" => ("bold", "#16a34a"),
+        "depends-on" => ("dashed", "#475569"),
+        "extends" => ("solid", "#0891b2"),
+        "reveals-gap-in" => ("dotted", "#d97706"),
+        "challenges" | "conflicts-with" | "rebuts" => ("dotted", CONTESTED_COLOR),
+        "supersedes" | "concedes" => ("dashed", "#9ca3af"),
+        _ => ("solid", "#6b7280"),
+    }
+}
+
+/// Shorten long titles so labels stay readable.
+fn short_title(title: &str) -> String {
+    if title.chars().count() > 40 {
+        format!("{}…", title.chars().take(39).collect::<String>())
+    } else {
+        title.to_string()
+    }
+}
+
+/// `rebuts`, `concedes` and `grounded_in` edges, in the same flat form as
+/// `flatten_edges`, which does not send them with `lattice push`.
+fn debate_edges(nodes: &[LatticeNode]) -> Vec<FlatEdge> {
+    let mut flat = Vec::new();
+    for node in nodes {
+        let Some(edges) = &node.edges else {
+            continue;
+        };
+        for (refs, edge_type) in [
+            (&edges.rebuts, "rebuts"),
+            (&edges.concedes, "concedes"),
+            (&edges.grounded_in, "grounded-in"),
+        ] {
+            for r in refs.iter().flatten() {
+                flat.push(FlatEdge {
+                    source: node.id.clone(),
+                    target: r.target.clone(),
+                    edge_type: edge_type.to_string(),
+                });
+            }
+        }
+    }
+    flat
+}
+
+/// Collect the nodes and edges to render, applying visibility and root/depth
+/// filtering. Edge endpoints are resolved through aliases; edges to nodes
+/// outside the selection are dropped.
+fn select<'a>(
+    nodes: &[&'a LatticeNode],
+    options: &GraphExportOptions,
+) -> Result<(Vec<GraphNode<'a>>, Vec<GraphEdge>), String> {
+    let index: NodeIndex = nodes.iter().map(|n| (n.id.clone(), (*n).clone())).collect();
+    let drifted: HashSet<String> = drift_reports(&index)
+        .into_iter()
+        .map(|r| r.node_id)
+        .collect();
+
+    let owned: Vec<LatticeNode> = nodes.iter().map(|n| (*n).clone()).collect();
+    let mut edges: Vec<GraphEdge> = flatten_edges(&owned)
+        .into_iter()
+        .chain(debate_edges(&owned))
+        .filter_map(|e| {
+            let source = lookup_node(&index, &e.source)?.id.clone();
+            let target = lookup_node(&index, &e.target)?.id.clone();
+            Some(GraphEdge {
+                source,
+                target,
+                edge_type: e.edge_type,
+            })
+        })
+        .collect();
+    edges.sort_by(|a, b| {
+        (&a.source, &a.target, &a.edge_type).cmp(&(&b.source, &b.target, &b.edge_type))
+    });
+    edges.dedup_by(|a, b| {
+        a.source == b.source && a.target == b.target && a.edge_type == b.edge_type
+    });
+
+    let keep: Option<HashSet<String>> = match &options.root {
+        None => None,
+        Some(root) => {
+            let root = lookup_node(&index, root)
+                .ok_or_else(|| format!("Node not found: {}", root))?
+                .id
+                .clone();
+            let mut neighbours: HashMap<&str, Vec<&str>> = HashMap::new();
+            for e in &edges {
+                neighbours.entry(&e.source).or_default().push(&e.target);
+                neighbours.entry(&e.target).or_default().push(&e.source);
+            }
+            let mut seen = HashSet::from([root.clone()]);
+            let mut queue = VecDeque::from([(root, 0)]);
+            while let Some((id, dist)) = queue.pop_front() {
+                if options.depth.is_some_and(|d| dist >= d) {
+                    continue;
+                }
+                for &next in neighbours.get(id.as_str()).into_iter().flatten() {
+                    if seen.insert(next.to_string()) {
+                        queue.push_back((next.to_string(), dist + 1));
+                    }
+                }
+            }
+            Some(seen)
+        }
+    };
+
+    let selected = |id: &str| keep.as_ref().is_none_or(|k| k.contains(id));
+    edges.retain(|e| selected(&e.source) && selected(&e.target));
+    let graph_nodes = nodes
+        .iter()
+        .filter(|n| selected(&n.id))
+        .map(|n| GraphNode {
+            node: n,
+            drifted: drifted.contains(&n.id),
+            contested: n.status == Status::Contested,
+        })
+        .collect();
+
+    Ok((graph_nodes, edges))
+}
+
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn to_dot(title: &str, nodes: &[GraphNode], edges: &[GraphEdge]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "digraph lattice {{");
+    let _ = writeln!(out, "  label=\"{}\";", dot_escape(title));
+    let _ = writeln!(out, "  labelloc=t;");
+    let _ = writeln!(out, "  rankdir=LR;");
+    let _ = writeln!(out, "  node [style=filled, fontname=\"Helvetica\"];");
+    let _ = writeln!(out, "  edge [fontname=\"Helvetica\", fontsize=10];");
+    let _ = writeln!(out);
+
+    for n in nodes {
+        let (shape, fill, border) = node_style(&n.node.node_type);
+        let label = format!(
+            "{}\\n{}",
+            dot_escape(&n.node.id),
+            dot_escape(&short_title(&n.node.title))
+        );
+        let mut attrs = format!(
+            "label=\"{}\", shape={}, fillcolor=\"{}\"",
+            label, shape, fill
+        );
+        // Contested nodes get a dashed outline; drifted ones an orange border
+        let color = match (n.contested, n.drifted) {
+            (true, _) => CONTESTED_COLOR,
+            (false, true) => DRIFTED_COLOR,
+            (false, false) => border,
+        };
+        let _ = write!(attrs, ", color=\"{}\"", color);
+        if n.contested || n.drifted {
+            attrs.push_str(", penwidth=3");
+        }
+        if n.contested {
+            attrs.push_str(", style=\"filled,dashed\"");
+        }
+        let _ = writeln!(out, "  \"{}\" [{}];", dot_escape(&n.node.id), attrs);
+    }
+    if !edges.is_empty() {
+        let _ = writeln!(out);
+    }
+    for e in edges {
+        let (line, color) = edge_style(&e.edge_type);
+        let _ = writeln!(
+            out,
+            "  \"{}\" -> \"{}\" [label=\"{}\", style={}, color=\"{}\"];",
+            dot_escape(&e.source),
+            dot_escape(&e.target),
+            e.edge_type,
+            line,
+            color
+        );
+    }
+    let _ = writeln!(out, "}}");
+    out
+}
+
+/// Mermaid node IDs may not contain `-` or other punctuation. Every other
+/// character becomes `_<hex>_`, so distinct IDs such as `REQ-A` and `REQ_A`
+/// stay distinct.
+fn mermaid_id(id: &str) -> String {
+    let mut out = String::with_capacity(id.len());
+    for c in id.chars() {
+        if c.is_ascii_alphanumeric() {
+            out.push(c);
+        } else {
+            let _ = write!(out, "_{:x}_", c as u32);
+        }
+    }
+    out
+}
+
+/// Escape text for a quoted Mermaid label: characters that end the label or
+/// the shape become entity codes, and line breaks become spaces.
+fn mermaid_text(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '#' => out.push_str("#35;"),
+            '"' => out.push_str("#quot;"),
+            '[' => out.push_str("#91;"),
+            ']' => out.push_str("#93;"),
+            '|' => out.push_str("#124;"),
+            '\n' | '\r' => out.push(' '),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn to_mermaid(title: &str, nodes: &[GraphNode], edges: &[GraphEdge]) -> String {
+    let mut out = String::new();
+    // A JSON string is a valid YAML double-quoted scalar
+    let _ = writeln!(out, "---\ntitle: {}\n---", serde_json::Value::from(title));
+    let _ = writeln!(out, "flowchart LR");
+
+    for n in nodes {
+        let label = format!(
+            "{}<br/>{}",
+            mermaid_text(&n.node.id),
+            mermaid_text(&short_title(&n.node.title))
+        );
+        let (open, close) = match n.node.node_type {
+            NodeType::Source => ("[(", ")]"),
+            NodeType::Thesis => ("([", "])"),
+            NodeType::Requirement => ("[", "]"),
+            NodeType::Implementation => ("[[", "]]"),
+            NodeType::Message => (">", "]"),
+        };
+        let _ = writeln!(
+            out,
+            "  {}{}\"{}\"{}",
+            mermaid_id(&n.node.id),
+            open,
+            label,
+            close
+        );
+    }
+
+    for e in edges {
+        let arrow = match edge_style(&e.edge_type).0 {
+            "bold" => "==>",
+            "dashed" | "dotted" => "-.->",
+            _ => "-->",
+        };
+        let _ = writeln!(
+            out,
+            "  {} {}|{}| {}",
+            mermaid_id(&e.source),
+            arrow,
+            e.edge_type,
+            mermaid_id(&e.target)
+        );
+    }
+
+    for node_type in [
+        NodeType::Source,
+        NodeType::Thesis,
+        NodeType::Requirement,
+        NodeType::Implementation,
+        NodeType::Message,
+    ] {
+        let ids: Vec<String> = nodes
+            .iter()
+            .filter(|n| n.node.node_type == node_type)
+            .map(|n| mermaid_id(&n.node.id))
+            .collect();
+        if ids.is_empty() {
+            continue;
+        }
+        let (_, fill, border) = node_style(&node_type);
+        let name = type_name(&node_type);
+        let _ = writeln!(out, "  classDef {} fill:{},stroke:{}", name, fill, border);
+        let _ = writeln!(out, "  class {} {}", ids.join(","), name);
+    }
+    for (class, color, flag) in [
+        (
+            "drifted",
+            DRIFTED_COLOR,
+            (|n: &GraphNode| n.drifted) as fn(&GraphNode) -> bool,
+        ),
+        ("contested", CONTESTED_COLOR, |n: &GraphNode| n.contested),
+    ] {
+        let ids: Vec<String> = nodes
+            .iter()
+            .filter(|n| flag(n))
+            .map(|n| mermaid_id(&n.node.id))
+            .collect();
+        if !ids.is_empty() {
+            let _ = writeln!(
+                out,
+                "  classDef {} stroke:{},stroke-width:3px",
+                class, color
+            );
+            let _ = writeln!(out, "  class {} {}", ids.join(","), class);
+        }
+    }
+    for (i, e) in edges.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "  linkStyle {} stroke:{}",
+            i,
+            edge_style(&e.edge_type).1
+        );
+    }
+    out
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn to_graphml(title: &str, nodes: &[GraphNode], edges: &[GraphEdge]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(
+        out,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+    );
+    for (id, attr_type) in [
+        ("type", "string"),
+        ("title", "string"),
+        ("version", "string"),
+        ("status", "string"),
+        ("drifted", "boolean"),
+        ("contested", "boolean"),
+        ("color", "string"),
+        ("shape", "string"),
+    ] {
+        let _ = writeln!(
+            out,
+            r#"  <key id="{id}" for="node" attr.name="{id}" attr.type="{attr_type}"/>"#
+        );
+    }
+    let _ = writeln!(
+        out,
+        r#"  <key id="edge_type" for="edge" attr.name="type" attr.type="string"/>"#
+    );
+    let _ = writeln!(
+        out,
+        r#"  <key id="edge_style" for="edge" attr.name="style" attr.type="string"/>"#
+    );
+    let _ = writeln!(
+        out,
+        r#"  <graph id="{}" edgedefault="directed">"#,
+        xml_escape(title)
+    );
+
+    for n in nodes {
+        let (shape, fill, _) = node_style(&n.node.node_type);
+        let _ = writeln!(out, r#"    <node id="{}">"#, xml_escape(&n.node.id));
+        for (key, value) in [
+            ("type", type_name(&n.node.node_type)),
+            ("title", n.node.title.clone()),
+            ("version", n.node.version.clone()),
+            ("status", format!("{:?}", n.node.status).to_lowercase()),
+            ("drifted", n.drifted.to_string()),
+            ("contested", n.contested.to_string()),
+            ("color", fill.to_string()),
+            ("shape", shape.to_string()),
+        ] {
+            let _ = writeln!(
+                out,
+                r#"      <data key="{}">{}</data>"#,
+                key,
+                xml_escape(&value)
+            );
+        }
+        let _ = writeln!(out, "    </node>");
+    }
+    for (i, e) in edges.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            r#"    <edge id="e{}" source="{}" target="{}">"#,
+            i,
+            xml_escape(&e.source),
+            xml_escape(&e.target)
+        );
+        let _ = writeln!(out, r#"      <data key="edge_type">{}</data>"#, e.edge_type);
+        let _ = writeln!(
+            out,
+            r#"      <data key="edge_style">{}</data>"#,
+            edge_style(&e.edge_type).0
+        );
+        let _ = writeln!(out, "    </edge>");
+    }
+    let _ = writeln!(out, "  </graph>");
+    let _ = writeln!(out, "</graphml>");
+    out
+}
+
+fn to_cytoscape(title: &str, nodes: &[GraphNode], edges: &[GraphEdge]) -> String {
+    let node_elements: Vec<_> = nodes
+        .iter()
+        .map(|n| {
+            let mut classes = vec![type_name(&n.node.node_type)];
+            if n.drifted {
+                classes.push("drifted".to_string());
+            }
+            if n.contested {
+                classes.push("contested".to_string());
+            }
+            json!({
+                "data": {
+                    "id": n.node.id,
+                    "label": format!("{}\n{}", n.node.id, short_title(&n.node.title)),
+                    "title": n.node.title,
+                    "type": type_name(&n.node.node_type),
+                    "version": n.node.version,
+                    "status": format!("{:?}", n.node.status).to_lowercase(),
+                    "drifted": n.drifted,
+                    "contested": n.contested,
+                },
+                "classes": classes.join(" "),
+            })
+        })
+        .collect();
+    let edge_elements: Vec<_> = edges
+        .iter()
+        .enumerate()
+        .map(|(i, e)| {
+            json!({
+                "data": {
+                    "id": format!("e{}", i),
+                    "source": e.source,
+                    "target": e.target,
+                    "type": e.edge_type,
+                },
+                "classes": e.edge_type,
+            })
+        })
+        .collect();
+
+    let mut style = vec![
+        json!({"selector": "node", "style": {"label": "data(label)", "text-wrap": "wrap", "border-width": 1}}),
+        json!({"selector": "edge", "style": {"label": "data(type)", "curve-style": "bezier", "target-arrow-shape": "triangle", "font-size": 10}}),
+    ];
+    for node_type in [
+        NodeType::Source,
+        NodeType::Thesis,
+        NodeType::Requirement,
+        NodeType::Implementation,
+        NodeType::Message,
+    ] {
+        let (_, fill, border) = node_style(&node_type);
+        style.push(json!({
+            "selector": format!("node.{}", type_name(&node_type)),
+            "style": {"background-color": fill, "border-color": border},
+        }));
+    }
+    let mut edge_types: Vec<&str> = edges.iter().map(|e| e.edge_type.as_str()).collect();
+    edge_types.sort();
+    edge_types.dedup();
+    for edge_type in edge_types {
+        let (line, color) = edge_style(edge_type);
+        let (line_style, width) = match line {
+            "bold" => ("solid", 3),
+            other => (other, 1),
+        };
+        style.push(json!({
+            "selector": format!("edge.{}", edge_type),
+            "style": {"line-style": line_style, "width": width, "line-color": color, "target-arrow-color": color},
+        }));
+    }
+    style.push(json!({"selector": "node.drifted", "style": {"border-color": DRIFTED_COLOR, "border-width": 3}}));
+    style.push(json!({"selector": "node.contested", "style": {"border-color": CONTESTED_COLOR, "border-width": 3, "border-style": "dashed"}}));
+
+    let doc = json!({
+        "title": title,
+        "elements": {
+            "nodes": node_elements,
+            "edges": edge_elements,
+        },
+        "style": style,
+    });
+    serde_json::to_string_pretty(&doc).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Render the lattice (or the neighbourhood of `options.root`) as a graph.
+pub fn export_graph(data: &LatticeData, options: &GraphExportOptions) -> Result<String, String> {
+    let nodes: Vec<&LatticeNode> = [
+        &data.sources,
+        &data.theses,
+        &data.requirements,
+        &data.implementations,
+        &data.messages,
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|n| options.include_internal || n.visibility.as_deref() != Some("internal"))
+    .collect();
+
+    let (nodes, edges) = select(&nodes, options)?;
+    Ok(match options.format {
+        GraphFormat::Dot => to_dot(&options.title, &nodes, &edges),
+        GraphFormat::Mermaid => to_mermaid(&options.title, &nodes, &edges),
+        GraphFormat::GraphMl => to_graphml(&options.title, &nodes, &edges),
+        GraphFormat::Cytoscape => to_cytoscape(&options.title, &nodes, &edges),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::*;
+
+    /// SRC-A <-supported_by- THX-A (contested) <-derives_from- REQ-A
+    /// <-satisfies- IMP-A (bound to an old REQ-A, so drifted).
+    fn sample_data() -> LatticeData {
+        let mut thesis = LatticeNode {
+            status: Status::Contested,
+            edges: Some(Edges {
+                supported_by: Some(vec![EdgeReference::test_edge("SRC-A", "1.0.0")]),
+                ..Default::default()
+            }),
+            ..LatticeNode::test_node("THX-A", NodeType::Thesis)
+        };
+        let mut source = LatticeNode::test_node("SRC-A", NodeType::Source);
+        let mut requirement = LatticeNode {
+            version: "2.0.0".to_string(),
+            edges: Some(Edges {
+                derives_from: Some(vec![EdgeReference::test_edge("THX-A", "1.0.0")]),
+                ..Default::default()
+            }),
+            ..LatticeNode::test_node("REQ-A", NodeType::Requirement)
+        };
+        let mut implementation = LatticeNode {
+            edges: Some(Edges {
+                satisfies: Some(vec![EdgeReference::test_edge("REQ-A", "1.0.0")]),
+                ..Default::default()
+            }),
+            ..LatticeNode::test_node("IMP-A", NodeType::Implementation)
+        };
+        // Quoted titles exercise label escaping
+        for node in [
+            &mut thesis,
+            &mut source,
+            &mut requirement,
+            &mut implementation,
+        ] {
+            node.title = format!("Title \"{}\"", node.id);
+        }
+        LatticeData {
+            sources: vec![source],
+            theses: vec![thesis],
+            requirements: vec![requirement],
+            implementations: vec![implementation],
+            messages: vec![],
+        }
+    }
+
+    fn options(format: GraphFormat) -> GraphExportOptions {
+        GraphExportOptions {
+            format,
+            title: "Test".to_string(),
+            include_internal: false,
+            root: None,
+            depth: None,
+        }
+    }
+
+    #[test]
+    fn test_export_dot_styles_and_highlights() {
+        let dot = export_graph(&sample_data(), &options(GraphFormat::Dot)).unwrap();
+        assert!(dot.starts_with("digraph lattice {"));
+        assert!(dot.contains(r#""SRC-A" [label="SRC-A\nTitle \"SRC-A\"", shape=note"#));
+        assert!(dot.contains(r#""SRC-A" -> "THX-A" [label="supports""#));
+        assert!(dot.contains(r#""IMP-A" -> "REQ-A" [label="satisfies", style=bold"#));
+        let imp = dot.lines().find(|l| l.contains(r#""IMP-A" ["#)).unwrap();
+        assert!(imp.contains(DRIFTED_COLOR));
+        let thx = dot.lines().find(|l| l.contains(r#""THX-A" ["#)).unwrap();
+        assert!(thx.contains("filled,dashed"));
+    }
+
+    #[test]
+    fn test_export_mermaid_sanitizes_ids_and_classes() {
+        let mermaid = export_graph(&sample_data(), &options(GraphFormat::Mermaid)).unwrap();
+        assert!(mermaid.contains("flowchart LR"));
+        assert!(mermaid.contains("  REQ_2d_A[\"REQ-A<br/>Title #quot;REQ-A#quot;\"]"));
+        assert!(mermaid.contains("  IMP_2d_A ==>|satisfies| REQ_2d_A"));
+        assert!(mermaid.contains("  class IMP_2d_A drifted"));
+        assert!(mermaid.contains("  class THX_2d_A contested"));
+        assert!(mermaid.contains("  linkStyle 2 stroke:"));
+    }
+
+    #[test]
+    fn test_export_mermaid_escapes_ids_and_labels() {
+        let mut data = sample_data();
+        data.requirements.push(LatticeNode {
+            title: "Pipes | and [brackets]\nover #two lines".to_string(),
+            ..LatticeNode::test_node("REQ_A", NodeType::Requirement)
+        });
+        let mut opts = options(GraphFormat::Mermaid);
+        opts.title = "Q3: \"plan\"\nDraft".to_string();
+
+        let mermaid = export_graph(&data, &opts).unwrap();
+        assert!(mermaid.starts_with("---\ntitle: \"Q3: \\\"plan\\\"\\nDraft\"\n---\n"));
+        assert_ne!(mermaid_id("REQ-A"), mermaid_id("REQ_A"));
+        assert!(mermaid.contains(
+            "  REQ_5f_A[\"REQ_A<br/>Pipes #124; and #91;brackets#93; over #35;two lines\"]"
+        ));
+        assert!(mermaid.contains("  REQ_2d_A[\"REQ-A<br/>"));
+    }
+
+    #[test]
+    fn test_export_graphml_and_cytoscape() {
+        let graphml = export_graph(&sample_data(), &options(GraphFormat::GraphMl)).unwrap();
+        assert!(graphml.contains(r#"<node id="REQ-A">"#));
+        assert!(graphml.contains("Title &quot;REQ-A&quot;"));
+        assert!(graphml.contains(r#"<edge id="e0" source="IMP-A" target="REQ-A">"#));
+        assert_eq!(graphml.matches("<edge ").count(), 3);
+
+        let cy = export_graph(&sample_data(), &options(GraphFormat::Cytoscape)).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&cy).unwrap();
+        assert_eq!(value["elements"]["nodes"].as_array().unwrap().len(), 4);
+        let imp = &value["elements"]["nodes"].as_array().unwrap()[3];
+        assert_eq!(imp["classes"], "implementation drifted");
+        assert!(
+            value["style"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|s| s["selector"] == "edge.depends-on" || s["selector"] == "edge.satisfies")
+        );
+    }
+
+    #[test]
+    fn test_export_includes_debate_edges() {
+        let mut data = sample_data();
+        data.theses.push(LatticeNode {
+            edges: Some(Edges {
+                rebuts: Some(vec![EdgeReference::test_edge("THX-A", "1.0.0")]),
+                grounded_in: Some(vec![EdgeReference::test_edge("SRC-A", "1.0.0")]),
+                ..Default::default()
+            }),
+            ..LatticeNode::test_node("THX-B", NodeType::Thesis)
+        });
+        let dot = export_graph(&data, &options(GraphFormat::Dot)).unwrap();
+        assert!(dot.contains(r#""THX-B" -> "THX-A" [label="rebuts""#));
+        assert!(dot.contains(r#""THX-B" -> "SRC-A" [label="grounded-in""#));
+
+        // The push payload is unchanged
+        let pushed = flatten_edges(&data.theses);
+        assert!(pushed.iter().all(|e| e.source != "THX-B"));
+    }
+
+    #[test]
+    fn test_export_graph_root_and_depth_filter() {
+        let mut opts = options(GraphFormat::GraphMl);
+        opts.root = Some("REQ-A".to_string());
+        opts.depth = Some(1);
+        let graphml = export_graph(&sample_data(), &opts).unwrap();
+        assert!(graphml.contains(r#"<node id="THX-A">"#));
+        assert!(graphml.contains(r#"<node id="IMP-A">"#));
+        assert!(!graphml.contains(r#"<node id="SRC-A">"#));
+        assert_eq!(graphml.matches("<edge ").count(), 2);
+
+        opts.root = Some("REQ-MISSING".to_string());
+        assert!(export_graph(&sample_data(), &opts).is_err());
+        assert!("svg".parse::<GraphFormat>().is_err());
+    }
+}
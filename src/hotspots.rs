@@ -0,0 +1,311 @@
+//! Hotspot analysis: which requirements and theses sit on the code that
+//! changes most.
+//!
+//! Churn comes from `git log --numstat` over the files bound by
+//! implementations. It rolls up to the requirements those implementations
+//! satisfy and to the theses those requirements derive from, and is weighted
+//! by drift and contested status:
+//!
+//! `score = lines changed × (1 + 0.5 × drift items) × (2 if contested)`
+
+use crate::graph::{drift_reports, lookup_node};
+use crate::types::{NodeIndex, NodeMeta, NodeType, Status};
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+use std::process::Command;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum HotspotError {
+    #[error("git command failed: {0}")]
+    GitError(String),
+}
+
+/// Changes to one file over the analysis window.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileChurn {
+    /// Lines added plus lines deleted.
+    pub lines: usize,
+    /// Commits that touched the file.
+    pub commits: BTreeSet<String>,
+}
+
+/// A requirement or thesis ranked by the churn under it.
+#[derive(Debug, Clone)]
+pub struct Hotspot {
+    pub node_id: String,
+    pub node_type: NodeType,
+    pub title: String,
+    /// Lines changed across all bound files.
+    pub lines: usize,
+    /// Distinct commits touching any bound file.
+    pub commits: usize,
+    /// Bound files that changed, with their line churn, busiest first.
+    pub files: Vec<(String, usize)>,
+    /// Implementations binding those files.
+    pub implementations: Vec<String>,
+    /// Drift items where this node is the stale or the changed side.
+    pub drift: usize,
+    pub contested: bool,
+    pub score: f64,
+}
+
+/// Parse `git log --numstat --format=%H` output into churn per file.
+/// Binary files count as changed with zero lines.
+pub fn parse_numstat(output: &str) -> HashMap<String, FileChurn> {
+    let mut churn: HashMap<String, FileChurn> = HashMap::new();
+    let mut commit = String::new();
+    for line in output.lines().filter(|l| !l.trim().is_empty()) {
+        let parts: Vec<&str> = line.splitn(3, '\t').collect();
+        let [added, deleted, path] = parts[..] else {
+            commit = line.trim().to_string();
+            continue;
+        };
+        let entry = churn.entry(path.to_string()).or_default();
+        entry.lines += added.parse::<usize>().unwrap_or(0) + deleted.parse::<usize>().unwrap_or(0);
+        entry.commits.insert(commit.clone());
+    }
+    churn
+}
+
+/// Churn per file (relative to `root`) for commits since `since`, which is
+/// anything `git log --since` accepts (e.g. "90 days ago", "2026-01-01").
+pub fn git_churn(root: &Path, since: &str) -> Result<HashMap<String, FileChurn>, HotspotError> {
+    let output = Command::new("git")
+        .args([
+            "log",
+            "--numstat",
+            "--no-renames",
+            "--relative",
+            "--format=%H",
+            &format!("--since={}", since),
+            "--",
+            ".",
+        ])
+        .current_dir(root)
+        .output()
+        .map_err(|e| HotspotError::GitError(format!("failed to run git log: {}", e)))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(HotspotError::GitError(format!(
+            "git log failed: {}",
+            stderr.trim()
+        )));
+    }
+
+    Ok(parse_numstat(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Rank requirements and theses by the churn of the files bound beneath
+/// them. Nodes whose files saw no changes are left out. Sorted by score,
+/// highest first, then by ID.
+pub fn find_hotspots(index: &NodeIndex, churn: &HashMap<String, FileChurn>) -> Vec<Hotspot> {
+    // Files and implementations under each requirement
+    let mut files_by_node: HashMap<String, BTreeSet<String>> = HashMap::new();
+    let mut impls_by_node: HashMap<String, BTreeSet<String>> = HashMap::new();
+    for imp in index
+        .values()
+        .filter(|n| n.node_type == NodeType::Implementation)
+    {
+        let Some(NodeMeta::Implementation(meta)) = &imp.meta else {
+            continue;
+        };
+        let files: Vec<String> = meta
+            .files
+            .iter()
+            .flatten()
+            .map(|f| f.path.trim_start_matches("./").to_string())
+            .filter(|p| churn.contains_key(p))
+            .collect();
+        if files.is_empty() {
+            continue;
+        }
+        let satisfied = imp
+            .edges
+            .iter()
+            .flat_map(|e| e.satisfies.iter().flatten())
+            .filter_map(|e| lookup_node(index, &e.target));
+        for req in satisfied {
+            files_by_node
+                .entry(req.id.clone())
+                .or_default()
+                .extend(files.iter().cloned());
+            impls_by_node
+                .entry(req.id.clone())
+                .or_default()
+                .insert(imp.id.clone());
+        }
+    }
+
+    // Theses inherit from the requirements that derive from them
+    let requirement_ids: Vec<String> = files_by_node.keys().cloned().collect();
+    for req_id in requirement_ids {
+        let theses = index[&req_id]
+            .edges
+            .iter()
+            .flat_map(|e| e.derives_from.iter().flatten())
+            .filter_map(|e| lookup_node(index, &e.target))
+            .filter(|n| n.node_type == NodeType::Thesis);
+        for thesis in theses {
+            let files = files_by_node[&req_id].clone();
+            let impls = impls_by_node[&req_id].clone();
+            files_by_node
+                .entry(thesis.id.clone())
+                .or_default()
+                .extend(files);
+            impls_by_node
+                .entry(thesis.id.clone())
+                .or_default()
+                .extend(impls);
+        }
+    }
+
+    let mut drift: HashMap<&str, usize> = HashMap::new();
+    let reports = drift_reports(index);
+    for report in &reports {
+        for item in &report.drift_items {
+            *drift.entry(report.node_id.as_str()).or_default() += 1;
+            *drift.entry(item.target_id.as_str()).or_default() += 1;
+        }
+    }
+
+    let mut hotspots: Vec<Hotspot> = files_by_node
+        .into_iter()
+        .filter_map(|(id, files)| {
+            let node = &index[&id];
+            let mut commits: BTreeSet<&str> = BTreeSet::new();
+            let mut files: Vec<(String, usize)> = files
+                .into_iter()
+                .map(|path| {
+                    let c = &churn[&path];
+                    commits.extend(c.commits.iter().map(String::as_str));
+                    (path, c.lines)
+                })
+                .collect();
+            files.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+            let lines: usize = files.iter().map(|(_, l)| l).sum();
+            if commits.is_empty() {
+                return None;
+            }
+            let drift = drift.get(id.as_str()).copied().unwrap_or(0);
+            let contested = node.status == Status::Contested;
+            let score =
+                lines as f64 * (1.0 + 0.5 * drift as f64) * if contested { 2.0 } else { 1.0 };
+            Some(Hotspot {
+                node_id: id.clone(),
+                node_type: node.node_type.clone(),
+                title: node.title.clone(),
+                lines,
+                commits: commits.len(),
+                files,
+                implementations: impls_by_node
+                    .remove(&id)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect(),
+                drift,
+                contested,
+                score,
+            })
+        })
+        .collect();
+    hotspots.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.node_id.cmp(&b.node_id))
+    });
+    hotspots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{EdgeReference, Edges, FileRef, ImplementationMeta, LatticeNode};
+
+    fn implementation(id: &str, satisfies: &str, files: &[&str]) -> LatticeNode {
+        let mut imp = LatticeNode {
+            edges: Some(Edges {
+                satisfies: Some(vec![EdgeReference::test_edge(satisfies, "1.0.0")]),
+                ..Default::default()
+            }),
+            ..LatticeNode::test_node(id, NodeType::Implementation)
+        };
+        imp.meta = Some(NodeMeta::Implementation(ImplementationMeta {
+            language: None,
+            files: Some(
+                files
+                    .iter()
+                    .map(|p| FileRef {
+                        path: p.to_string(),
+                        functions: None,
+                    })
+                    .collect(),
+            ),
+            test_command: None,
+        }));
+        imp
+    }
+
+    #[test]
+    fn test_parse_numstat() {
+        let output = "aaa\n\n10\t2\tsrc/a.rs\n-\t-\tlogo.png\n\nbbb\n\n3\t0\tsrc/a.rs\n";
+        let churn = parse_numstat(output);
+        assert_eq!(churn["src/a.rs"].lines, 15);
+        assert_eq!(churn["src/a.rs"].commits.len(), 2);
+        assert_eq!(churn["logo.png"].lines, 0);
+        assert!(churn["logo.png"].commits.contains("aaa"));
+    }
+
+    #[test]
+    fn test_find_hotspots_rolls_up_and_weights() {
+        // THX <- REQ-A <- IMP-A (src/a.rs, ./src/b.rs); REQ-B <- IMP-B (src/c.rs, bound to a stale REQ-B)
+        let mut index = NodeIndex::new();
+        let mut thesis = LatticeNode::test_node("THX", NodeType::Thesis);
+        thesis.status = Status::Contested;
+        index.insert("THX".into(), thesis);
+        index.insert(
+            "REQ-A".into(),
+            LatticeNode {
+                edges: Some(Edges {
+                    derives_from: Some(vec![EdgeReference::test_edge("THX", "1.0.0")]),
+                    ..Default::default()
+                }),
+                ..LatticeNode::test_node("REQ-A", NodeType::Requirement)
+            },
+        );
+        index.insert(
+            "REQ-B".into(),
+            LatticeNode {
+                version: "2.0.0".to_string(),
+                ..LatticeNode::test_node("REQ-B", NodeType::Requirement)
+            },
+        );
+        index.insert(
+            "IMP-A".into(),
+            implementation("IMP-A", "REQ-A", &["src/a.rs", "./src/b.rs"]),
+        );
+        index.insert(
+            "IMP-B".into(),
+            implementation("IMP-B", "REQ-B", &["src/c.rs"]),
+        );
+
+        let churn =
+            parse_numstat("c1\n\n10\t0\tsrc/a.rs\n5\t5\tsrc/b.rs\n\nc2\n\n12\t0\tsrc/c.rs\n");
+        let hotspots = find_hotspots(&index, &churn);
+        let ids: Vec<_> = hotspots.iter().map(|h| h.node_id.as_str()).collect();
+        assert_eq!(ids, vec!["THX", "REQ-A", "REQ-B"]);
+
+        let thx = &hotspots[0];
+        assert!(thx.contested);
+        assert_eq!(thx.lines, 20);
+        assert_eq!(thx.score, 40.0);
+        assert_eq!(thx.implementations, vec!["IMP-A"]);
+        assert_eq!(thx.files[0], ("src/a.rs".to_string(), 10));
+
+        let req_b = &hotspots[2];
+        assert_eq!(req_b.drift, 1);
+        assert_eq!(req_b.score, 18.0);
+        assert_eq!(req_b.commits, 1);
+    }
+}
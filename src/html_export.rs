@@ -416,6 +416,7 @@ mod tests {
         LatticeData {
             sources: vec![LatticeNode {
                 id: "SRC-001".to_string(),
+                aliases: None,
                 node_type: NodeType::Source,
                 title: "Test Source".to_string(),
                 body: "A test source".to_string(),
@@ -425,16 +426,19 @@ mod tests {
                 created_by: "test".to_string(),
                 requested_by: None,
                 priority: None,
+                estimate: None,
                 category: None,
                 tags: None,
                 acceptance: None,
                 visibility: None,
+                lint_ignore: None,
                 resolution: None,
                 meta: None,
                 edges: None,
             }],
             theses: vec![LatticeNode {
                 id: "THX-001".to_string(),
+                aliases: None,
                 node_type: NodeType::Thesis,
                 title: "Test Thesis".to_string(),
                 body: "A test thesis".to_string(),
@@ -444,10 +448,12 @@ mod tests {
                 created_by: "test".to_string(),
                 requested_by: None,
                 priority: None,
+                estimate: None,
                 category: None,
                 tags: None,
                 acceptance: None,
                 visibility: None,
+                lint_ignore: None,
                 resolution: None,
                 meta: None,
                 edges: None,
@@ -455,6 +461,7 @@ mod tests {
             requirements: vec![
                 LatticeNode {
                     id: "REQ-001".to_string(),
+                    aliases: None,
                     node_type: NodeType::Requirement,
                     title: "Test Requirement 1".to_string(),
                     body: "A test requirement".to_string(),
@@ -464,10 +471,12 @@ mod tests {
                     created_by: "test".to_string(),
                     requested_by: None,
                     priority: Some(Priority::P0),
+                    estimate: None,
                     category: None,
                     tags: None,
                     acceptance: None,
                     visibility: None,
+                    lint_ignore: None,
                     resolution: Some(ResolutionInfo {
                         status: Resolution::Verified,
                         reason: None,
@@ -479,6 +488,7 @@ mod tests {
                 },
                 LatticeNode {
                     id: "REQ-002".to_string(),
+                    aliases: None,
                     node_type: NodeType::Requirement,
                     title: "Test Requirement 2".to_string(),
                     body: "Another test requirement".to_string(),
@@ -488,10 +498,12 @@ mod tests {
                     created_by: "test".to_string(),
                     requested_by: None,
                     priority: Some(Priority::P1),
+                    estimate: None,
                     category: None,
                     tags: None,
                     acceptance: None,
                     visibility: None,
+                    lint_ignore: None,
                     resolution: None,
                     meta: None,
                     edges: None,
@@ -0,0 +1,353 @@
+//! Persistent node ID → file path index.
+//!
+//! Resolving a node ID used to mean deserializing every YAML file under
+//! `.lattice/` until the ID matched. The index caches that mapping at
+//! `~/.cache/lattice/<project-hash>/ids.json`, keyed by node ID, and
+//! revalidates each entry against the file's mtime and size, so only new or
+//! modified files are re-parsed. The cache is best-effort: if it is missing, corrupt, or
+//! cannot be written, lookups fall back to a full scan.
+//!
+//! Linked requirement: REQ-CORE-004
+
+use crate::search::cache_dir;
+use crate::storage::{LATTICE_DIR, StorageError, load_node};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use walkdir::WalkDir;
+
+/// Node type directories under `.lattice/`, in lookup order.
+pub const NODE_TYPE_DIRS: &[&str] = &[
+    "sources",
+    "theses",
+    "requirements",
+    "implementations",
+    "messages",
+];
+
+/// A single indexed file: the node ID it holds plus the stamp it was read at.
+/// The ID is empty for a file that does not parse, so it is not re-read
+/// until it changes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdIndexEntry {
+    pub id: String,
+    pub mtime_secs: u64,
+    pub mtime_nanos: u32,
+    pub size: u64,
+}
+
+/// Cached ID → path mapping. Paths are relative to `.lattice/`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdIndex {
+    /// Node ID → the file that defines it (the first one found, if an ID
+    /// is duplicated).
+    #[serde(default)]
+    pub ids: BTreeMap<String, String>,
+    /// Every indexed file → the stamp and ID it was read at, used to tell
+    /// whether an `ids` entry is still valid.
+    pub files: BTreeMap<String, IdIndexEntry>,
+}
+
+/// Counts reported by `IdIndex::refresh` and `rebuild_id_index`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IdIndexStats {
+    /// Files in the index after the refresh.
+    pub total: usize,
+    /// Files that had to be parsed (new or modified since the last refresh).
+    pub parsed: usize,
+    /// Entries dropped because the file disappeared.
+    pub removed: usize,
+}
+
+/// Read a file's (mtime secs, mtime nanos, size) stamp.
+fn file_stamp(path: &Path) -> Option<(u64, u32, u64)> {
+    let meta = fs::metadata(path).ok()?;
+    let mtime = meta.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+    Some((mtime.as_secs(), mtime.subsec_nanos(), meta.len()))
+}
+
+fn is_yaml(path: &Path) -> bool {
+    path.extension()
+        .map(|ext| ext == "yaml" || ext == "yml")
+        .unwrap_or(false)
+}
+
+/// Location of the cache file, or `None` if no cache directory is available.
+pub fn id_index_path(root: &Path) -> Option<PathBuf> {
+    cache_dir(root).ok().map(|dir| dir.join("ids.json"))
+}
+
+impl IdIndexEntry {
+    fn matches(&self, stamp: (u64, u32, u64)) -> bool {
+        (self.mtime_secs, self.mtime_nanos, self.size) == stamp
+    }
+}
+
+impl IdIndex {
+    /// Load the cached index, or an empty one if none exists or it is unreadable.
+    pub fn load(root: &Path) -> Self {
+        id_index_path(root)
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|data| serde_json::from_str(&data).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write the index to the cache directory via a temp file + rename so
+    /// concurrent readers never see a partial file.
+    pub fn save(&self, root: &Path) -> std::io::Result<()> {
+        let Some(path) = id_index_path(root) else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let data = serde_json::to_string(self).map_err(std::io::Error::other)?;
+        let tmp = path.with_extension(format!("json.{}.tmp", std::process::id()));
+        fs::write(&tmp, data)?;
+        fs::rename(&tmp, &path)
+    }
+
+    /// Look up the path for a node ID. Only entries whose file is unchanged
+    /// since it was indexed are trusted.
+    pub fn path_for(&self, root: &Path, node_id: &str) -> Option<PathBuf> {
+        let rel = self.ids.get(node_id)?;
+        let entry = self.files.get(rel).filter(|e| e.id == node_id)?;
+        let path = root.join(LATTICE_DIR).join(rel);
+        file_stamp(&path)
+            .is_some_and(|s| entry.matches(s))
+            .then_some(path)
+    }
+
+    /// Absolute paths of every indexed file, grouped in `NODE_TYPE_DIRS`
+    /// order.
+    pub fn paths(&self, root: &Path) -> Vec<PathBuf> {
+        let lattice_dir = root.join(LATTICE_DIR);
+        NODE_TYPE_DIRS
+            .iter()
+            .flat_map(|type_name| {
+                let prefix = format!("{}{}", type_name, std::path::MAIN_SEPARATOR);
+                self.files
+                    .range(prefix.clone()..)
+                    .take_while(move |(rel, _)| rel.starts_with(&prefix))
+                    .map(|(rel, _)| lattice_dir.join(rel))
+            })
+            .collect()
+    }
+
+    /// Re-walk the lattice, re-parsing only files whose stamp changed and
+    /// dropping entries for files that no longer exist.
+    pub fn refresh(&mut self, root: &Path) -> IdIndexStats {
+        let lattice_dir = root.join(LATTICE_DIR);
+        let mut files = BTreeMap::new();
+        let mut ids = BTreeMap::new();
+        let mut parsed = 0;
+
+        for type_name in NODE_TYPE_DIRS {
+            let type_dir = lattice_dir.join(type_name);
+            if !type_dir.exists() {
+                continue;
+            }
+
+            for entry in WalkDir::new(&type_dir)
+                .into_iter()
+                .filter_map(|e| e.ok())
+                .filter(|e| is_yaml(e.path()))
+            {
+                let path = entry.path();
+                let (Ok(rel), Some(stamp)) = (path.strip_prefix(&lattice_dir), file_stamp(path))
+                else {
+                    continue;
+                };
+                let rel = rel.to_string_lossy().to_string();
+
+                if let Some(cached) = self.files.get(&rel)
+                    && cached.matches(stamp)
+                {
+                    ids.entry(cached.id.clone()).or_insert_with(|| rel.clone());
+                    files.insert(rel, cached.clone());
+                    continue;
+                }
+
+                parsed += 1;
+                let id = load_node(path).map(|node| node.id).unwrap_or_default();
+                if !id.is_empty() {
+                    ids.entry(id.clone()).or_insert_with(|| rel.clone());
+                }
+                files.insert(
+                    rel,
+                    IdIndexEntry {
+                        id,
+                        mtime_secs: stamp.0,
+                        mtime_nanos: stamp.1,
+                        size: stamp.2,
+                    },
+                );
+            }
+        }
+
+        let removed = self
+            .files
+            .keys()
+            .filter(|k| !files.contains_key(*k))
+            .count();
+        self.files = files;
+        self.ids = ids;
+
+        IdIndexStats {
+            total: self.files.len(),
+            parsed,
+            removed,
+        }
+    }
+}
+
+/// Resolve a node ID to its file path using the persistent index.
+///
+/// Tries the cached entry first; on a miss or a stale entry, refreshes the
+/// index (parsing only changed files), persists it, and retries.
+pub fn lookup_node_path(root: &Path, node_id: &str) -> Result<PathBuf, StorageError> {
+    let index = IdIndex::load(root);
+    if let Some(path) = index.path_for(root, node_id) {
+        return Ok(path);
+    }
+
+    refreshed_id_index(root)
+        .path_for(root, node_id)
+        .ok_or_else(|| StorageError::NodeNotFound(node_id.to_string()))
+}
+
+/// Load the cached index and bring it up to date with the files on disk,
+/// persisting it if anything changed. Use when resolving many IDs at once.
+pub fn refreshed_id_index(root: &Path) -> IdIndex {
+    let mut index = IdIndex::load(root);
+    let before = index.clone();
+    index.refresh(root);
+    if index != before {
+        let _ = index.save(root);
+    }
+    index
+}
+
+/// Discard the cached index and rebuild it from scratch.
+pub fn rebuild_id_index(root: &Path) -> Result<IdIndexStats, StorageError> {
+    let mut index = IdIndex::default();
+    let stats = index.refresh(root);
+    index.save(root)?;
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_lattice;
+    use tempfile::TempDir;
+
+    fn write_req(root: &Path, file: &str, id: &str) -> PathBuf {
+        let path = root
+            .join(LATTICE_DIR)
+            .join("requirements")
+            .join("core")
+            .join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(
+            &path,
+            format!(
+                "id: {}\ntype: requirement\ntitle: Test\nbody: Body\nstatus: active\nversion: 1.0.0\ncreated_at: '2026-01-01T00:00:00Z'\ncreated_by: test\n",
+                id
+            ),
+        )
+        .unwrap();
+        path
+    }
+
+    #[test]
+    fn test_refresh_indexes_all_nodes() {
+        let tmp = TempDir::new().unwrap();
+        init_lattice(tmp.path(), false).unwrap();
+        write_req(tmp.path(), "001.yaml", "REQ-CORE-001");
+        write_req(tmp.path(), "002.yaml", "REQ-CORE-002");
+
+        let mut index = IdIndex::default();
+        let stats = index.refresh(tmp.path());
+        assert_eq!(stats.total, 2);
+        assert_eq!(stats.parsed, 2);
+        assert!(index.path_for(tmp.path(), "REQ-CORE-002").is_some());
+
+        // Second refresh reuses every entry
+        let stats = index.refresh(tmp.path());
+        assert_eq!(stats.parsed, 0);
+    }
+
+    #[test]
+    fn test_path_for_ignores_modified_file() {
+        let tmp = TempDir::new().unwrap();
+        init_lattice(tmp.path(), false).unwrap();
+        let path = write_req(tmp.path(), "001.yaml", "REQ-CORE-001");
+
+        let mut index = IdIndex::default();
+        index.refresh(tmp.path());
+
+        // Rewrite the file under a different ID — the stale entry must not match
+        fs::write(
+            &path,
+            "id: REQ-CORE-009\ntype: requirement\ntitle: Changed title\nbody: Body\nstatus: active\nversion: 1.0.0\ncreated_at: '2026-01-01T00:00:00Z'\ncreated_by: test\n",
+        )
+        .unwrap();
+        assert!(index.path_for(tmp.path(), "REQ-CORE-001").is_none());
+
+        let stats = index.refresh(tmp.path());
+        assert_eq!(stats.parsed, 1);
+        assert_eq!(index.path_for(tmp.path(), "REQ-CORE-009"), Some(path));
+    }
+
+    #[test]
+    fn test_refresh_keeps_unparsable_files_out_of_ids() {
+        let tmp = TempDir::new().unwrap();
+        init_lattice(tmp.path(), false).unwrap();
+        write_req(tmp.path(), "001.yaml", "REQ-CORE-001");
+        let bad = write_req(tmp.path(), "002.yaml", "REQ-CORE-002");
+        fs::write(&bad, "{{invalid").unwrap();
+
+        let mut index = IdIndex::default();
+        index.refresh(tmp.path());
+        assert_eq!(index.ids.keys().collect::<Vec<_>>(), vec!["REQ-CORE-001"]);
+        assert!(index.paths(tmp.path()).contains(&bad));
+
+        // The broken file is not re-parsed until it changes
+        assert_eq!(index.refresh(tmp.path()).parsed, 0);
+    }
+
+    #[test]
+    fn test_refresh_drops_deleted_files() {
+        let tmp = TempDir::new().unwrap();
+        init_lattice(tmp.path(), false).unwrap();
+        let path = write_req(tmp.path(), "001.yaml", "REQ-CORE-001");
+
+        let mut index = IdIndex::default();
+        index.refresh(tmp.path());
+        fs::remove_file(&path).unwrap();
+
+        let stats = index.refresh(tmp.path());
+        assert_eq!(stats.removed, 1);
+        assert_eq!(stats.total, 0);
+    }
+
+    #[test]
+    fn test_lookup_node_path_after_move() {
+        let tmp = TempDir::new().unwrap();
+        init_lattice(tmp.path(), false).unwrap();
+        let old = write_req(tmp.path(), "001.yaml", "REQ-CORE-001");
+        assert_eq!(lookup_node_path(tmp.path(), "REQ-CORE-001").unwrap(), old);
+
+        let new = old.with_file_name("001-renamed.yaml");
+        fs::rename(&old, &new).unwrap();
+        assert_eq!(lookup_node_path(tmp.path(), "REQ-CORE-001").unwrap(), new);
+
+        assert!(matches!(
+            lookup_node_path(tmp.path(), "REQ-MISSING"),
+            Err(StorageError::NodeNotFound(_))
+        ));
+    }
+}
@@ -4,29 +4,52 @@
 //! interconnected knowledge nodes (sources, theses, requirements, implementations)
 //! with version-bound edges and drift detection.
 
+pub mod coverage;
 pub mod diff;
 pub mod export;
 pub mod graph;
+pub mod graph_export;
+pub mod hotspots;
 pub mod html_export;
+pub mod id_index;
 pub mod lint;
 pub mod mcp;
+pub mod policy;
 pub mod push;
+pub mod quality;
+pub mod query;
+pub mod sarif;
 pub mod search;
 pub mod storage;
+pub mod symbols;
 pub mod types;
 pub mod update;
 
+pub use coverage::{
+    CoverageMetric, CoverageReport, MetricCoverage, Tally, compute_coverage, parse_thresholds,
+};
 pub use diff::{
-    ChangeType, DiffEntry, DiffError, DiffResult, compute_changed_fields, format_diff_markdown,
-    format_entry_text, git_head_sha, lattice_diff,
+    ChangeType, DiffEntry, DiffError, DiffResult, NodeRevision, changed_node_ids,
+    compute_changed_fields, explain_drift, format_diff_markdown, format_entry_text, git_head_sha,
+    lattice_at_ref, lattice_diff, node_at_version, node_history,
 };
 pub use export::{Audience, ExportOptions, LatticeData, export_narrative};
 pub use graph::{
-    DriftReport, DriftSeverity, Plan, PlannedItem, build_node_index, find_drift, generate_plan,
+    DependencyCycle, DriftExplanation, DriftItem, DriftReport, DriftSeverity, Impact, ImpactedNode,
+    IncomingRef, IndirectDrift, Plan, PlanFilter, PlanState, PlannedItem, ReverseIndex, TraceHop,
+    build_indexes, build_node_index, build_reverse_index, drift_reports, find_drift, find_impact,
+    find_incoming_edges, find_transitive_drift, generate_plan, lookup_node, trace_paths,
+    unresolved_requirements,
 };
+pub use graph_export::{GraphExportOptions, GraphFormat, export_graph};
+pub use hotspots::{FileChurn, Hotspot, HotspotError, find_hotspots, git_churn, parse_numstat};
 pub use html_export::{HtmlExportOptions, export_html};
-pub use lint::{LintReport, LintSeverity, fix_issues, lint_lattice};
+pub use id_index::{IdIndex, IdIndexStats, rebuild_id_index};
+pub use lint::{
+    LINT_RULES, LintConfig, LintReport, LintRule, LintSeverity, RuleLevel, fix_issues, lint_lattice,
+};
 pub use push::{PushDiff, PushDiffEntry, diff_result_to_push_diff, fetch_last_push_sha};
+pub use query::{Query, QueryError, parse_query, run_query};
 #[cfg(feature = "vector-search")]
 pub use search::FastEmbedProvider;
 pub use search::{
@@ -34,14 +57,17 @@ pub use search::{
     SearchResults, split_csv,
 };
 pub use storage::{
-    AddEdgeOptions, AddImplementationOptions, AddMessageOptions, AddRequirementOptions,
-    AddSourceOptions, AddThesisOptions, CURRENT_SCHEMA_VERSION, EDGE_TYPES, EditNodeOptions,
-    GapType, LATTICE_DIR, LatticeConfig, RefineOptions, RefineResult, RemoveEdgeOptions,
+    ARCHIVE_DIR, AddEdgeOptions, AddImplementationOptions, AddMessageOptions,
+    AddRequirementOptions, AddSourceOptions, AddThesisOptions, BumpLevel, CURRENT_SCHEMA_VERSION,
+    EDGE_TYPES, EditNodeOptions, GapType, IncomingEdge, IncomingEdgeAction, LATTICE_DIR,
+    LatticeConfig, MergeNodesOptions, MergeNodesResult, RefineOptions, RefineResult,
+    RemoveEdgeOptions, RemoveNodeOptions, RemoveNodeResult, RenameNodeOptions, RenameNodeResult,
     ReplaceEdgeOptions, ResolveOptions, SchemaCheck, VerifyOptions, acknowledge_drift, add_edge,
     add_implementation, add_message, add_requirement, add_source, add_thesis, check_schema_version,
     edit_node, find_lattice_root, find_node_path, get_git_remote_url, get_git_user,
-    get_github_pages_url, init_lattice, load_all_nodes, load_config, load_nodes_by_type,
-    refine_requirement, remove_edge, replace_edge, resolve_node, verify_implementation,
+    get_github_pages_url, init_lattice, load_all_nodes, load_config, load_node, load_nodes_by_type,
+    merge_nodes, refine_requirement, remove_edge, remove_node, rename_node, replace_edge,
+    resolve_node, verify_implementation,
 };
 pub use types::{
     ConfidenceEntry, LatticeNode, MessageMeta, NodeIndex, NodeMeta, NodeType, Priority, Resolution,
@@ -1,9 +1,22 @@
 //! Lint and validation for Lattice files.
 //!
+//! Every check reports under a stable rule ID (see `LINT_RULES`). The `lint:`
+//! section of config.yaml can turn rules off, change their severity, and
+//! suppress them for specific node IDs or file globs; a node can also list
+//! rules to ignore in its own `lint_ignore` field. Teams add their own rules
+//! as declarative policies (see `crate::policy`).
+//!
 //! Linked requirements: REQ-CORE-012
 
-use crate::storage::LATTICE_DIR;
-use crate::types::{LatticeNode, NodeType};
+use crate::graph::lookup_node;
+use crate::policy::{LintPolicy, policy_violations, validate_policies};
+use crate::quality::quality_issues;
+use crate::storage::{ARCHIVE_DIR, LATTICE_DIR, LatticeConfig};
+use crate::symbols::{Language, defines};
+use crate::types::{LatticeNode, NodeIndex, NodeMeta, NodeType, Status};
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
@@ -31,14 +44,358 @@ pub enum Fixable {
     No,
 }
 
+/// The level a rule reports at: a default in `LINT_RULES`, or an override
+/// under `lint.rules` in config.yaml.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    Off,
+    Warning,
+    Error,
+}
+
+impl RuleLevel {
+    fn severity(self) -> Option<LintSeverity> {
+        match self {
+            RuleLevel::Off => None,
+            RuleLevel::Warning => Some(LintSeverity::Warning),
+            RuleLevel::Error => Some(LintSeverity::Error),
+        }
+    }
+}
+
+impl fmt::Display for RuleLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleLevel::Off => write!(f, "off"),
+            RuleLevel::Warning => write!(f, "warning"),
+            RuleLevel::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A lint check, identified by a stable ID.
+#[derive(Debug, Clone, Copy)]
+pub struct LintRule {
+    pub id: &'static str,
+    /// Level used when config.yaml does not override it.
+    pub level: RuleLevel,
+    /// `lattice lint --fix` can repair issues from this rule.
+    pub fixable: bool,
+    pub description: &'static str,
+}
+
+/// Every lint rule, in the order checks run.
+pub const LINT_RULES: &[LintRule] = &[
+    LintRule {
+        id: "lattice-dir-missing",
+        level: RuleLevel::Error,
+        fixable: false,
+        description: "No .lattice directory exists",
+    },
+    LintRule {
+        id: "missing-config",
+        level: RuleLevel::Warning,
+        fixable: true,
+        description: ".lattice/config.yaml is missing",
+    },
+    LintRule {
+        id: "invalid-config",
+        level: RuleLevel::Warning,
+        fixable: false,
+        description: "config.yaml does not parse, or a lint setting names an unknown rule",
+    },
+    LintRule {
+        id: "unreadable-file",
+        level: RuleLevel::Error,
+        fixable: false,
+        description: "A node file cannot be read",
+    },
+    LintRule {
+        id: "invalid-yaml",
+        level: RuleLevel::Error,
+        fixable: false,
+        description: "A node file is not a valid node",
+    },
+    LintRule {
+        id: "missing-id",
+        level: RuleLevel::Error,
+        fixable: false,
+        description: "A node has no 'id'",
+    },
+    LintRule {
+        id: "missing-title",
+        level: RuleLevel::Error,
+        fixable: false,
+        description: "A node has no 'title'",
+    },
+    LintRule {
+        id: "missing-version",
+        level: RuleLevel::Warning,
+        fixable: true,
+        description: "A node has no 'version'",
+    },
+    LintRule {
+        id: "invalid-version",
+        level: RuleLevel::Warning,
+        fixable: false,
+        description: "A node version is not MAJOR.MINOR.PATCH",
+    },
+    LintRule {
+        id: "wrong-directory",
+        level: RuleLevel::Warning,
+        fixable: false,
+        description: "A node file is outside its type's directory",
+    },
+    LintRule {
+        id: "missing-priority",
+        level: RuleLevel::Warning,
+        fixable: false,
+        description: "A requirement has no 'priority'",
+    },
+    LintRule {
+        id: "empty-edge-target",
+        level: RuleLevel::Error,
+        fixable: false,
+        description: "An edge has an empty target",
+    },
+    LintRule {
+        id: "unbound-edge-version",
+        level: RuleLevel::Warning,
+        fixable: true,
+        description: "An edge does not record the target version it was bound to",
+    },
+    LintRule {
+        id: "duplicate-id",
+        level: RuleLevel::Error,
+        fixable: false,
+        description: "Two node files share an ID",
+    },
+    LintRule {
+        id: "dangling-edge",
+        level: RuleLevel::Warning,
+        fixable: false,
+        description: "An edge targets a node that does not exist",
+    },
+    LintRule {
+        id: "edge-endpoints",
+        level: RuleLevel::Error,
+        fixable: false,
+        description: "An edge connects node types its edge type does not allow",
+    },
+    LintRule {
+        id: "edge-cycle",
+        level: RuleLevel::Error,
+        fixable: false,
+        description: "depends_on or supersedes edges form a loop",
+    },
+    LintRule {
+        id: "orphan-node",
+        level: RuleLevel::Warning,
+        fixable: false,
+        description: "A node has no inbound or outbound edges",
+    },
+    LintRule {
+        id: "missing-file",
+        level: RuleLevel::Warning,
+        fixable: true,
+        description: "An implementation binds a path (or glob) that matches no file",
+    },
+    LintRule {
+        id: "absolute-file-path",
+        level: RuleLevel::Warning,
+        fixable: false,
+        description: "An implementation binds an absolute path instead of one relative to the lattice root",
+    },
+    LintRule {
+        id: "missing-function",
+        level: RuleLevel::Warning,
+        fixable: false,
+        description: "A function listed on a bound file is not defined in it (Rust, TypeScript/JavaScript, Python, Go)",
+    },
+    LintRule {
+        id: "req-weak-words",
+        level: RuleLevel::Off,
+        fixable: false,
+        description: "A requirement title or body uses vague wording (should, etc., fast, easy, ...)",
+    },
+    LintRule {
+        id: "req-missing-acceptance",
+        level: RuleLevel::Off,
+        fixable: false,
+        description: "A requirement has no acceptance tests",
+    },
+    LintRule {
+        id: "req-passive-voice",
+        level: RuleLevel::Off,
+        fixable: false,
+        description: "A requirement body uses passive voice without naming who acts",
+    },
+    LintRule {
+        id: "req-compound",
+        level: RuleLevel::Off,
+        fixable: false,
+        description: "One sentence joins several verb phrases with and/or under a single shall/must/should",
+    },
+    LintRule {
+        id: "req-title-length",
+        level: RuleLevel::Off,
+        fixable: false,
+        description: "A requirement title is longer than 80 characters",
+    },
+];
+
+impl LintRule {
+    pub fn find(id: &str) -> Option<&'static LintRule> {
+        LINT_RULES.iter().find(|r| r.id == id)
+    }
+}
+
+/// Rule ID that matches every rule in suppressions and `lint_ignore`.
+pub const ALL_RULES: &str = "all";
+
+/// The `lint:` section of config.yaml.
+///
+/// ```yaml
+/// lint:
+///   rules:
+///     orphan-node: off
+///     missing-priority: error
+///   suppress:
+///     - ids: [SRC-LEGACY-NOTES]
+///       rules: [orphan-node]
+///     - paths: ["sources/imported/**"]
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct LintConfig {
+    /// Level overrides by rule ID.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rules: BTreeMap<String, RuleLevel>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppress: Vec<LintSuppression>,
+    /// Team-defined rules, checked after the built-in ones.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub policies: Vec<LintPolicy>,
+}
+
+impl LintConfig {
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty() && self.suppress.is_empty() && self.policies.is_empty()
+    }
+
+    /// Whether `id` names a built-in rule or a policy.
+    pub fn has_rule(&self, id: &str) -> bool {
+        LintRule::find(id).is_some() || self.policies.iter().any(|p| p.id == id)
+    }
+
+    /// The level a rule reports at once `rules` overrides are applied.
+    pub fn level(&self, id: &str) -> Option<RuleLevel> {
+        self.rules.get(id).copied().or_else(|| {
+            LintRule::find(id).map(|r| r.level).or_else(|| {
+                self.policies
+                    .iter()
+                    .find(|p| p.id == id)
+                    .map(|p| p.severity)
+            })
+        })
+    }
+}
+
+/// Silences rules for the nodes matching `ids` or `paths`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct LintSuppression {
+    /// Node IDs to suppress.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ids: Vec<String>,
+    /// Globs over node file paths relative to `.lattice/`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+    /// Rule IDs to suppress; empty means every rule.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<String>,
+}
+
 /// A single lint issue found in a file.
 #[derive(Debug, Clone)]
 pub struct LintIssue {
+    /// ID of the rule in `LINT_RULES`, or of the policy, that raised the issue.
+    pub rule: String,
     pub file: PathBuf,
     pub node_id: Option<String>,
     pub severity: LintSeverity,
     pub message: String,
     pub fixable: Fixable,
+    /// The part of the node file the issue is about, when known.
+    pub anchor: Option<IssueAnchor>,
+    /// How to resolve the issue by hand, when the rule knows.
+    pub suggestion: Option<String>,
+}
+
+/// Where in a node file an issue points, resolved to a line by
+/// `crate::sarif::anchor_line`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IssueAnchor {
+    /// A top-level field, e.g. `version`.
+    Field(String),
+    /// An edge entry; without an edge type, the first edge to `target`.
+    Edge {
+        edge_type: Option<String>,
+        target: String,
+    },
+    /// A `key: value` entry at any depth, e.g. a bound file's `path`.
+    Value { key: String, value: String },
+    /// A 1-based line, e.g. from a YAML parse error.
+    Line(usize),
+}
+
+impl LintIssue {
+    /// An issue at its rule's default severity.
+    pub fn new(
+        rule: &'static str,
+        file: PathBuf,
+        node_id: Option<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        let rule_def = LintRule::find(rule);
+        debug_assert!(rule_def.is_some(), "unknown lint rule '{}'", rule);
+        LintIssue {
+            rule: rule.to_string(),
+            file,
+            node_id,
+            severity: rule_def
+                .and_then(|r| r.level.severity())
+                .unwrap_or(LintSeverity::Warning),
+            message: message.into(),
+            fixable: if rule_def.is_some_and(|r| r.fixable) {
+                Fixable::Yes
+            } else {
+                Fixable::No
+            },
+            anchor: None,
+            suggestion: None,
+        }
+    }
+
+    pub fn suggest(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    pub fn at(mut self, anchor: Option<IssueAnchor>) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    pub fn at_field(self, field: &str) -> Self {
+        self.at(Some(IssueAnchor::Field(field.to_string())))
+    }
+
+    pub fn at_edge(self, edge_type: Option<&str>, target: &str) -> Self {
+        self.at(Some(IssueAnchor::Edge {
+            edge_type: edge_type.map(str::to_string),
+            target: target.to_string(),
+        }))
+    }
 }
 
 impl fmt::Display for LintIssue {
@@ -55,8 +412,8 @@ impl fmt::Display for LintIssue {
             .unwrap_or_default();
         write!(
             f,
-            "{}: {}{}: {}",
-            self.severity, file_display, node_part, self.message
+            "{}[{}]: {}{}: {}",
+            self.severity, self.rule, file_display, node_part, self.message
         )
     }
 }
@@ -94,39 +451,63 @@ impl LintReport {
             .iter()
             .any(|i| i.severity == LintSeverity::Error)
     }
+
+    /// Keep only issues on the given nodes, plus issues not tied to any
+    /// node (config problems, unreadable files), for linting a change set.
+    pub fn retain_nodes(&mut self, ids: &BTreeSet<String>) {
+        self.issues
+            .retain(|i| i.node_id.as_ref().is_none_or(|id| ids.contains(id)));
+    }
 }
 
-/// Lint all YAML files in the lattice directory.
+/// Lint all YAML files in the lattice directory, applying the `lint:`
+/// settings from config.yaml and each node's `lint_ignore`.
 pub fn lint_lattice(root: &Path) -> LintReport {
     let lattice_dir = root.join(LATTICE_DIR);
     let mut issues = Vec::new();
 
     if !lattice_dir.exists() {
-        issues.push(LintIssue {
-            file: lattice_dir,
-            node_id: None,
-            severity: LintSeverity::Error,
-            message: "No .lattice directory found".to_string(),
-            fixable: Fixable::No,
-        });
+        issues.push(LintIssue::new(
+            "lattice-dir-missing",
+            lattice_dir,
+            None,
+            "No .lattice directory found",
+        ));
         return LintReport { issues };
     }
 
-    // Check config.yaml exists
+    // Check config.yaml exists and parses
     let config_path = lattice_dir.join("config.yaml");
-    if !config_path.exists() {
-        issues.push(LintIssue {
-            file: config_path,
-            node_id: None,
-            severity: LintSeverity::Warning,
-            message: "Missing config.yaml".to_string(),
-            fixable: Fixable::Yes,
-        });
-    }
+    let config = match std::fs::read_to_string(&config_path) {
+        Err(_) => {
+            issues.push(LintIssue::new(
+                "missing-config",
+                config_path,
+                None,
+                "Missing config.yaml",
+            ));
+            LintConfig::default()
+        }
+        Ok(content) => match serde_yaml::from_str::<Option<LatticeConfig>>(&content) {
+            Ok(config) => config.map(|c| c.lint).unwrap_or_default(),
+            Err(e) => {
+                issues.push(LintIssue::new(
+                    "invalid-config",
+                    config_path,
+                    None,
+                    format!("Invalid config.yaml, lint settings ignored: {}", e),
+                ));
+                LintConfig::default()
+            }
+        },
+    };
 
     // Validate all YAML files
+    let mut nodes: HashMap<String, NodeFile> = HashMap::new();
+    let archive_dir = lattice_dir.join(ARCHIVE_DIR);
     for entry in WalkDir::new(&lattice_dir)
         .into_iter()
+        .filter_entry(|e| e.path() != archive_dir)
         .filter_map(|e| e.ok())
         .filter(|e| {
             e.path()
@@ -147,7 +528,12 @@ pub fn lint_lattice(root: &Path) -> LintReport {
             continue;
         }
 
-        lint_node_file(path, &lattice_dir, &mut issues);
+        if let Some(node) = lint_node_file(path, &lattice_dir, &mut issues) {
+            nodes.entry(node.id).or_insert(NodeFile {
+                path: path.to_path_buf(),
+                lint_ignore: node.lint_ignore.unwrap_or_default(),
+            });
+        }
     }
 
     // Check for duplicate IDs
@@ -156,82 +542,209 @@ pub fn lint_lattice(root: &Path) -> LintReport {
     // Build node index once for cross-node checks
     if let Ok(index) = crate::graph::build_node_index(root) {
         check_edge_references(&index, &mut issues);
+        check_edge_semantics(&index, &mut issues);
+        check_edge_cycles(&index, &mut issues);
         check_orphan_nodes(&index, &mut issues);
+        check_implementation_files(root, &index, &mut issues);
+        check_requirement_quality(&index, &mut issues);
+        check_policies(&index, &config.policies, &mut issues);
+    }
+
+    LintReport {
+        issues: apply_config(&lattice_dir, &config, &nodes, issues),
+    }
+}
+
+/// Where a node lives and which rules it opts out of.
+struct NodeFile {
+    path: PathBuf,
+    lint_ignore: Vec<String>,
+}
+
+/// Report unknown rule IDs and bad globs in the lint settings, then apply
+/// level overrides and drop suppressed issues.
+fn apply_config(
+    lattice_dir: &Path,
+    config: &LintConfig,
+    nodes: &HashMap<String, NodeFile>,
+    mut issues: Vec<LintIssue>,
+) -> Vec<LintIssue> {
+    let config_path = lattice_dir.join("config.yaml");
+    let known = |id: &str| id == ALL_RULES || config.has_rule(id);
+    let config_issue =
+        |message: String| LintIssue::new("invalid-config", config_path.clone(), None, message);
+
+    let mut config_issues: Vec<LintIssue> = validate_policies(&config.policies)
+        .into_iter()
+        .map(config_issue)
+        .collect();
+    for id in config.rules.keys().filter(|id| !config.has_rule(id)) {
+        config_issues.push(config_issue(format!(
+            "Unknown lint rule '{}' in lint.rules",
+            id
+        )));
+    }
+
+    let mut suppressions: Vec<(&LintSuppression, GlobSet)> = Vec::new();
+    for (i, suppression) in config.suppress.iter().enumerate() {
+        for id in suppression.rules.iter().filter(|id| !known(id)) {
+            config_issues.push(config_issue(format!(
+                "Unknown lint rule '{}' in lint.suppress[{}]",
+                id, i
+            )));
+        }
+        if suppression.ids.is_empty() && suppression.paths.is_empty() {
+            config_issues.push(config_issue(format!(
+                "lint.suppress[{}] lists no ids or paths",
+                i
+            )));
+            continue;
+        }
+        let mut globs = GlobSetBuilder::new();
+        for pattern in &suppression.paths {
+            match Glob::new(pattern) {
+                Ok(glob) => {
+                    globs.add(glob);
+                }
+                Err(e) => config_issues.push(config_issue(format!(
+                    "Invalid glob '{}' in lint.suppress[{}]: {}",
+                    pattern, i, e
+                ))),
+            }
+        }
+        suppressions.push((
+            suppression,
+            globs.build().unwrap_or_else(|_| GlobSet::empty()),
+        ));
     }
+    issues.extend(config_issues);
+
+    let mut ids: Vec<&String> = nodes.keys().collect();
+    ids.sort();
+    for id in ids {
+        let node = &nodes[id];
+        for rule in node.lint_ignore.iter().filter(|r| !known(r)) {
+            issues.push(LintIssue::new(
+                "invalid-config",
+                node.path.clone(),
+                Some(id.clone()),
+                format!("Unknown lint rule '{}' in lint_ignore", rule),
+            ));
+        }
+    }
+
+    let matches_rule =
+        |rules: &[String], rule: &str| rules.iter().any(|r| r == ALL_RULES || r == rule);
 
-    LintReport { issues }
+    issues
+        .into_iter()
+        .filter_map(|mut issue| {
+            issue.severity = config.level(&issue.rule)?.severity()?;
+
+            let node = issue.node_id.as_ref().and_then(|id| nodes.get(id));
+            if node.is_some_and(|n| matches_rule(&n.lint_ignore, &issue.rule)) {
+                return None;
+            }
+
+            // Cross-node issues carry a "<ID>" placeholder instead of a path
+            let path = match node {
+                Some(n) if !issue.file.starts_with(lattice_dir) => n.path.as_path(),
+                _ => issue.file.as_path(),
+            };
+            let relative = path.strip_prefix(lattice_dir).unwrap_or(path);
+            let suppressed = suppressions.iter().any(|(s, globs)| {
+                (s.rules.is_empty() || matches_rule(&s.rules, &issue.rule))
+                    && (issue.node_id.as_ref().is_some_and(|id| s.ids.contains(id))
+                        || globs.is_match(relative))
+            });
+            (!suppressed).then_some(issue)
+        })
+        .collect()
 }
 
-/// Lint a single node YAML file.
-fn lint_node_file(path: &Path, _lattice_dir: &Path, issues: &mut Vec<LintIssue>) {
+/// Lint a single node YAML file, returning the node if it parsed.
+fn lint_node_file(
+    path: &Path,
+    _lattice_dir: &Path,
+    issues: &mut Vec<LintIssue>,
+) -> Option<LatticeNode> {
     let content = match std::fs::read_to_string(path) {
         Ok(c) => c,
         Err(_) => {
-            issues.push(LintIssue {
-                file: path.to_path_buf(),
-                node_id: None,
-                severity: LintSeverity::Error,
-                message: "Cannot read file".to_string(),
-                fixable: Fixable::No,
-            });
-            return;
+            issues.push(LintIssue::new(
+                "unreadable-file",
+                path.to_path_buf(),
+                None,
+                "Cannot read file",
+            ));
+            return None;
         }
     };
 
     let node: LatticeNode = match serde_yaml::from_str(&content) {
         Ok(n) => n,
         Err(e) => {
-            issues.push(LintIssue {
-                file: path.to_path_buf(),
-                node_id: None,
-                severity: LintSeverity::Error,
-                message: format!("Invalid YAML: {}", e),
-                fixable: Fixable::No,
-            });
-            return;
+            issues.push(
+                LintIssue::new(
+                    "invalid-yaml",
+                    path.to_path_buf(),
+                    None,
+                    format!("Invalid YAML: {}", e),
+                )
+                .at(e.location().map(|l| IssueAnchor::Line(l.line()))),
+            );
+            return None;
         }
     };
 
     // Check required fields
     if node.id.is_empty() {
-        issues.push(LintIssue {
-            file: path.to_path_buf(),
-            node_id: None,
-            severity: LintSeverity::Error,
-            message: "Missing or empty 'id' field".to_string(),
-            fixable: Fixable::No,
-        });
+        issues.push(
+            LintIssue::new(
+                "missing-id",
+                path.to_path_buf(),
+                None,
+                "Missing or empty 'id' field",
+            )
+            .at_field("id"),
+        );
     }
 
     if node.title.is_empty() {
-        issues.push(LintIssue {
-            file: path.to_path_buf(),
-            node_id: Some(node.id.clone()),
-            severity: LintSeverity::Error,
-            message: "Missing or empty 'title' field".to_string(),
-            fixable: Fixable::No,
-        });
+        issues.push(
+            LintIssue::new(
+                "missing-title",
+                path.to_path_buf(),
+                Some(node.id.clone()),
+                "Missing or empty 'title' field",
+            )
+            .at_field("title"),
+        );
     }
 
     if node.version.is_empty() {
-        issues.push(LintIssue {
-            file: path.to_path_buf(),
-            node_id: Some(node.id.clone()),
-            severity: LintSeverity::Warning,
-            message: "Missing 'version' field (default: 1.0.0)".to_string(),
-            fixable: Fixable::Yes,
-        });
+        issues.push(
+            LintIssue::new(
+                "missing-version",
+                path.to_path_buf(),
+                Some(node.id.clone()),
+                "Missing 'version' field (default: 1.0.0)",
+            )
+            .at_field("version"),
+        );
     } else {
         // Validate semver format
         let parts: Vec<&str> = node.version.split('.').collect();
         if parts.len() != 3 || parts.iter().any(|p| p.parse::<u64>().is_err()) {
-            issues.push(LintIssue {
-                file: path.to_path_buf(),
-                node_id: Some(node.id.clone()),
-                severity: LintSeverity::Warning,
-                message: format!("Invalid semver version: '{}'", node.version),
-                fixable: Fixable::No,
-            });
+            issues.push(
+                LintIssue::new(
+                    "invalid-version",
+                    path.to_path_buf(),
+                    Some(node.id.clone()),
+                    format!("Invalid semver version: '{}'", node.version),
+                )
+                .at_field("version"),
+            );
         }
     }
 
@@ -247,27 +760,28 @@ fn lint_node_file(path: &Path, _lattice_dir: &Path, issues: &mut Vec<LintIssue>)
     let in_correct_dir = path.components().any(|c| c.as_os_str() == expected_dir);
 
     if !in_correct_dir {
-        issues.push(LintIssue {
-            file: path.to_path_buf(),
-            node_id: Some(node.id.clone()),
-            severity: LintSeverity::Warning,
-            message: format!(
-                "Node type '{:?}' should be in '{}/' directory",
-                node.node_type, expected_dir
-            ),
-            fixable: Fixable::No,
-        });
+        issues.push(
+            LintIssue::new(
+                "wrong-directory",
+                path.to_path_buf(),
+                Some(node.id.clone()),
+                format!(
+                    "Node type '{:?}' should be in '{}/' directory",
+                    node.node_type, expected_dir
+                ),
+            )
+            .at_field("type"),
+        );
     }
 
     // Requirements should have priority
     if node.node_type == NodeType::Requirement && node.priority.is_none() {
-        issues.push(LintIssue {
-            file: path.to_path_buf(),
-            node_id: Some(node.id.clone()),
-            severity: LintSeverity::Warning,
-            message: "Requirement missing 'priority' field".to_string(),
-            fixable: Fixable::No,
-        });
+        issues.push(LintIssue::new(
+            "missing-priority",
+            path.to_path_buf(),
+            Some(node.id.clone()),
+            "Requirement missing 'priority' field",
+        ));
     }
 
     // Check edge version bindings
@@ -278,25 +792,29 @@ fn lint_node_file(path: &Path, _lattice_dir: &Path, issues: &mut Vec<LintIssue>)
             if let Some(edge_refs) = refs {
                 for edge_ref in edge_refs {
                     if edge_ref.target.is_empty() {
-                        issues.push(LintIssue {
-                            file: path.to_path_buf(),
-                            node_id: Some(node.id.clone()),
-                            severity: LintSeverity::Error,
-                            message: format!("Empty target in '{}' edge", edge_type),
-                            fixable: Fixable::No,
-                        });
+                        issues.push(
+                            LintIssue::new(
+                                "empty-edge-target",
+                                path.to_path_buf(),
+                                Some(node.id.clone()),
+                                format!("Empty target in '{}' edge", edge_type),
+                            )
+                            .at_edge(Some(edge_type), &edge_ref.target),
+                        );
                     }
                     if edge_ref.version.is_none() {
-                        issues.push(LintIssue {
-                            file: path.to_path_buf(),
-                            node_id: Some(node.id.clone()),
-                            severity: LintSeverity::Warning,
-                            message: format!(
-                                "Edge '{}' -> '{}' missing version binding",
-                                edge_type, edge_ref.target
-                            ),
-                            fixable: Fixable::Yes,
-                        });
+                        issues.push(
+                            LintIssue::new(
+                                "unbound-edge-version",
+                                path.to_path_buf(),
+                                Some(node.id.clone()),
+                                format!(
+                                    "Edge '{}' -> '{}' missing version binding",
+                                    edge_type, edge_ref.target
+                                ),
+                            )
+                            .at_edge(Some(edge_type), &edge_ref.target),
+                        );
                     }
                 }
             }
@@ -313,6 +831,8 @@ fn lint_node_file(path: &Path, _lattice_dir: &Path, issues: &mut Vec<LintIssue>)
         check_edges(&edges.conflicts_with, "conflicts_with", issues);
         check_edges(&edges.supersedes, "supersedes", issues);
     }
+
+    Some(node)
 }
 
 /// Check for duplicate node IDs across all files.
@@ -320,8 +840,10 @@ fn check_duplicate_ids(root: &Path, issues: &mut Vec<LintIssue>) {
     let mut seen: std::collections::HashMap<String, PathBuf> = std::collections::HashMap::new();
 
     let lattice_dir = root.join(LATTICE_DIR);
+    let archive_dir = lattice_dir.join(ARCHIVE_DIR);
     for entry in WalkDir::new(&lattice_dir)
         .into_iter()
+        .filter_entry(|e| e.path() != archive_dir)
         .filter_map(|e| e.ok())
         .filter(|e| {
             e.path()
@@ -342,17 +864,19 @@ fn check_duplicate_ids(root: &Path, issues: &mut Vec<LintIssue>) {
 
         if let Ok(node) = crate::storage::load_node(path) {
             if let Some(prev_path) = seen.get(&node.id) {
-                issues.push(LintIssue {
-                    file: path.to_path_buf(),
-                    node_id: Some(node.id.clone()),
-                    severity: LintSeverity::Error,
-                    message: format!(
-                        "Duplicate ID '{}' (also in {})",
-                        node.id,
-                        prev_path.display()
-                    ),
-                    fixable: Fixable::No,
-                });
+                issues.push(
+                    LintIssue::new(
+                        "duplicate-id",
+                        path.to_path_buf(),
+                        Some(node.id.clone()),
+                        format!(
+                            "Duplicate ID '{}' (also in {})",
+                            node.id,
+                            prev_path.display()
+                        ),
+                    )
+                    .at_field("id"),
+                );
             } else {
                 seen.insert(node.id, path.to_path_buf());
             }
@@ -363,18 +887,263 @@ fn check_duplicate_ids(root: &Path, issues: &mut Vec<LintIssue>) {
 /// Check that edge references point to existing node IDs.
 fn check_edge_references(index: &crate::types::NodeIndex, issues: &mut Vec<LintIssue>) {
     for node in index.values() {
-        for edge_ref in node.all_edges() {
+        for (edge_type, edge_ref) in node.typed_edges() {
             if !index.contains_key(&edge_ref.target) {
-                issues.push(LintIssue {
-                    file: PathBuf::from(format!("<{}>", node.id)),
-                    node_id: Some(node.id.clone()),
-                    severity: LintSeverity::Warning,
-                    message: format!("Edge references non-existent node '{}'", edge_ref.target),
-                    fixable: Fixable::No,
-                });
+                issues.push(
+                    LintIssue::new(
+                        "dangling-edge",
+                        PathBuf::from(format!("<{}>", node.id)),
+                        Some(node.id.clone()),
+                        format!("Edge references non-existent node '{}'", edge_ref.target),
+                    )
+                    .at_edge(Some(edge_type), &edge_ref.target),
+                );
+            }
+        }
+    }
+}
+
+/// Node types an edge type may connect. An empty list allows any type.
+#[derive(Debug, Clone, Copy)]
+pub struct EdgeRule {
+    pub edge_type: &'static str,
+    pub from: &'static [NodeType],
+    pub to: &'static [NodeType],
+    /// Both ends must be the same node type.
+    pub same_type: bool,
+}
+
+/// Allowed endpoints for every edge type in `EDGE_TYPES`.
+pub const EDGE_RULES: &[EdgeRule] = &[
+    EdgeRule {
+        edge_type: "supported_by",
+        from: &[NodeType::Thesis],
+        to: &[NodeType::Source],
+        same_type: false,
+    },
+    EdgeRule {
+        edge_type: "derives_from",
+        from: &[NodeType::Requirement],
+        to: &[NodeType::Thesis],
+        same_type: false,
+    },
+    EdgeRule {
+        edge_type: "depends_on",
+        from: &[NodeType::Requirement],
+        to: &[NodeType::Requirement],
+        same_type: false,
+    },
+    EdgeRule {
+        edge_type: "satisfies",
+        from: &[NodeType::Implementation],
+        to: &[NodeType::Requirement],
+        same_type: false,
+    },
+    EdgeRule {
+        edge_type: "extends",
+        from: &[],
+        to: &[],
+        same_type: false,
+    },
+    EdgeRule {
+        edge_type: "reveals_gap_in",
+        from: &[NodeType::Implementation],
+        to: &[NodeType::Requirement, NodeType::Thesis],
+        same_type: false,
+    },
+    EdgeRule {
+        edge_type: "challenges",
+        from: &[],
+        to: &[NodeType::Thesis],
+        same_type: false,
+    },
+    EdgeRule {
+        edge_type: "validates",
+        from: &[NodeType::Implementation, NodeType::Thesis],
+        to: &[NodeType::Thesis],
+        same_type: false,
+    },
+    EdgeRule {
+        edge_type: "conflicts_with",
+        from: &[],
+        to: &[],
+        same_type: false,
+    },
+    EdgeRule {
+        edge_type: "supersedes",
+        from: &[],
+        to: &[],
+        same_type: true,
+    },
+    EdgeRule {
+        edge_type: "rebuts",
+        from: &[NodeType::Thesis],
+        to: &[NodeType::Thesis],
+        same_type: false,
+    },
+    EdgeRule {
+        edge_type: "concedes",
+        from: &[NodeType::Thesis],
+        to: &[NodeType::Thesis],
+        same_type: false,
+    },
+    EdgeRule {
+        edge_type: "grounded_in",
+        from: &[NodeType::Message],
+        to: &[NodeType::Thesis],
+        same_type: false,
+    },
+];
+
+impl EdgeRule {
+    pub fn for_edge_type(edge_type: &str) -> Option<&'static EdgeRule> {
+        EDGE_RULES.iter().find(|r| r.edge_type == edge_type)
+    }
+
+    pub fn allows(&self, from: &NodeType, to: &NodeType) -> bool {
+        (self.from.is_empty() || self.from.contains(from))
+            && (self.to.is_empty() || self.to.contains(to))
+            && (!self.same_type || from == to)
+    }
+
+    /// Human-readable form of the rule, e.g. "implementation -> requirement".
+    pub fn describe(&self) -> String {
+        let side = |types: &[NodeType]| {
+            if types.is_empty() {
+                "any".to_string()
+            } else {
+                types.iter().map(type_name).collect::<Vec<_>>().join("/")
+            }
+        };
+        if self.same_type {
+            "nodes of the same type".to_string()
+        } else {
+            format!("{} -> {}", side(self.from), side(self.to))
+        }
+    }
+}
+
+fn type_name(node_type: &NodeType) -> String {
+    format!("{:?}", node_type).to_lowercase()
+}
+
+/// Check that each edge connects node types its edge type allows.
+fn check_edge_semantics(index: &NodeIndex, issues: &mut Vec<LintIssue>) {
+    let mut nodes: Vec<&LatticeNode> = index.values().collect();
+    nodes.sort_by(|a, b| a.id.cmp(&b.id));
+    for node in nodes {
+        for (edge_type, edge_ref) in node.typed_edges() {
+            let (Some(rule), Some(target)) = (
+                EdgeRule::for_edge_type(edge_type),
+                lookup_node(index, &edge_ref.target),
+            ) else {
+                continue;
+            };
+            if rule.allows(&node.node_type, &target.node_type) {
+                continue;
+            }
+            issues.push(
+                LintIssue::new(
+                    "edge-endpoints",
+                    PathBuf::from(format!("<{}>", node.id)),
+                    Some(node.id.clone()),
+                    format!(
+                        "Invalid '{}' edge {} ({}) -> {} ({}): expected {}",
+                        edge_type,
+                        node.id,
+                        type_name(&node.node_type),
+                        target.id,
+                        type_name(&target.node_type),
+                        rule.describe()
+                    ),
+                )
+                .at_edge(Some(edge_type), &edge_ref.target),
+            );
+        }
+    }
+}
+
+/// Edge types whose edges must never form a loop.
+const ACYCLIC_EDGE_TYPES: &[&str] = &["depends_on", "supersedes"];
+
+/// Report cycles among `depends_on` and `supersedes` edges, once per loop.
+fn check_edge_cycles(index: &NodeIndex, issues: &mut Vec<LintIssue>) {
+    for edge_type in ACYCLIC_EDGE_TYPES {
+        let mut graph: HashMap<&str, Vec<&str>> = HashMap::new();
+        for node in index.values() {
+            let mut targets: Vec<&str> = node
+                .typed_edges()
+                .into_iter()
+                .filter(|(t, _)| t == edge_type)
+                .filter_map(|(_, e)| lookup_node(index, &e.target))
+                .map(|n| n.id.as_str())
+                .collect();
+            targets.sort();
+            targets.dedup();
+            graph.insert(node.id.as_str(), targets);
+        }
+
+        for cycle in find_cycles(&graph) {
+            issues.push(
+                LintIssue::new(
+                    "edge-cycle",
+                    PathBuf::from(format!("<{}>", cycle[0])),
+                    Some(cycle[0].clone()),
+                    format!("'{}' cycle: {}", edge_type, cycle.join(" -> ")),
+                )
+                .at_edge(Some(edge_type), &cycle[1]),
+            );
+        }
+    }
+}
+
+/// Find loops with a depth-first search, one per back edge. Each loop starts
+/// at its smallest ID and ends by repeating it; duplicates are dropped.
+fn find_cycles(graph: &HashMap<&str, Vec<&str>>) -> Vec<Vec<String>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Mark {
+        Active,
+        Done,
+    }
+
+    fn visit<'a>(
+        id: &'a str,
+        graph: &HashMap<&'a str, Vec<&'a str>>,
+        marks: &mut HashMap<&'a str, Mark>,
+        stack: &mut Vec<&'a str>,
+        found: &mut BTreeSet<Vec<String>>,
+    ) {
+        marks.insert(id, Mark::Active);
+        stack.push(id);
+        for &next in graph.get(id).into_iter().flatten() {
+            match marks.get(next) {
+                Some(Mark::Active) => {
+                    let start = stack.iter().position(|&s| s == next).unwrap_or(0);
+                    let mut cycle: Vec<String> =
+                        stack[start..].iter().map(|s| s.to_string()).collect();
+                    let min = (0..cycle.len()).min_by_key(|&i| &cycle[i]).unwrap_or(0);
+                    cycle.rotate_left(min);
+                    cycle.push(cycle[0].clone());
+                    found.insert(cycle);
+                }
+                Some(Mark::Done) => {}
+                None => visit(next, graph, marks, stack, found),
             }
         }
+        stack.pop();
+        marks.insert(id, Mark::Done);
     }
+
+    let mut ids: Vec<&str> = graph.keys().copied().collect();
+    ids.sort();
+    let mut marks: HashMap<&str, Mark> = HashMap::new();
+    let mut found: BTreeSet<Vec<String>> = BTreeSet::new();
+    for id in ids {
+        if !marks.contains_key(id) {
+            visit(id, graph, &mut marks, &mut Vec::new(), &mut found);
+        }
+    }
+    found.into_iter().collect()
 }
 
 /// Check for orphan nodes that have no edges (no inbound or outbound connections).
@@ -399,16 +1168,184 @@ fn check_orphan_nodes(index: &crate::types::NodeIndex, issues: &mut Vec<LintIssu
     orphans.sort_by(|a, b| a.id.cmp(&b.id));
 
     for node in &orphans {
-        issues.push(LintIssue {
-            file: PathBuf::from(format!("<{}>", node.id)),
-            node_id: Some(node.id.clone()),
-            severity: LintSeverity::Warning,
-            message: format!(
+        issues.push(LintIssue::new(
+            "orphan-node",
+            PathBuf::from(format!("<{}>", node.id)),
+            Some(node.id.clone()),
+            format!(
                 "Node has no edges (orphan) — expected at least one connection for {:?}",
                 node.node_type
             ),
-            fixable: Fixable::No,
-        });
+        ));
+    }
+}
+
+/// Directories a `**` file binding never descends into: VCS metadata and
+/// build or dependency output.
+const SKIPPED_DIRS: &[&str] = &[".git", "target", "node_modules"];
+
+/// Whether a `FileRef.path` is absolute. Bindings are relative to the
+/// lattice root, so absolute ones are reported rather than resolved.
+fn is_absolute_ref(pattern: &str) -> bool {
+    let path = Path::new(pattern);
+    path.is_absolute() || path.has_root()
+}
+
+/// Files that a `FileRef.path` names, relative to `root` (`..` may climb
+/// out of it, as in a monorepo). The path may be a glob, where `*` stays
+/// within one directory and `**` crosses directories. Absolute paths match
+/// nothing.
+pub fn resolve_file_ref(root: &Path, pattern: &str) -> Vec<PathBuf> {
+    let is_glob = |s: &str| s.contains(['*', '?', '[', '{']);
+    if is_absolute_ref(pattern) {
+        return Vec::new();
+    }
+    let pattern = pattern.trim_start_matches("./");
+    if !is_glob(pattern) {
+        let path = root.join(pattern);
+        return if path.exists() {
+            vec![path]
+        } else {
+            Vec::new()
+        };
+    }
+    let Ok(glob) = GlobBuilder::new(pattern).literal_separator(true).build() else {
+        return Vec::new();
+    };
+    let matcher = glob.compile_matcher();
+
+    // Walk only the directory above the first wildcard
+    let base: PathBuf = Path::new(pattern)
+        .components()
+        .take_while(|c| !is_glob(&c.as_os_str().to_string_lossy()))
+        .collect();
+    let walk_root = root.join(&base);
+    // Skipped directories are still searched when the pattern names them
+    let named: Vec<&str> = pattern.split('/').collect();
+    let mut files: Vec<PathBuf> = WalkDir::new(&walk_root)
+        .into_iter()
+        .filter_entry(|e| {
+            e.depth() == 0
+                || !e.file_type().is_dir()
+                || !SKIPPED_DIRS
+                    .iter()
+                    .any(|d| e.file_name() == *d && !named.contains(d))
+        })
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| {
+            e.path()
+                .strip_prefix(&walk_root)
+                .is_ok_and(|rel| matcher.is_match(base.join(rel)))
+        })
+        .map(|e| e.into_path())
+        .collect();
+    files.sort();
+    files
+}
+
+/// Check that implementation file bindings exist relative to the lattice
+/// root, and that listed functions are defined in the bound files.
+/// Deprecated and superseded implementations are skipped.
+fn check_implementation_files(root: &Path, index: &NodeIndex, issues: &mut Vec<LintIssue>) {
+    let mut implementations: Vec<&LatticeNode> = index
+        .values()
+        .filter(|n| n.node_type == NodeType::Implementation)
+        .filter(|n| !matches!(n.status, Status::Deprecated | Status::Superseded))
+        .collect();
+    implementations.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut contents: HashMap<PathBuf, Option<String>> = HashMap::new();
+    for imp in implementations {
+        let Some(NodeMeta::Implementation(meta)) = &imp.meta else {
+            continue;
+        };
+        for file_ref in meta.files.iter().flatten() {
+            let matched = resolve_file_ref(root, &file_ref.path);
+            let issue = |rule: &'static str, message: String| {
+                LintIssue::new(
+                    rule,
+                    PathBuf::from(format!("<{}>", imp.id)),
+                    Some(imp.id.clone()),
+                    message,
+                )
+                .at(Some(IssueAnchor::Value {
+                    key: "path".to_string(),
+                    value: file_ref.path.clone(),
+                }))
+            };
+            if is_absolute_ref(&file_ref.path) {
+                issues.push(issue(
+                    "absolute-file-path",
+                    format!(
+                        "Bound file '{}' is absolute; bind it relative to the lattice root",
+                        file_ref.path
+                    ),
+                ));
+                continue;
+            }
+            if matched.is_empty() {
+                issues.push(issue(
+                    "missing-file",
+                    format!("Bound file '{}' matches no file", file_ref.path),
+                ));
+                continue;
+            }
+
+            let sources: Vec<(Language, &PathBuf)> = matched
+                .iter()
+                .filter_map(|p| Language::from_path(p).map(|lang| (lang, p)))
+                .collect();
+            if sources.is_empty() {
+                continue;
+            }
+            for function in file_ref.functions.iter().flatten() {
+                let found = sources.iter().any(|(lang, path)| {
+                    contents
+                        .entry(path.to_path_buf())
+                        .or_insert_with(|| std::fs::read_to_string(path).ok())
+                        .as_deref()
+                        .is_some_and(|content| defines(*lang, content, function))
+                });
+                if !found {
+                    issues.push(issue(
+                        "missing-function",
+                        format!(
+                            "Function '{}' is not defined in '{}'",
+                            function, file_ref.path
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Run the requirement-quality rules, which are off unless configured.
+fn check_requirement_quality(index: &NodeIndex, issues: &mut Vec<LintIssue>) {
+    let mut ids: Vec<&String> = index.keys().collect();
+    ids.sort();
+    for id in ids {
+        issues.extend(quality_issues(&index[id]));
+    }
+}
+
+/// Check every node against the team's policies.
+fn check_policies(index: &NodeIndex, policies: &[LintPolicy], issues: &mut Vec<LintIssue>) {
+    // Policies that shadow a built-in rule are reported by `validate_policies`
+    for policy in policies.iter().filter(|p| LintRule::find(&p.id).is_none()) {
+        for (node_id, missing) in policy_violations(policy, index) {
+            issues.push(LintIssue {
+                rule: policy.id.clone(),
+                file: PathBuf::from(format!("<{}>", node_id)),
+                node_id: Some(node_id),
+                severity: policy.severity.severity().unwrap_or(LintSeverity::Warning),
+                message: format!("{} ({})", policy.message, missing),
+                fixable: Fixable::No,
+                anchor: None,
+                suggestion: None,
+            });
+        }
     }
 }
 
@@ -419,7 +1356,7 @@ pub fn fix_issues(root: &Path, report: &LintReport) -> Vec<String> {
 
     for issue in report.fixable() {
         // Fix missing config.yaml
-        if issue.message == "Missing config.yaml" {
+        if issue.rule == "missing-config" {
             let config_path = lattice_dir.join("config.yaml");
             let default_config = "# Lattice configuration\nversion: \"1.0\"\n";
             if std::fs::write(&config_path, default_config).is_ok() {
@@ -428,8 +1365,39 @@ pub fn fix_issues(root: &Path, report: &LintReport) -> Vec<String> {
             continue;
         }
 
+        // Prune file bindings that match nothing
+        if issue.rule == "missing-file"
+            && let Some(node_id) = &issue.node_id
+            && let Ok(path) = crate::storage::find_node_path(root, node_id)
+            && let Ok(mut node) = crate::storage::load_node(&path)
+            && let Some(NodeMeta::Implementation(meta)) = &mut node.meta
+            && let Some(files) = &mut meta.files
+        {
+            let mut pruned = Vec::new();
+            files.retain(|f| {
+                // Absolute paths are never resolved, so not known to be missing
+                let exists =
+                    is_absolute_ref(&f.path) || !resolve_file_ref(root, &f.path).is_empty();
+                if !exists {
+                    pruned.push(f.path.clone());
+                }
+                exists
+            });
+            if files.is_empty() {
+                meta.files = None;
+            }
+            if !pruned.is_empty() && crate::storage::save_node(&path, &node).is_ok() {
+                fixed.push(format!(
+                    "Removed missing file bindings from {}: {}",
+                    node_id,
+                    pruned.join(", ")
+                ));
+            }
+            continue;
+        }
+
         // Fix missing version on edges
-        if issue.message.contains("missing version binding")
+        if issue.rule == "unbound-edge-version"
             && let Some(node_id) = &issue.node_id
             && let Ok(path) = crate::storage::find_node_path(root, node_id)
             && let Ok(mut node) = crate::storage::load_node(&path)
@@ -568,6 +1536,559 @@ mod tests {
         assert!(warnings.iter().any(|w| w.message.contains("non-existent")));
     }
 
+    /// Write a node whose `edges` block is given as (edge type, target) pairs.
+    fn write_node(root: &Path, node_type: &str, dir: &str, id: &str, edges: &[(&str, &str)]) {
+        let mut yaml = format!(
+            "id: {id}\ntype: {node_type}\ntitle: {id}\nbody: Body\nstatus: active\nversion: '1.0.0'\ncreated_at: '2026-01-01'\ncreated_by: test\npriority: P1\n"
+        );
+        if !edges.is_empty() {
+            yaml.push_str("edges:\n");
+            for (edge_type, target) in edges {
+                yaml.push_str(&format!(
+                    "  {edge_type}:\n    - target: {target}\n      version: '1.0.0'\n"
+                ));
+            }
+        }
+        let dir = root.join(LATTICE_DIR).join(dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(format!("{}.yaml", id.to_lowercase())), yaml).unwrap();
+    }
+
+    #[test]
+    fn test_lint_edge_semantics() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        crate::storage::init_lattice(root, false).unwrap();
+
+        write_node(root, "thesis", "theses", "THX-A", &[]);
+        write_node(
+            root,
+            "source",
+            "sources",
+            "SRC-A",
+            &[("satisfies", "THX-A")],
+        );
+        write_node(
+            root,
+            "requirement",
+            "requirements",
+            "REQ-A",
+            &[("grounded_in", "THX-A")],
+        );
+        write_node(
+            root,
+            "implementation",
+            "implementations",
+            "IMP-A",
+            &[("satisfies", "REQ-A")],
+        );
+        write_node(
+            root,
+            "thesis",
+            "theses",
+            "THX-B",
+            &[("supersedes", "REQ-A"), ("validates", "THX-A")],
+        );
+
+        let report = lint_lattice(root);
+        let errors: Vec<&str> = report
+            .errors()
+            .iter()
+            .filter(|e| e.message.starts_with("Invalid '"))
+            .map(|e| e.message.as_str())
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "Invalid 'grounded_in' edge REQ-A (requirement) -> THX-A (thesis): expected message -> thesis",
+                "Invalid 'satisfies' edge SRC-A (source) -> THX-A (thesis): expected implementation -> requirement",
+                "Invalid 'supersedes' edge THX-B (thesis) -> REQ-A (requirement): expected nodes of the same type",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_edge_cycles() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        crate::storage::init_lattice(root, false).unwrap();
+
+        write_node(
+            root,
+            "requirement",
+            "requirements",
+            "REQ-B",
+            &[("depends_on", "REQ-C")],
+        );
+        write_node(
+            root,
+            "requirement",
+            "requirements",
+            "REQ-C",
+            &[("depends_on", "REQ-A")],
+        );
+        write_node(
+            root,
+            "requirement",
+            "requirements",
+            "REQ-A",
+            &[("depends_on", "REQ-B")],
+        );
+        write_node(
+            root,
+            "requirement",
+            "requirements",
+            "REQ-D",
+            &[("depends_on", "REQ-A")],
+        );
+        write_node(
+            root,
+            "requirement",
+            "requirements",
+            "REQ-E",
+            &[("supersedes", "REQ-E")],
+        );
+
+        let report = lint_lattice(root);
+        let cycles: Vec<&str> = report
+            .errors()
+            .iter()
+            .filter(|e| e.message.contains("cycle"))
+            .map(|e| e.message.as_str())
+            .collect();
+        assert_eq!(
+            cycles,
+            vec![
+                "'depends_on' cycle: REQ-A -> REQ-B -> REQ-C -> REQ-A",
+                "'supersedes' cycle: REQ-E -> REQ-E",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_edge_type_has_a_rule() {
+        for edge_type in crate::storage::EDGE_TYPES {
+            assert!(
+                EdgeRule::for_edge_type(edge_type).is_some(),
+                "no rule for {}",
+                edge_type
+            );
+        }
+        assert_eq!(EDGE_RULES.len(), crate::storage::EDGE_TYPES.len());
+    }
+
+    #[test]
+    fn test_lint_rule_ids_unique() {
+        let ids: BTreeSet<&str> = LINT_RULES.iter().map(|r| r.id).collect();
+        assert_eq!(ids.len(), LINT_RULES.len());
+        assert!(LintRule::find(ALL_RULES).is_none());
+    }
+
+    fn append_config(root: &Path, yaml: &str) {
+        let path = root.join(LATTICE_DIR).join("config.yaml");
+        let mut config = fs::read_to_string(&path).unwrap();
+        config.push_str(yaml);
+        fs::write(path, config).unwrap();
+    }
+
+    #[test]
+    fn test_lint_config_levels_and_suppressions() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        crate::storage::init_lattice(root, false).unwrap();
+
+        write_node(root, "source", "sources", "SRC-A", &[]);
+        write_node(root, "source", "sources/imported", "SRC-B", &[]);
+        write_node(root, "thesis", "theses", "THX-A", &[]);
+        write_node(root, "thesis", "theses", "THX-B", &[]);
+        write_node(
+            root,
+            "requirement",
+            "requirements",
+            "REQ-A",
+            &[("depends_on", "REQ-MISSING")],
+        );
+
+        let report = lint_lattice(root);
+        let orphans = report.issues.iter().filter(|i| i.rule == "orphan-node");
+        assert_eq!(orphans.count(), 4);
+        assert!(!report.has_errors());
+
+        append_config(
+            root,
+            "lint:\n  rules:\n    dangling-edge: error\n  suppress:\n    - ids: [SRC-A]\n      rules: [orphan-node]\n    - paths: [\"sources/imported/**\"]\n",
+        );
+        let report = lint_lattice(root);
+        let orphans: Vec<_> = report
+            .issues
+            .iter()
+            .filter(|i| i.rule == "orphan-node")
+            .filter_map(|i| i.node_id.as_deref())
+            .collect();
+        assert_eq!(orphans.len(), 2);
+        assert!(orphans.contains(&"THX-A") && orphans.contains(&"THX-B"));
+        assert_eq!(report.errors().len(), 1);
+        assert_eq!(report.errors()[0].rule, "dangling-edge");
+
+        append_config(root, "    - ids: [THX-A, THX-B]\n");
+        let report = lint_lattice(root);
+        assert!(!report.issues.iter().any(|i| i.rule == "orphan-node"));
+        assert!(report.issues.iter().all(|i| i.rule != "invalid-config"));
+    }
+
+    #[test]
+    fn test_lint_rule_off_in_config() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        crate::storage::init_lattice(root, false).unwrap();
+        write_node(root, "thesis", "theses", "THX-A", &[]);
+
+        append_config(root, "lint:\n  rules:\n    orphan-node: off\n");
+        let report = lint_lattice(root);
+        assert!(report.issues.is_empty(), "{:?}", report.issues);
+    }
+
+    #[test]
+    fn test_lint_ignore_on_node() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        crate::storage::init_lattice(root, false).unwrap();
+
+        let thx_dir = root.join(LATTICE_DIR).join("theses");
+        fs::write(
+            thx_dir.join("thx-a.yaml"),
+            "id: THX-A\ntype: thesis\ntitle: Kept alone\nbody: Body\nstatus: active\nversion: '1.0.0'\ncreated_at: '2026-01-01'\ncreated_by: test\nlint_ignore: [orphan-node]\n",
+        )
+        .unwrap();
+        fs::write(
+            thx_dir.join("thx-b.yaml"),
+            "id: THX-B\ntype: thesis\ntitle: Typo\nbody: Body\nstatus: active\nversion: '1.0.0'\ncreated_at: '2026-01-01'\ncreated_by: test\nlint_ignore: [orphan-nodes]\n",
+        )
+        .unwrap();
+
+        let report = lint_lattice(root);
+        let found: Vec<(&str, Option<&str>)> = report
+            .issues
+            .iter()
+            .map(|i| (i.rule.as_str(), i.node_id.as_deref()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("orphan-node", Some("THX-B")),
+                ("invalid-config", Some("THX-B")),
+            ]
+        );
+        assert!(report.issues[1].message.contains("'orphan-nodes'"));
+    }
+
+    #[test]
+    fn test_lint_config_unknown_rules_reported() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        crate::storage::init_lattice(root, false).unwrap();
+
+        append_config(
+            root,
+            "lint:\n  rules:\n    no-such-rule: off\n  suppress:\n    - rules: [orphan-node]\n",
+        );
+        let report = lint_lattice(root);
+        let messages: Vec<&str> = report
+            .issues
+            .iter()
+            .filter(|i| i.rule == "invalid-config")
+            .map(|i| i.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Unknown lint rule 'no-such-rule' in lint.rules",
+                "lint.suppress[0] lists no ids or paths",
+            ]
+        );
+
+        // A config that no longer parses is reported rather than silently ignored
+        append_config(root, "    - ids: oops\n      paths: {}\n");
+        let report = lint_lattice(root);
+        assert!(
+            report
+                .issues
+                .iter()
+                .any(|i| i.rule == "invalid-config" && i.message.starts_with("Invalid config.yaml"))
+        );
+    }
+
+    #[test]
+    fn test_lint_config_policies() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        crate::storage::init_lattice(root, false).unwrap();
+        write_node(root, "thesis", "theses", "THX-A", &[]);
+        write_node(
+            root,
+            "requirement",
+            "requirements",
+            "REQ-A",
+            &[("derives_from", "THX-A")],
+        );
+
+        append_config(
+            root,
+            "lint:\n  policies:\n    - id: req-acceptance\n      type: requirement\n      when: { priority: P1 }\n      require:\n        - field: acceptance\n      message: P1 requirements need an acceptance test\n      severity: error\n",
+        );
+        let report = lint_lattice(root);
+        assert_eq!(report.errors().len(), 1);
+        let issue = report.errors()[0];
+        assert_eq!(issue.rule, "req-acceptance");
+        assert_eq!(issue.node_id.as_deref(), Some("REQ-A"));
+        assert_eq!(
+            issue.message,
+            "P1 requirements need an acceptance test ('acceptance': 0, needs 1)"
+        );
+
+        // Policies take level overrides like built-in rules
+        append_config(root, "  rules:\n    req-acceptance: warning\n");
+        let report = lint_lattice(root);
+        assert!(!report.has_errors());
+        assert_eq!(report.warnings()[0].rule, "req-acceptance");
+    }
+
+    /// Write an implementation node binding the given (path, functions).
+    fn write_implementation(root: &Path, id: &str, files: &[(&str, &[&str])]) {
+        let mut yaml = format!(
+            "id: {id}\ntype: implementation\ntitle: {id}\nbody: Body\nstatus: active\nversion: '1.0.0'\ncreated_at: '2026-01-01'\ncreated_by: test\nmeta:\n  files:\n"
+        );
+        for (path, functions) in files {
+            yaml.push_str(&format!("  - path: '{path}'\n"));
+            if !functions.is_empty() {
+                yaml.push_str(&format!("    functions: [{}]\n", functions.join(", ")));
+            }
+        }
+        let dir = root.join(LATTICE_DIR).join("implementations");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(format!("{}.yaml", id.to_lowercase())), yaml).unwrap();
+    }
+
+    #[test]
+    fn test_lint_implementation_files() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        crate::storage::init_lattice(root, false).unwrap();
+        fs::create_dir_all(root.join("src/cli")).unwrap();
+        fs::create_dir_all(root.join("target/debug")).unwrap();
+        fs::create_dir_all(root.join("node_modules/pkg")).unwrap();
+        fs::write(root.join("target/debug/vendored.rs"), "").unwrap();
+        fs::write(root.join("node_modules/pkg/vendored.rs"), "").unwrap();
+        fs::write(
+            root.join("src/lib.rs"),
+            "pub fn build_index() {}
+",
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/cli/main.py"),
+            "def run():
+    pass
+",
+        )
+        .unwrap();
+        fs::write(
+            root.join("README.md"),
+            "# Readme
+",
+        )
+        .unwrap();
+        write_implementation(
+            root,
+            "IMP-A",
+            &[
+                ("src/lib.rs", &["build_index", "load_index"]),
+                ("src/*.py", &[]),
+                ("src/**/*.py", &["run"]),
+                ("./README.md", &["anything"]),
+                ("src/gone.rs", &["build_index"]),
+                ("src/../README.md", &[]),
+                ("**/vendored.rs", &[]),
+            ],
+        );
+
+        let report = lint_lattice(root);
+        let found: Vec<(&str, &str)> = report
+            .issues
+            .iter()
+            .filter(|i| i.rule.starts_with("missing-f"))
+            .map(|i| (i.rule.as_str(), i.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "missing-function",
+                    "Function 'load_index' is not defined in 'src/lib.rs'"
+                ),
+                ("missing-file", "Bound file 'src/*.py' matches no file"),
+                ("missing-file", "Bound file 'src/gone.rs' matches no file"),
+                (
+                    "missing-file",
+                    "Bound file '**/vendored.rs' matches no file"
+                ),
+            ]
+        );
+        assert_eq!(
+            report
+                .issues
+                .iter()
+                .find(|i| i.rule == "missing-file")
+                .unwrap()
+                .anchor,
+            Some(IssueAnchor::Value {
+                key: "path".to_string(),
+                value: "src/*.py".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_fix_prunes_missing_files() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        crate::storage::init_lattice(root, false).unwrap();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(
+            root.join("src/lib.rs"),
+            "pub fn build_index() {}
+",
+        )
+        .unwrap();
+        write_implementation(
+            root,
+            "IMP-A",
+            &[("src/lib.rs", &[]), ("src/gone.rs", &[]), ("old/*.rs", &[])],
+        );
+        write_implementation(root, "IMP-B", &[("src/gone.rs", &[])]);
+
+        let fixed = fix_issues(root, &lint_lattice(root));
+        assert!(fixed.contains(
+            &"Removed missing file bindings from IMP-A: src/gone.rs, old/*.rs".to_string()
+        ));
+        assert!(
+            !lint_lattice(root)
+                .issues
+                .iter()
+                .any(|i| i.rule == "missing-file")
+        );
+
+        let load = |id: &str| {
+            let path = crate::storage::find_node_path(root, id).unwrap();
+            match crate::storage::load_node(&path).unwrap().meta {
+                Some(NodeMeta::Implementation(meta)) => meta.files,
+                _ => panic!("expected implementation meta"),
+            }
+        };
+        let files = load("IMP-A").unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path, "src/lib.rs");
+        assert!(load("IMP-B").is_none());
+    }
+
+    #[test]
+    fn test_fix_keeps_bindings_it_cannot_rule_out() {
+        // A lattice in a monorepo subdirectory, binding a sibling package
+        let dir = TempDir::new().unwrap();
+        let root = &dir.path().join("app");
+        crate::storage::init_lattice(root, false).unwrap();
+        fs::create_dir_all(dir.path().join("shared")).unwrap();
+        fs::write(dir.path().join("shared/lib.rs"), "").unwrap();
+        fs::create_dir_all(root.join("src/target")).unwrap();
+        fs::write(root.join("src/target/gen.rs"), "").unwrap();
+        write_implementation(
+            root,
+            "IMP-A",
+            &[
+                ("src/target/*.rs", &[]),
+                ("../shared/lib.rs", &[]),
+                ("../shared/*.rs", &[]),
+                ("/opt/vendor/lib.rs", &[]),
+                ("src/gone.rs", &[]),
+            ],
+        );
+
+        let report = lint_lattice(root);
+        let found: Vec<(&str, &str)> = report
+            .issues
+            .iter()
+            .filter(|i| i.rule.contains("file"))
+            .map(|i| (i.rule.as_str(), i.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "absolute-file-path",
+                    "Bound file '/opt/vendor/lib.rs' is absolute; bind it relative to the lattice root"
+                ),
+                ("missing-file", "Bound file 'src/gone.rs' matches no file"),
+            ]
+        );
+
+        let fixed = fix_issues(root, &report);
+        assert_eq!(
+            fixed,
+            vec!["Removed missing file bindings from IMP-A: src/gone.rs".to_string()]
+        );
+        let path = crate::storage::find_node_path(root, "IMP-A").unwrap();
+        let Some(NodeMeta::Implementation(meta)) = crate::storage::load_node(&path).unwrap().meta
+        else {
+            panic!("expected implementation meta");
+        };
+        assert_eq!(meta.files.unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_lint_requirement_quality_opt_in() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        crate::storage::init_lattice(root, false).unwrap();
+        write_node(root, "requirement", "requirements", "REQ-A", &[]);
+        write_node(root, "requirement", "requirements", "REQ-B", &[]);
+        let quality = |report: &LintReport| -> Vec<(String, Option<String>)> {
+            report
+                .issues
+                .iter()
+                .filter(|i| i.rule.starts_with("req-"))
+                .map(|i| (i.rule.clone(), i.node_id.clone()))
+                .collect()
+        };
+        assert!(quality(&lint_lattice(root)).is_empty());
+
+        append_config(root, "lint:\n  rules:\n    req-missing-acceptance: error\n");
+        let mut report = lint_lattice(root);
+        assert_eq!(
+            quality(&report),
+            vec![
+                (
+                    "req-missing-acceptance".to_string(),
+                    Some("REQ-A".to_string())
+                ),
+                (
+                    "req-missing-acceptance".to_string(),
+                    Some("REQ-B".to_string())
+                ),
+            ]
+        );
+        assert!(report.has_errors());
+        assert!(report.errors().iter().all(|i| i.suggestion.is_some()));
+
+        report.retain_nodes(&BTreeSet::from(["REQ-B".to_string()]));
+        assert_eq!(
+            quality(&report),
+            vec![(
+                "req-missing-acceptance".to_string(),
+                Some("REQ-B".to_string())
+            )]
+        );
+    }
+
     #[test]
     fn test_fix_creates_config() {
         let dir = TempDir::new().unwrap();
@@ -639,14 +2160,18 @@ mod tests {
     #[test]
     fn test_lint_report_display() {
         let issue = LintIssue {
+            rule: "duplicate-id".to_string(),
             file: PathBuf::from("test.yaml"),
             node_id: Some("REQ-001".to_string()),
             severity: LintSeverity::Error,
             message: "test error".to_string(),
             fixable: Fixable::No,
+            anchor: None,
+            suggestion: None,
         };
         let display = format!("{}", issue);
         assert!(display.contains("error"));
+        assert!(display.contains("[duplicate-id]"));
         assert!(display.contains("REQ-001"));
     }
 }
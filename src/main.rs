@@ -2,20 +2,31 @@
 //!
 //! Linked requirements: REQ-CLI-001 through REQ-CLI-005, REQ-CORE-009
 
+// The command catalog is one large json! literal
+#![recursion_limit = "256"]
+
 use clap::{Parser, Subcommand};
 use colored::Colorize;
+use lattice::sarif;
 use lattice::{
     AddEdgeOptions, AddImplementationOptions, AddMessageOptions, AddRequirementOptions,
-    AddSourceOptions, AddThesisOptions, Audience, CURRENT_SCHEMA_VERSION, DiffEntry, DriftSeverity,
-    EditNodeOptions, ExportOptions, GapType, HtmlExportOptions, LATTICE_DIR, LatticeData,
-    LintSeverity, NodeMeta, NodeType, Plan, Priority, RefineOptions, RemoveEdgeOptions,
-    ReplaceEdgeOptions, Resolution, ResolveOptions, SchemaCheck, SearchEngine, SearchParams,
-    Status, VerifyOptions, add_edge, add_implementation, add_message, add_requirement, add_source,
-    add_thesis, build_node_index, check_schema_version, edit_node, export_html, export_narrative,
-    find_drift, find_lattice_root, find_node_path, fix_issues, format_diff_markdown,
-    format_entry_text, generate_plan, get_git_user, get_github_pages_url, init_lattice,
-    lattice_diff, lint_lattice, load_all_nodes, load_config, load_nodes_by_type,
-    refine_requirement, remove_edge, replace_edge, resolve_node, split_csv, verify_implementation,
+    AddSourceOptions, AddThesisOptions, Audience, BumpLevel, CURRENT_SCHEMA_VERSION, DiffEntry,
+    DriftSeverity, EditNodeOptions, ExportOptions, GapType, GraphExportOptions, GraphFormat,
+    HtmlExportOptions, IncomingEdgeAction, LATTICE_DIR, LINT_RULES, LatticeData, LintConfig,
+    LintRule, LintSeverity, MergeNodesOptions, NodeMeta, NodeType, Plan, PlanFilter, PlanState,
+    Priority, RefineOptions, RemoveEdgeOptions, RemoveNodeOptions, RenameNodeOptions,
+    ReplaceEdgeOptions, Resolution, ResolveOptions, RuleLevel, SchemaCheck, SearchEngine,
+    SearchParams, Status, Tally, VerifyOptions, add_edge, add_implementation, add_message,
+    add_requirement, add_source, add_thesis, build_indexes, build_node_index, changed_node_ids,
+    check_schema_version, compute_coverage, drift_reports, edit_node, explain_drift, export_graph,
+    export_html, export_narrative, find_drift, find_hotspots, find_impact, find_incoming_edges,
+    find_lattice_root, find_node_path, find_transitive_drift, fix_issues, format_diff_markdown,
+    format_entry_text, generate_plan, get_git_user, get_github_pages_url, git_churn, init_lattice,
+    lattice_at_ref, lattice_diff, lint_lattice, load_all_nodes, load_config, load_node,
+    load_nodes_by_type, lookup_node, merge_nodes, node_at_version, node_history, parse_query,
+    parse_thresholds, rebuild_id_index, refine_requirement, remove_edge, remove_node, rename_node,
+    replace_edge, resolve_node, run_query, split_csv, trace_paths, unresolved_requirements,
+    verify_implementation,
 };
 use serde_json::json;
 use std::env;
@@ -38,6 +49,13 @@ struct Cli {
     #[arg(long, requires = "json")]
     compact: bool,
 
+    /// Read the lattice as of a git ref (commit, tag, branch) instead of the
+    /// working tree. Supported by get, list, summary, search, query, drift,
+    /// impact, trace, plan and export (except drift --explain and export
+    /// --format pages)
+    #[arg(long, global = true, value_name = "REF")]
+    at: Option<String>,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -176,6 +194,10 @@ enum Commands {
         #[arg(long)]
         priority: Option<String>,
 
+        /// New effort estimate (points or days) — requirements only
+        #[arg(long)]
+        estimate: Option<f64>,
+
         /// New confidence (0.0-1.0) — theses only
         #[arg(long)]
         confidence: Option<f64>,
@@ -196,6 +218,10 @@ enum Commands {
         #[arg(long)]
         test_command: Option<String>,
 
+        /// Version bump level (major, minor, patch); defaults to config version_policy
+        #[arg(long)]
+        bump: Option<String>,
+
         /// Output format (text, json)
         #[arg(short, long, default_value = "text")]
         format: String,
@@ -286,7 +312,7 @@ enum Commands {
         format: String,
     },
 
-    /// Remove an edge between two nodes
+    /// Remove a node, or an edge between two nodes
     Remove {
         #[command(subcommand)]
         remove_command: RemoveCommands,
@@ -298,6 +324,32 @@ enum Commands {
         replace_command: ReplaceCommands,
     },
 
+    /// Merge a duplicate node into another, retargeting its incoming edges
+    Merge {
+        /// Node ID to keep
+        keep_id: String,
+
+        /// Duplicate node ID to fold in and mark superseded
+        drop_id: String,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Change a node's ID and rewrite every edge that references it
+    Rename {
+        /// Current node ID
+        old_id: String,
+
+        /// New node ID (same type prefix)
+        new_id: String,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
     // ── Health & Analysis ───────────────────────────────────────────
     /// Status overview — node counts, resolution, drift, orphans
     Summary {
@@ -316,7 +368,15 @@ enum Commands {
         #[arg(long)]
         acknowledge: Option<String>,
 
-        /// Output format (text, json)
+        /// Show what changed in each target since the bound version (from git history)
+        #[arg(long)]
+        explain: bool,
+
+        /// Also report nodes downstream of drifted nodes, with the propagation path
+        #[arg(long)]
+        transitive: bool,
+
+        /// Output format (text, json, sarif)
         #[arg(short, long, default_value = "text")]
         format: String,
     },
@@ -357,6 +417,21 @@ enum Commands {
         #[arg(long)]
         strict: bool,
 
+        /// Output format (text, json, sarif)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Report traceability gaps with coverage percentages per category and priority
+    Coverage {
+        /// Exit with code 2 if any metric is below its threshold in config.yaml
+        #[arg(long)]
+        check: bool,
+
+        /// List the IDs of uncovered nodes
+        #[arg(long)]
+        gaps: bool,
+
         /// Output format (text, json)
         #[arg(short, long, default_value = "text")]
         format: String,
@@ -372,7 +447,19 @@ enum Commands {
         #[arg(long)]
         strict: bool,
 
-        /// Output format (text, json)
+        /// List rule IDs with their default and configured levels
+        #[arg(long, conflicts_with_all = ["fix", "strict"])]
+        list_rules: bool,
+
+        /// Only report issues on nodes changed since a git ref (and untracked nodes)
+        #[arg(long, conflicts_with = "list_rules")]
+        changed: bool,
+
+        /// Git ref for --changed (default: merge-base with main)
+        #[arg(long, requires = "changed")]
+        since: Option<String>,
+
+        /// Output format (text, json, sarif)
         #[arg(short, long, default_value = "text")]
         format: String,
     },
@@ -408,16 +495,122 @@ enum Commands {
         format: String,
     },
 
+    /// Show a node's version history from git
+    Log {
+        /// Node ID (e.g., REQ-CORE-001)
+        id: String,
+
+        /// Print the node as it was at this version instead of the history
+        #[arg(long)]
+        version: Option<String>,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Find nodes with a graph query (e.g. "requirements where priority = P0 and not <-satisfies")
+    Query {
+        /// Query, e.g. "requirements where priority = P0 and ->derives_from thesis(status = contested)"
+        query: String,
+
+        /// Maximum number of results
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Show everything affected by a change to a node (blast radius)
+    Impact {
+        /// Node ID (e.g., THX-AGENT-PROTOCOL)
+        id: String,
+
+        /// Only follow this many edges back from the node
+        #[arg(long)]
+        depth: Option<usize>,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Explain how one node connects to another (or to its sources)
+    Trace {
+        /// Node to start from (e.g., IMP-AUTH-JWT)
+        from: String,
+
+        /// Node to reach; defaults to every upstream node with no outgoing edges
+        to: Option<String>,
+
+        /// Show simple paths, shortest first, instead of only the shortest
+        #[arg(long)]
+        all: bool,
+
+        /// Maximum number of paths to show
+        #[arg(long, default_value = "50")]
+        limit: usize,
+
+        /// Longest path to follow, in hops
+        #[arg(long)]
+        max_depth: Option<usize>,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Rank requirements and theses by git churn on their implementation files
+    Hotspots {
+        /// Start of the window; anything `git log --since` accepts
+        #[arg(long, default_value = "90 days ago")]
+        since: String,
+
+        /// Only show this node type (requirement, thesis)
+        #[arg(long = "type")]
+        node_type: Option<String>,
+
+        /// Maximum number of hotspots to show
+        #[arg(long, default_value = "20")]
+        limit: usize,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
     /// Plan implementation order based on requirement dependencies
     Plan {
         /// Requirement IDs to plan (e.g., REQ-CLI-001 REQ-CLI-002)
-        #[arg(required = true)]
+        #[arg(required_unless_present = "all")]
         requirements: Vec<String>,
+
+        /// Plan every unresolved requirement
+        #[arg(long, conflicts_with = "requirements")]
+        all: bool,
+
+        /// With --all: only requirements in this category
+        #[arg(long)]
+        category: Option<String>,
+
+        /// With --all: only requirements with this tag
+        #[arg(long)]
+        tag: Option<String>,
+
+        /// With --all: only requirements with this priority (P0, P1, P2)
+        #[arg(long)]
+        priority: Option<String>,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
     },
 
     /// Export to narrative, JSON, HTML, or GitHub Pages
     Export {
-        /// Export format (narrative, json, html, pages)
+        /// Export format (narrative, json, html, pages, dot, mermaid, graphml, cytoscape)
         #[arg(short, long, default_value = "narrative")]
         format: String,
 
@@ -433,9 +626,17 @@ enum Commands {
         #[arg(long)]
         include_internal: bool,
 
-        /// Output directory for HTML export
+        /// Output directory for HTML export, or output file for graph formats
         #[arg(short, long)]
         output: Option<String>,
+
+        /// Graph formats: only export nodes connected to this node
+        #[arg(long)]
+        root: Option<String>,
+
+        /// Graph formats with --root: only follow this many edges from the root
+        #[arg(long)]
+        depth: Option<usize>,
     },
 
     // ── Setup ───────────────────────────────────────────────────────
@@ -473,6 +674,12 @@ enum Commands {
         format: String,
     },
 
+    /// Manage the cached node ID index
+    Index {
+        #[command(subcommand)]
+        index_command: IndexCommands,
+    },
+
     /// Show commands, concepts, or workflows
     Help {
         /// Output as structured JSON for agent consumption
@@ -541,6 +748,10 @@ enum AddCommands {
         #[arg(long)]
         priority: String,
 
+        /// Effort estimate (points or days)
+        #[arg(long)]
+        estimate: Option<f64>,
+
         /// Category (e.g., API, CORE, CLI)
         #[arg(long)]
         category: String,
@@ -757,6 +968,32 @@ enum AddCommands {
 
 #[derive(Subcommand)]
 enum RemoveCommands {
+    /// Archive or delete a node and clean up edges pointing at it
+    Node {
+        /// Node ID to remove
+        id: String,
+
+        /// Delete the file instead of archiving it to .lattice/archive/
+        #[arg(long)]
+        hard: bool,
+
+        /// Remove incoming edges from the nodes that reference this one
+        #[arg(long, conflicts_with = "retarget")]
+        remove_edges: bool,
+
+        /// Retarget incoming edges to this node ID instead
+        #[arg(long)]
+        retarget: Option<String>,
+
+        /// Show what would change without modifying any files
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
     /// Remove an edge between two nodes
     Edge {
         /// Source node ID (edge goes FROM this node)
@@ -778,6 +1015,16 @@ enum RemoveCommands {
     },
 }
 
+#[derive(Subcommand)]
+enum IndexCommands {
+    /// Discard the cached ID index and rebuild it from the .lattice/ files
+    Rebuild {
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+}
+
 #[derive(Subcommand)]
 enum ReplaceCommands {
     /// Replace an edge's target with a new target
@@ -809,7 +1056,18 @@ enum ReplaceCommands {
     },
 }
 
+/// Commands that can read a past lattice via the global `--at <ref>` option.
+const AT_REF_COMMANDS: &[&str] = &[
+    "get", "list", "summary", "search", "query", "drift", "impact", "trace", "plan", "export",
+];
+
+/// Snapshot root set by `--at <ref>`; when present, every command reads from it.
+static AT_REF_ROOT: std::sync::OnceLock<std::path::PathBuf> = std::sync::OnceLock::new();
+
 fn get_lattice_root() -> std::path::PathBuf {
+    if let Some(root) = AT_REF_ROOT.get() {
+        return root.clone();
+    }
     let cwd = env::current_dir().expect("Failed to get current directory");
     match find_lattice_root(&cwd) {
         Some(root) => root,
@@ -820,6 +1078,37 @@ fn get_lattice_root() -> std::path::PathBuf {
     }
 }
 
+/// Print the field-level changes behind a drift item (`drift --explain`).
+fn print_drift_explanation(item: &lattice::graph::DriftItem) {
+    let Some(explanation) = &item.explanation else {
+        println!(
+            "     {}",
+            format!("(v{} not found in git history)", item.bound_version).dimmed()
+        );
+        return;
+    };
+    println!(
+        "     {}",
+        format!(
+            "changed since {}: {}",
+            &explanation.bound_commit[..std::cmp::min(8, explanation.bound_commit.len())],
+            explanation.changed_fields.join(", ")
+        )
+        .dimmed()
+    );
+    for (field, lines) in &explanation.diffs {
+        println!("     {}:", field);
+        for line in lines {
+            let styled = match line.chars().next() {
+                Some('-') => line.red(),
+                Some('+') => line.green(),
+                _ => line.dimmed(),
+            };
+            println!("       {}", styled);
+        }
+    }
+}
+
 fn parse_priority(s: &str) -> Priority {
     match s.to_uppercase().as_str() {
         "P0" => Priority::P0,
@@ -887,6 +1176,14 @@ fn is_json(format: &str) -> bool {
     format == "json"
 }
 
+fn is_sarif(format: &str) -> bool {
+    format == "sarif"
+}
+
+fn print_sarif(log: &serde_json::Value) {
+    println!("{}", serde_json::to_string_pretty(log).unwrap());
+}
+
 fn emit_created(format: &str, node_type: &str, id: &str, path: &std::path::Path) {
     if is_json(format) {
         println!(
@@ -1072,15 +1369,102 @@ fn prompt_yes_no(message: &str) -> bool {
     matches!(line.trim().to_lowercase().as_str(), "y" | "yes")
 }
 
+/// List lint rules with their default level and the level config.yaml sets.
+fn print_lint_rules(config: &LintConfig, format: &str) {
+    // (id, default level, fixable, description, source)
+    let rules: Vec<(&str, RuleLevel, bool, &str, &str)> = LINT_RULES
+        .iter()
+        .map(|r| (r.id, r.level, r.fixable, r.description, "built-in"))
+        .chain(
+            config
+                .policies
+                .iter()
+                .filter(|p| LintRule::find(&p.id).is_none())
+                .map(|p| {
+                    (
+                        p.id.as_str(),
+                        p.severity,
+                        false,
+                        p.message.as_str(),
+                        "policy",
+                    )
+                }),
+        )
+        .collect();
+    let level = |id: &str, default: RuleLevel| config.level(id).unwrap_or(default);
+
+    if is_json(format) {
+        let rules: Vec<_> = rules
+            .iter()
+            .map(|&(id, default, fixable, description, source)| {
+                json!({
+                    "id": id,
+                    "default": default.to_string(),
+                    "level": level(id, default).to_string(),
+                    "fixable": fixable,
+                    "description": description,
+                    "source": source,
+                })
+            })
+            .collect();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&json!({ "rules": rules })).unwrap()
+        );
+        return;
+    }
+
+    println!(
+        "{}",
+        format!("{:<22} {:<8} {:<8} DESCRIPTION", "RULE", "DEFAULT", "LEVEL").bold()
+    );
+    for &(id, default, fixable, description, source) in &rules {
+        let configured = level(id, default);
+        let configured_str = format!("{:<8}", configured.to_string());
+        let configured_str = if configured == default {
+            configured_str.normal()
+        } else {
+            configured_str.cyan()
+        };
+        let mut notes = Vec::new();
+        if fixable {
+            notes.push("fixable");
+        }
+        if source == "policy" {
+            notes.push("policy");
+        }
+        let notes = if notes.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", notes.join(", "))
+        };
+        println!(
+            "{:<22} {:<8} {} {}{}",
+            id,
+            default.to_string(),
+            configured_str,
+            description,
+            notes
+        );
+    }
+    println!();
+    println!(
+        "{}",
+        "Configure under 'lint:' in .lattice/config.yaml, or list rule IDs in a node's lint_ignore field."
+            .dimmed()
+    );
+}
+
 fn print_plan(plan: &Plan, index: &lattice::NodeIndex) {
     // Print summary
     println!("{}", "IMPLEMENTATION PLAN".bold());
     println!(
         "{}",
         format!(
-            "{} ready, {} blocked, {} verified\n",
+            "{} ready, {} blocked, {} in progress, {} verified\n",
             plan.ready.len(),
             plan.blocked.len(),
+            plan.in_progress.len(),
             plan.verified.len()
         )
         .dimmed()
@@ -1142,6 +1526,9 @@ fn print_plan(plan: &Plan, index: &lattice::NodeIndex) {
             Some(Resolution::Blocked) => "[blocked]".red(),
             Some(Resolution::Deferred) => "[deferred]".yellow(),
             Some(Resolution::Wontfix) => "[wontfix]".dimmed(),
+            None if item.state == PlanState::InProgress => {
+                format!("[in progress: {}]", item.implementations.join(", ")).blue()
+            }
             None => "".normal(),
         };
         println!(
@@ -1153,6 +1540,109 @@ fn print_plan(plan: &Plan, index: &lattice::NodeIndex) {
             item.title
         );
     }
+
+    // Print parallelizable waves
+    if !plan.waves.is_empty() {
+        println!();
+        println!(
+            "{}",
+            "WAVES (items in a wave can be worked on in parallel)".bold()
+        );
+        for (i, wave) in plan.waves.iter().enumerate() {
+            println!("{}", format!("Wave {} ({}):", i + 1, wave.len()).cyan());
+            for item in wave
+                .iter()
+                .filter_map(|id| plan.items.iter().find(|i| &i.id == id))
+            {
+                let priority = item
+                    .priority
+                    .as_ref()
+                    .map(|p| format!("[{:?}] ", p))
+                    .unwrap_or_default();
+                let unblocks = if item.unblocks > 0 {
+                    format!(" (unblocks {})", item.unblocks)
+                        .dimmed()
+                        .to_string()
+                } else {
+                    String::new()
+                };
+                println!(
+                    "  {} {}- {}{}",
+                    item.id,
+                    priority.yellow(),
+                    item.title,
+                    unblocks
+                );
+            }
+        }
+    }
+
+    let estimated = plan.schedule.len() > plan.unestimated.len();
+
+    // Print the chain that bounds how soon the plan can be finished
+    if !plan.critical_path.is_empty() {
+        println!();
+        if estimated {
+            println!(
+                "{}",
+                format!("CRITICAL PATH ({} total)", plan.critical_path_effort).bold()
+            );
+        } else {
+            println!("{}", "CRITICAL PATH (longest dependency chain)".bold());
+        }
+        for item in plan
+            .critical_path
+            .iter()
+            .filter_map(|id| plan.items.iter().find(|i| &i.id == id))
+        {
+            let estimate = item
+                .estimate
+                .map(|e| format!(" [{}]", e))
+                .unwrap_or_default();
+            println!("  {}{} - {}", item.id.red(), estimate.dimmed(), item.title);
+        }
+    }
+
+    if estimated {
+        println!();
+        println!("{}", "REMAINING EFFORT BY CATEGORY".bold());
+        for (category, effort) in &plan.effort_by_category {
+            println!("  {:<16} {}", category, effort);
+        }
+
+        println!();
+        println!("{}", "SCHEDULE (by earliest start)".bold());
+        for item in plan
+            .schedule
+            .iter()
+            .filter_map(|id| plan.items.iter().find(|i| &i.id == id))
+        {
+            let estimate = item
+                .estimate
+                .map(|e| e.to_string())
+                .unwrap_or_else(|| "?".to_string());
+            println!(
+                "  {} {} - {} {}",
+                format!("@{:<5}", item.earliest_start.unwrap_or_default()).cyan(),
+                item.id,
+                item.title,
+                format!("[{}]", estimate).dimmed()
+            );
+        }
+
+        if !plan.unestimated.is_empty() {
+            println!();
+            println!(
+                "{}",
+                format!(
+                    "{} item(s) have no estimate and count as zero: {}",
+                    plan.unestimated.len(),
+                    plan.unestimated.join(", ")
+                )
+                .yellow()
+            );
+        }
+    }
 }
 
 fn summarize_edges(edges: &lattice::types::Edges) -> Option<String> {
@@ -1265,7 +1755,7 @@ fn build_command_catalog() -> serde_json::Value {
                 "extends": "Node extends another node — adds capability without replacing. Direction: any → any.",
                 "reveals_gap_in": "Implementation discovered a gap in a requirement or thesis — feedback edge, knowledge flowing upstream from code. Direction: implementation → requirement/thesis.",
                 "challenges": "Evidence contradicts a thesis — signals the thesis may need revision. Direction: any → thesis.",
-                "validates": "Implementation confirms a thesis through working code — positive feedback. Direction: implementation → thesis.",
+                "validates": "Implementation confirms a thesis through working code, or a thesis corroborates another — positive feedback. Direction: implementation/thesis → thesis.",
                 "conflicts_with": "Two nodes make incompatible claims — needs resolution. Direction: any → any.",
                 "supersedes": "Node replaces an older node — the old node is deprecated. Direction: new → old.",
                 "rebuts": "Thesis directly argues against another thesis — structured adversarial debate. Direction: thesis → thesis.",
@@ -1375,14 +1865,14 @@ fn build_command_catalog() -> serde_json::Value {
             },
             {
                 "name": "get",
-                "description": "Get a specific node by ID with full details including edges and resolution. Use when you know the node ID and need complete context.",
+                "description": "Get a specific node by ID with full details including edges, resolution, and incoming edges (which nodes point at it). Use when you know the node ID and need complete context.",
                 "parameters": [
                     param("id", "string", true, "Node ID (e.g. REQ-CORE-001)"),
                     param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
                 ],
-                "output_schema_hint": "{ id, title, body, status, version, priority?, category?, tags[], edges: { derives_from[], depends_on[], satisfies[], ... }, resolution?, created_at, created_by }",
+                "output_schema_hint": "{ id, title, body, status, version, priority?, category?, tags[], edges: { derives_from[], depends_on[], satisfies[], ... }, incoming: [{ from_id, from_type, edge_type, version? }], resolution?, created_at, created_by }",
                 "examples": [
-                    {"command": "lattice get REQ-CORE-001", "explanation": "View a requirement with its edges and resolution status"},
+                    {"command": "lattice get REQ-CORE-001", "explanation": "View a requirement with its edges, resolution status, and the implementations that satisfy it"},
                     {"command": "lattice get THX-AGENT-NATIVE-TOOLS --format json", "explanation": "Get full node data as JSON for processing"}
                 ],
                 "related_commands": ["list", "search", "edit"]
@@ -1414,7 +1904,23 @@ fn build_command_catalog() -> serde_json::Value {
                     {"command": "lattice search --related-to THX-AGENT-NATIVE-TOOLS", "explanation": "Find all nodes connected to a thesis"},
                     {"command": "lattice search --tag agent --category AGENT", "explanation": "Filter by tag and category simultaneously"}
                 ],
-                "related_commands": ["list", "get"]
+                "related_commands": ["list", "get", "query"]
+            },
+            {
+                "name": "query",
+                "description": "Answer ad hoc questions with a small graph query language: a node type, then 'where' with field predicates (FIELD = | != | ~ VALUE) and edge patterns (->EDGE [TYPE] [(...)] for outgoing, <-EDGE for incoming, 'any' for every edge type), combined with and/or/not and parentheses. Fields: id, title, body, type, status, priority, resolution, category, tag, version, created_by.",
+                "parameters": [
+                    param("query", "string", true, "Query, e.g. \"requirements where priority = P0 and not <-satisfies implementation\""),
+                    param("--limit", "integer", false, "Maximum number of results"),
+                    param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
+                ],
+                "output_schema_hint": "{ query, count, results: [{ id, type, title, version, status, priority?, resolution? }] }",
+                "examples": [
+                    {"command": "lattice query \"requirements where priority = P0 and not <-satisfies implementation and ->derives_from thesis(status = contested)\"", "explanation": "Critical requirements nobody implements that rest on a contested thesis"},
+                    {"command": "lattice query \"theses where not <-derives_from\"", "explanation": "Theses no requirement derives from"},
+                    {"command": "lattice query \"implementations where ->satisfies requirement(resolution = verified)\" -f json", "explanation": "Implementations of verified requirements, as JSON"}
+                ],
+                "related_commands": ["search", "get", "trace"]
             },
             {
                 "name": "add requirement",
@@ -1424,6 +1930,7 @@ fn build_command_catalog() -> serde_json::Value {
                     param("--title", "string", true, "Requirement title"),
                     param("--body", "string", true, "Requirement body/description"),
                     param("--priority", "string", true, "Priority: P0, P1, P2"),
+                    param("--estimate", "float", false, "Effort estimate in points or days"),
                     param("--category", "string", true, "Category (e.g. API, CORE, CLI)"),
                     param("--tags", "string", false, "Comma-separated tags"),
                     param("--derives-from", "string", false, "Comma-separated thesis IDs"),
@@ -1531,6 +2038,25 @@ fn build_command_catalog() -> serde_json::Value {
                 ],
                 "related_commands": ["remove edge", "replace edge", "refine"]
             },
+            {
+                "name": "remove node",
+                "description": "Remove a node from the lattice. Archives to .lattice/archive/ with status deprecated by default, or deletes with --hard. Lists every incoming edge and can remove or retarget them.",
+                "parameters": [
+                    param("id", "string", true, "Node ID to remove"),
+                    param("--hard", "bool", false, "Delete the file instead of archiving it"),
+                    param("--remove-edges", "bool", false, "Remove incoming edges from referencing nodes"),
+                    param("--retarget", "string", false, "Retarget incoming edges to this node ID"),
+                    param("--dry-run", "bool", false, "Preview changes without modifying files"),
+                    param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
+                ],
+                "output_schema_hint": "{ success, dry_run, id, action, file, archived_to?, incoming: [{ from, edge_type, version }], incoming_action, retarget_to?, edges_updated }",
+                "examples": [
+                    {"command": "lattice remove node REQ-OLD-001 --dry-run", "explanation": "Preview which edges point at the node before removing it"},
+                    {"command": "lattice remove node REQ-OLD-001 --retarget REQ-NEW-001", "explanation": "Archive a requirement and move everything that referenced it to its replacement"},
+                    {"command": "lattice remove node SRC-DUPLICATE --hard --remove-edges", "explanation": "Delete a node outright and drop edges that pointed at it"}
+                ],
+                "related_commands": ["remove edge", "replace edge", "lint"]
+            },
             {
                 "name": "remove edge",
                 "description": "Remove an edge between two nodes. Use when a relationship is no longer valid.",
@@ -1543,21 +2069,49 @@ fn build_command_catalog() -> serde_json::Value {
                 "examples": [
                     {"command": "lattice remove edge --from IMP-CLI-001 --type satisfies --to REQ-CORE-001", "explanation": "Remove a satisfaction binding that is no longer accurate"}
                 ],
-                "related_commands": ["add edge", "replace edge"]
+                "related_commands": ["add edge", "replace edge", "remove node"]
             },
             {
-                "name": "replace edge",
-                "description": "Retarget an existing edge to a new node. Use when a requirement is split or reorganized and existing edges need to follow.",
+                "name": "merge",
+                "description": "Merge a duplicate node into the one being kept. Unions tags, outgoing edges and acceptance tests, bumps the kept node's version, retargets every incoming edge, and marks the dropped node superseded with a supersedes edge from the survivor.",
                 "parameters": [
-                    param("--from", "string", true, "Source node ID"),
-                    param("--edge-type / --type", "string", true, "Edge type"),
-                    param("--old-to", "string", true, "Current target node ID to replace"),
-                    param("--new-to", "string", true, "New target node ID"),
-                    param("--rationale", "string", false, "Updated rationale for the edge"),
+                    param("keep_id", "string", true, "Node ID to keep"),
+                    param("drop_id", "string", true, "Duplicate node ID (same type) to fold in"),
                     param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
                 ],
+                "output_schema_hint": "{ success, keep_id, drop_id, version, tags_added, edges_added, acceptance_added, updated_nodes: [{ id, edges }] }",
                 "examples": [
-                    {"command": "lattice replace edge --from IMP-CLI-001 --type satisfies --old-to REQ-CORE-001 --new-to REQ-CORE-002 --rationale 'Requirement was split'", "explanation": "Retarget an implementation's satisfaction edge after a requirement was reorganized"}
+                    {"command": "lattice merge REQ-AUTH-001 REQ-AUTH-007", "explanation": "REQ-AUTH-007 duplicates REQ-AUTH-001; fold it in and point its implementations at REQ-AUTH-001"}
+                ],
+                "related_commands": ["rename", "remove node", "replace edge"]
+            },
+            {
+                "name": "rename",
+                "description": "Change a node's ID. Moves the file to the canonical path for the new ID, rewrites every incoming edge (keeping pinned versions), and records the old ID as an alias so get and search still resolve it.",
+                "parameters": [
+                    param("old_id", "string", true, "Current node ID"),
+                    param("new_id", "string", true, "New node ID (must keep the same type prefix)"),
+                    param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
+                ],
+                "output_schema_hint": "{ success, old_id, new_id, old_file, new_file, updated_nodes: [{ id, edges }] }",
+                "examples": [
+                    {"command": "lattice rename REQ-CORE-017 REQ-GRAPH-001", "explanation": "Move a requirement into a better-fitting category; edges pointing at REQ-CORE-017 now point at REQ-GRAPH-001"}
+                ],
+                "related_commands": ["replace edge", "remove node", "get"]
+            },
+            {
+                "name": "replace edge",
+                "description": "Retarget an existing edge to a new node. Use when a requirement is split or reorganized and existing edges need to follow.",
+                "parameters": [
+                    param("--from", "string", true, "Source node ID"),
+                    param("--edge-type / --type", "string", true, "Edge type"),
+                    param("--old-to", "string", true, "Current target node ID to replace"),
+                    param("--new-to", "string", true, "New target node ID"),
+                    param("--rationale", "string", false, "Updated rationale for the edge"),
+                    param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
+                ],
+                "examples": [
+                    {"command": "lattice replace edge --from IMP-CLI-001 --type satisfies --old-to REQ-CORE-001 --new-to REQ-CORE-002 --rationale 'Requirement was split'", "explanation": "Retarget an implementation's satisfaction edge after a requirement was reorganized"}
                 ],
                 "related_commands": ["add edge", "remove edge"]
             },
@@ -1581,23 +2135,26 @@ fn build_command_catalog() -> serde_json::Value {
             },
             {
                 "name": "edit",
-                "description": "Edit fields on an existing node. Auto-bumps the patch version, which may trigger drift on downstream edges. Use when a node's content needs updating.",
+                "description": "Edit fields on an existing node. Auto-bumps the version (patch by default, or per the version_policy in config.yaml), which may trigger drift on downstream edges. Use --bump to choose the level explicitly. Use when a node's content needs updating.",
                 "parameters": [
                     param("id", "string", true, "Node ID (e.g. REQ-CORE-001)"),
                     param("--title", "string", false, "New title"),
                     param("--body", "string", false, "New body"),
                     param("--status", "string", false, "New status: draft, active, deprecated, superseded"),
                     param("--priority", "string", false, "New priority: P0, P1, P2 (requirements only)"),
+                    param("--estimate", "float", false, "New effort estimate in points or days (requirements only)"),
                     param("--tags", "string", false, "Comma-separated tags (replaces existing)"),
                     param("--category", "string", false, "New category"),
                     param("--files", "string", false, "Comma-separated file paths (replaces existing, implementations only)"),
                     param("--test-command", "string", false, "Test command (implementations only)"),
+                    param("--bump", "string", false, "Version bump: major, minor, patch (default: config version_policy, else patch)"),
                     param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
                 ],
-                "output_schema_hint": "{ id, title, version, changed_fields[] }",
+                "output_schema_hint": "{ success, id, version, file }",
                 "examples": [
                     {"command": "lattice edit REQ-CORE-001 --title 'Updated title' --priority P0", "explanation": "Update a requirement's title and escalate its priority"},
-                    {"command": "lattice edit IMP-CLI-001 --files 'src/main.rs,src/lib.rs' --test-command 'cargo test'", "explanation": "Update an implementation's file bindings and test command"}
+                    {"command": "lattice edit IMP-CLI-001 --files 'src/main.rs,src/lib.rs' --test-command 'cargo test'", "explanation": "Update an implementation's file bindings and test command"},
+                    {"command": "lattice edit REQ-CORE-001 --body 'Rewritten scope...' --bump major", "explanation": "A breaking rewrite — dependents see major drift"}
                 ],
                 "related_commands": ["get", "drift"]
             },
@@ -1642,29 +2199,40 @@ fn build_command_catalog() -> serde_json::Value {
                 "parameters": [
                     param("--check", "bool", false, "Exit with code 2 if drift detected"),
                     param("--acknowledge", "string", false, "Node ID to acknowledge drift on (re-snapshots edge versions)"),
-                    param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
+                    param("--explain", "bool", false, "Include old/new title, body and acceptance tests and a line diff per drifted edge (from git history)"),
+                    param("--transitive", "bool", false, "Also report indirectly drifted nodes downstream, with propagation path and hop count"),
+                    param_s("--format", "-f", "string", false, "Output format: text, json, sarif (default: text)")
                 ],
                 "output_schema_hint": "{ drift_detected: bool, items: [{ node_id, edge_type, target_id, bound_version, current_version }] }",
                 "examples": [
                     {"command": "lattice drift", "explanation": "Check all edges for version drift — shows which nodes have stale bindings"},
                     {"command": "lattice drift --check --format json", "explanation": "Machine-readable drift check — exits non-zero if drift exists (useful in CI)"},
+                    {"command": "lattice drift --format sarif", "explanation": "Drifted edges as SARIF results pointing at the edge's line in the node file"},
+                    {"command": "lattice drift --explain", "explanation": "Show what each drifted target actually changed since the version it was bound at"},
+                    {"command": "lattice drift --transitive", "explanation": "After a source changes, see every thesis, requirement and implementation downstream of it"},
                     {"command": "lattice drift --acknowledge REQ-INFRA-015", "explanation": "After reviewing a node, re-snapshot its edge versions to clear the drift warning"}
                 ],
                 "related_commands": ["get", "edit", "summary"]
             },
             {
                 "name": "lint",
-                "description": "Check lattice files for structural issues like missing fields, broken edges, or invalid references. Run as part of health checks or CI.",
+                "description": "Check lattice files for structural issues like missing fields, broken edges, or invalid references. Also flags edges between node types their edge type does not allow (e.g. satisfies must go implementation -> requirement) and depends_on/supersedes cycles, with the offending path. Implementation file bindings are checked too: each path (or glob) must match a file relative to the lattice root (../ may reach sibling directories; absolute paths are flagged), and listed functions must be defined in Rust, TypeScript/JavaScript, Python or Go files; --fix prunes bindings to missing files. Opt-in requirement-quality rules (req-weak-words, req-missing-acceptance, req-passive-voice, req-compound, req-title-length) flag vague or untestable requirement text, each with a suggested fix; enable them under lint.rules and use --changed to check only nodes changed since a git ref. Every issue has a stable rule ID; the lint: section of config.yaml can turn rules off, change their severity, or suppress them for node IDs and path globs, and a node can list rules in its lint_ignore field. Teams add their own rules under lint.policies: a node type, 'when' field values that select nodes, and 'require' checks on fields or edges (with min counts), each with a message and severity. Run as part of health checks or CI.",
                 "parameters": [
                     param("--fix", "bool", false, "Attempt to auto-fix fixable issues"),
                     param("--strict", "bool", false, "Exit with non-zero status on any issue"),
-                    param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
+                    param("--list-rules", "bool", false, "List built-in rules and config policies with their default and configured levels"),
+                    param("--changed", "bool", false, "Only report issues on nodes changed since --since (and untracked nodes), plus issues not tied to a node"),
+                    param("--since", "string", false, "Git ref for --changed (default: merge-base with main)"),
+                    param_s("--format", "-f", "string", false, "Output format: text, json, sarif (default: text)")
                 ],
-                "output_schema_hint": "{ issues: [{ severity, node_id?, message, fixable }], total, fixable_count }",
+                "output_schema_hint": "{ errors, warnings, fixable, issues: [{ rule, file, severity, node_id?, message, suggestion?, fixable }], fixed }",
                 "examples": [
+                    {"command": "lattice lint --list-rules", "explanation": "Show every rule ID and the level it reports at in this repo"},
                     {"command": "lattice lint", "explanation": "Check for structural issues across all lattice files"},
-                    {"command": "lattice lint --fix", "explanation": "Auto-fix issues like missing config fields or malformed references"},
-                    {"command": "lattice lint --strict --format json", "explanation": "Strict mode for CI — exits non-zero on any issue"}
+                    {"command": "lattice lint --changed --strict", "explanation": "Hold only the nodes changed on this branch to the configured rules, e.g. the requirement-quality ones"},
+                    {"command": "lattice lint --fix", "explanation": "Auto-fix issues like missing config fields, unbound edge versions or file bindings to deleted files"},
+                    {"command": "lattice lint --strict --format json", "explanation": "Strict mode for CI — exits non-zero on any issue"},
+                    {"command": "lattice lint --format sarif > lattice.sarif", "explanation": "SARIF 2.1.0 with rule IDs and file/line locations, for GitHub code scanning"}
                 ],
                 "related_commands": ["drift", "summary", "freshness"]
             },
@@ -1705,16 +2273,34 @@ fn build_command_catalog() -> serde_json::Value {
                 "description": "Unified health check combining freshness, change pressure, and code impact into a single PASS/WARN/FAIL verdict. Use as the single CI gate for lattice health.",
                 "parameters": [
                     param("--check", "bool", false, "Exit with code 2 on FAIL verdict (for CI/hooks)"),
-                    param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
+                    param("--strict", "bool", false, "Also FAIL on lint issues (including config policies) and on bound files changed without a lattice update"),
+                    param_s("--format", "-f", "string", false, "Output format: text, json, sarif (default: text)")
                 ],
-                "output_schema_hint": "{ verdict, freshness: { gap_hours }, change_pressure: { contested_theses, drift_items, total }, code_impact: { total_files_changed, tracked_files_changed, bound_files_count } }",
+                "output_schema_hint": "{ verdict, freshness: { gap_hours }, change_pressure: { contested_theses, drift_items, total }, code_impact: { total_files_changed, tracked_files_changed, bound_files_count }, lint?: { issues, errors, by_rule } }",
                 "examples": [
                     {"command": "lattice health", "explanation": "Check overall lattice health — combines freshness, change pressure, and code impact"},
                     {"command": "lattice health --check", "explanation": "CI gate — exits non-zero if lattice health is FAIL"},
-                    {"command": "lattice health --format json", "explanation": "Machine-readable health report for automation"}
+                    {"command": "lattice health --format json", "explanation": "Machine-readable health report for automation"},
+                    {"command": "lattice health --strict --format sarif", "explanation": "Drift, contested theses, changed bound files and lint issues as SARIF, with the verdict in run properties"}
                 ],
                 "related_commands": ["freshness", "assess", "drift", "summary"]
             },
+            {
+                "name": "coverage",
+                "description": "Report gaps along the traceability chain: theses with no supporting source, requirements with no deriving thesis or satisfying implementation, implementations with no files, requirements without acceptance tests, and messages not grounded in an active thesis. Each gets a coverage percentage broken down by category and priority. Minimum percentages per metric can be set under coverage_thresholds in config.yaml.",
+                "parameters": [
+                    param("--check", "bool", false, "Exit with code 2 if any metric is below its configured threshold (for CI/hooks)"),
+                    param("--gaps", "bool", false, "List the IDs of uncovered nodes"),
+                    param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
+                ],
+                "output_schema_hint": "{ passed, failures[], metrics: [{ metric, label, total, covered, percent, threshold?, passed, gaps[], by_category: { name: { total, covered, percent } }, by_priority: { P0|P1|P2|none: { total, covered, percent } } }] }",
+                "examples": [
+                    {"command": "lattice coverage", "explanation": "See which parts of the traceability chain are missing"},
+                    {"command": "lattice coverage --gaps", "explanation": "List the requirements, theses and implementations that need links"},
+                    {"command": "lattice coverage --check --format json", "explanation": "CI gate against coverage_thresholds in config.yaml"}
+                ],
+                "related_commands": ["health", "lint", "summary", "query"]
+            },
             {
                 "name": "summary",
                 "description": "Show a compact status overview — node counts, resolution breakdown, drift status, and orphaned nodes. Start here to understand the lattice's current state.",
@@ -1742,34 +2328,109 @@ fn build_command_catalog() -> serde_json::Value {
                     {"command": "lattice diff --since HEAD~3 --md", "explanation": "Generate markdown summary of changes over the last 3 commits"},
                     {"command": "lattice diff --format json", "explanation": "Machine-readable diff for CI or automated comments"}
                 ],
-                "related_commands": ["summary", "get"]
+                "related_commands": ["summary", "get", "log"]
+            },
+            {
+                "name": "log",
+                "description": "Show the git history of a single node: every commit that touched its file, with version, author, date and changed fields. With --version, print the node as it was at that version.",
+                "parameters": [
+                    param("id", "string", true, "Node ID (e.g. REQ-CORE-001)"),
+                    param("--version", "string", false, "Print the node as of this version (e.g. 1.0.3)"),
+                    param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
+                ],
+                "output_schema_hint": "{ id, revisions: [{ commit, author, date, subject, version, fields[] }] } or, with --version, { id, version, commit, node }",
+                "examples": [
+                    {"command": "lattice log REQ-AUTH-001", "explanation": "See how a requirement evolved and which commits bumped its version"},
+                    {"command": "lattice log REQ-AUTH-001 --version 1.0.3", "explanation": "Read what the requirement said at 1.0.3, e.g. the version an implementation is still bound to"}
+                ],
+                "related_commands": ["diff", "drift", "get"]
+            },
+            {
+                "name": "impact",
+                "description": "Show the blast radius of changing or dropping a node: every thesis, requirement, implementation and message that reaches it through any edge type, grouped by distance, plus the files bound by affected implementations.",
+                "parameters": [
+                    param("id", "string", true, "Node ID to analyze (e.g. THX-AGENT-PROTOCOL)"),
+                    param("--depth", "integer", false, "Only follow this many edges back from the node"),
+                    param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
+                ],
+                "output_schema_hint": "{ id, total, by_type: { type: count }, levels: [{ distance, nodes: [{ id, type, title, via, edge_type }] }], files[] }",
+                "examples": [
+                    {"command": "lattice impact THX-AGENT-PROTOCOL", "explanation": "See what breaks if this thesis is dropped or revised"},
+                    {"command": "lattice impact REQ-CORE-001 --depth 1 --format json", "explanation": "List only the nodes that point at the requirement directly"}
+                ],
+                "related_commands": ["get", "drift", "edit"]
+            },
+            {
+                "name": "trace",
+                "description": "Explain why a node exists: the chains of typed edges from one node to another, e.g. IMP -> satisfies -> REQ -> derives_from -> THX -> supported_by -> SRC, with pinned versions and rationales. Hops whose binding has drifted are flagged. Without a target, traces to every upstream node with no outgoing edges.",
+                "parameters": [
+                    param("from", "string", true, "Node ID to start from (e.g. IMP-AUTH-JWT)"),
+                    param("to", "string", false, "Node ID to reach (default: every upstream leaf, typically sources)"),
+                    param("--all", "bool", false, "Show simple paths shortest first instead of only the shortest per end node"),
+                    param("--limit", "integer", false, "Maximum number of paths to show (default: 50)"),
+                    param("--max-depth", "integer", false, "Longest path to follow, in hops (default: unlimited; --all also stops after a fixed expansion budget)"),
+                    param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
+                ],
+                "output_schema_hint": "{ from, to?, path_count, paths: [{ length, drifted, hops: [{ from, edge_type, to, bound_version, current_version, rationale?, drift? }] }] }",
+                "examples": [
+                    {"command": "lattice trace IMP-AUTH-JWT", "explanation": "Walk from an implementation back to the sources that justify it"},
+                    {"command": "lattice trace IMP-AUTH-JWT THX-STATELESS-AUTH --all", "explanation": "List every way the implementation connects to a thesis"}
+                ],
+                "related_commands": ["get", "impact", "drift"]
+            },
+            {
+                "name": "hotspots",
+                "description": "Rank requirements and theses by how much their implementation files have changed in git over a window, weighted by drift and contested status: score = lines changed x (1 + 0.5 x drift items) x (2 if contested). Churn on an implementation's files counts toward the requirements it satisfies and the theses those derive from. Use to find the parts of the spec under the most pressure.",
+                "parameters": [
+                    param("--since", "string", false, "Start of the window, anything git log --since accepts (default: \"90 days ago\")"),
+                    param("--type", "string", false, "Only show requirement or thesis hotspots"),
+                    param("--limit", "integer", false, "Maximum number of hotspots to show (default: 20)"),
+                    param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
+                ],
+                "output_schema_hint": "{ since, total, hotspots: [{ id, type, title, score, lines, commits, drift, contested, implementations[], files: [{ path, lines }] }] }",
+                "examples": [
+                    {"command": "lattice hotspots", "explanation": "Which requirements and theses sit on the code that changed most in the last 90 days"},
+                    {"command": "lattice hotspots --since 2026-01-01 --type thesis --format json", "explanation": "Theses under the most pressure since the start of the year"}
+                ],
+                "related_commands": ["impact", "drift", "coverage"]
             },
             {
                 "name": "plan",
-                "description": "Plan implementation order for requirements based on their dependency graph. Shows which requirements are ready, which are blocked, and the optimal sequence.",
+                "description": "Plan implementation order for requirements based on their dependency graph. Shows which requirements are ready, which are blocked, the optimal sequence, and parallelizable waves. Ready items are ordered by priority, then by how many other items they unblock. Requirements satisfied by an implementation but not yet verified are reported as in progress. Requirements with an estimate (points or days) also get a critical path, remaining effort per category and a schedule by earliest start. Fails with the cycle path if depends_on edges form a loop. Use --all to plan the whole unresolved backlog.",
                 "parameters": [
-                    param("requirements", "string[]", true, "Requirement IDs to plan")
+                    param("requirements", "string[]", false, "Requirement IDs to plan (required unless --all)"),
+                    param("--all", "bool", false, "Plan every unresolved requirement"),
+                    param("--category", "string", false, "With --all: only requirements in this category"),
+                    param("--tag", "string", false, "With --all: only requirements with this tag"),
+                    param("--priority", "string", false, "With --all: only requirements with this priority (P0, P1, P2)"),
+                    param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
                 ],
-                "output_schema_hint": "{ ready[], blocked[], sequence[] }",
+                "output_schema_hint": "{ ready[], blocked[], verified[], in_progress[], waves: [[id]], sequence[], schedule[], critical_path: { items[], effort }, effort_by_category: { category: effort }, unestimated[], items: [{ id, title, priority?, category?, estimate?, resolution?, state: not_started|in_progress|verified, implementations[], depends_on[], blocked_by[], order, wave?, unblocks, earliest_start? }] }",
                 "examples": [
-                    {"command": "lattice plan REQ-CLI-001 REQ-CLI-002", "explanation": "Determine the implementation order for two requirements based on dependencies"}
+                    {"command": "lattice plan REQ-CLI-001 REQ-CLI-002", "explanation": "Determine the implementation order for two requirements based on dependencies"},
+                    {"command": "lattice plan --all --priority P0", "explanation": "Plan every unresolved P0 requirement"},
+                    {"command": "lattice plan --all --category API --format json", "explanation": "Waves of API work that multiple agents can pick up in parallel"}
                 ],
                 "related_commands": ["list", "get", "summary"]
             },
             {
                 "name": "export",
-                "description": "Export the lattice as a narrative document, JSON data, HTML page, or GitHub Pages site. Use to share lattice state with stakeholders or publish documentation.",
+                "description": "Export the lattice as a narrative document, JSON data, HTML page, GitHub Pages site, or a graph diagram (DOT, Mermaid, GraphML, Cytoscape.js). Graph formats color nodes by type, style edges by type, highlight drifted and contested nodes, and can be limited to the neighbourhood of one node. Use to share lattice state with stakeholders or embed diagrams in PRs and docs.",
                 "parameters": [
-                    param("--format", "string", false, "Export format: narrative, json, html, pages (default: narrative)"),
+                    param("--format", "string", false, "Export format: narrative, json, html, pages, dot, mermaid, graphml, cytoscape (default: narrative)"),
                     param("--audience", "string", false, "Target audience: investor, contributor, overview (default: overview)"),
                     param("--title", "string", false, "Document title (default: Lattice)"),
                     param("--include-internal", "bool", false, "Include nodes marked as internal"),
-                    param("--output", "string", false, "Output directory for HTML/pages export")
+                    param("--output", "string", false, "Output directory for HTML/pages export, or output file for graph formats (default: stdout)"),
+                    param("--root", "string", false, "Graph formats: only export nodes connected to this node ID"),
+                    param("--depth", "integer", false, "Graph formats with --root: only follow this many edges from the root")
                 ],
                 "examples": [
                     {"command": "lattice export", "explanation": "Generate a narrative overview of the lattice for general audiences"},
                     {"command": "lattice export --format json", "explanation": "Export full lattice data as JSON"},
-                    {"command": "lattice export --format pages --output _site", "explanation": "Generate a GitHub Pages site with interactive lattice viewer"}
+                    {"command": "lattice export --format pages --output _site", "explanation": "Generate a GitHub Pages site with interactive lattice viewer"},
+                    {"command": "lattice export --format mermaid --root REQ-CORE-001 --depth 2", "explanation": "Mermaid diagram of a requirement's neighbourhood to paste into a PR"},
+                    {"command": "lattice export --format dot -o lattice.dot", "explanation": "Graphviz file of the whole lattice; render with dot -Tsvg"}
                 ],
                 "related_commands": ["summary", "list"]
             },
@@ -1789,6 +2450,18 @@ fn build_command_catalog() -> serde_json::Value {
                 ],
                 "related_commands": []
             },
+            {
+                "name": "index rebuild",
+                "description": "Rebuild the cached node ID index from scratch. The index is maintained automatically; use this if lookups ever resolve to the wrong file.",
+                "parameters": [
+                    param_s("--format", "-f", "string", false, "Output format: text, json (default: text)")
+                ],
+                "output_schema_hint": "{ success, total, cache }",
+                "examples": [
+                    {"command": "lattice index rebuild", "explanation": "Re-scan every node file and rewrite the ID index"}
+                ],
+                "related_commands": ["get", "edit"]
+            },
             {
                 "name": "help",
                 "description": "Show available commands, or a specific topic (concepts, workflows). Use --json for the full machine-readable catalog (this output).",
@@ -1816,6 +2489,10 @@ fn build_command_catalog() -> serde_json::Value {
             "--format json": "Available on all read and write commands for structured output",
             "--json": "Output machine-readable command catalog (top-level flag)",
             "--json --compact": "Output compact command catalog — signatures only, no examples/descriptions",
+            "--at <ref>": format!(
+                "Read the lattice as of a git ref without checking it out ({}; not drift --explain or export --format pages)",
+                AT_REF_COMMANDS.join(", ")
+            ),
             "--help": "Show help for any command",
             "--version": "Show version"
         }
@@ -1828,6 +2505,8 @@ fn command_to_name(cmd: &Commands) -> &'static str {
         Commands::Add { .. } => "add",
         Commands::Remove { .. } => "remove",
         Commands::Replace { .. } => "replace",
+        Commands::Rename { .. } => "rename",
+        Commands::Merge { .. } => "merge",
         Commands::List { .. } => "list",
         Commands::Resolve { .. } => "resolve",
         Commands::Edit { .. } => "edit",
@@ -1840,6 +2519,7 @@ fn command_to_name(cmd: &Commands) -> &'static str {
         Commands::Freshness { .. } => "freshness",
         Commands::Assess { .. } => "assess",
         Commands::Health { .. } => "health",
+        Commands::Coverage { .. } => "coverage",
         Commands::Verify { .. } => "verify",
         Commands::Refine { .. } => "refine",
         Commands::Search { .. } => "search",
@@ -1849,6 +2529,12 @@ fn command_to_name(cmd: &Commands) -> &'static str {
         Commands::Push { .. } => "push",
         Commands::Migrate => "migrate",
         Commands::Diff { .. } => "diff",
+        Commands::Log { .. } => "log",
+        Commands::Query { .. } => "query",
+        Commands::Impact { .. } => "impact",
+        Commands::Trace { .. } => "trace",
+        Commands::Hotspots { .. } => "hotspots",
+        Commands::Index { .. } => "index",
         Commands::Help { .. } => "help",
     }
 }
@@ -1899,20 +2585,29 @@ fn print_grouped_help() {
                 "get",
                 "list",
                 "search",
+                "query",
                 "edit",
                 "resolve",
                 "verify",
                 "refine",
+                "remove node",
                 "remove edge",
                 "replace edge",
+                "rename",
+                "merge",
+            ],
+        ),
+        (
+            "ANALYSIS:",
+            &[
+                "summary", "diff", "log", "impact", "trace", "hotspots", "plan", "export",
             ],
         ),
-        ("ANALYSIS:", &["summary", "diff", "plan", "export"]),
         (
             "AUTOMATED CHECKS:",
-            &["health", "drift", "freshness", "assess", "lint"],
+            &["health", "coverage", "drift", "freshness", "assess", "lint"],
         ),
-        ("SETUP:", &["init", "update", "help"]),
+        ("SETUP:", &["init", "update", "index rebuild", "help"]),
     ];
 
     if let Some(commands) = catalog["commands"].as_array() {
@@ -2050,6 +2745,30 @@ fn main() {
         }
     }
 
+    if let Some(git_ref) = &cli.at {
+        if !AT_REF_COMMANDS.contains(&command_name) {
+            eprintln!(
+                "{}",
+                format!(
+                    "Error: --at is not supported by '{}' (supported: {})",
+                    command_name,
+                    AT_REF_COMMANDS.join(", ")
+                )
+                .red()
+            );
+            process::exit(1);
+        }
+        match lattice_at_ref(&get_lattice_root(), git_ref) {
+            Ok(root) => {
+                let _ = AT_REF_ROOT.set(root);
+            }
+            Err(e) => {
+                eprintln!("{}", format!("Error: {}", e).red());
+                process::exit(1);
+            }
+        }
+    }
+
     run_command(command);
 
     // Passive update check after command output is complete
@@ -2129,6 +2848,7 @@ fn run_command(command: Commands) {
                 title,
                 body,
                 priority,
+                estimate,
                 category,
                 tags,
                 derives_from,
@@ -2149,6 +2869,7 @@ fn run_command(command: Commands) {
                     title,
                     body,
                     priority,
+                    estimate,
                     category,
                     tags: split_csv(tags),
                     derives_from: split_csv(derives_from),
@@ -2340,6 +3061,138 @@ fn run_command(command: Commands) {
         },
 
         Commands::Remove { remove_command } => match remove_command {
+            RemoveCommands::Node {
+                id,
+                hard,
+                remove_edges,
+                retarget,
+                dry_run,
+                format,
+            } => {
+                let root = get_lattice_root();
+
+                let incoming = match (remove_edges, retarget) {
+                    (_, Some(new_id)) => IncomingEdgeAction::Retarget(new_id),
+                    (true, None) => IncomingEdgeAction::Remove,
+                    (false, None) => IncomingEdgeAction::Keep,
+                };
+                let options = RemoveNodeOptions {
+                    node_id: id.clone(),
+                    archive: !hard,
+                    incoming: incoming.clone(),
+                    dry_run,
+                };
+
+                let edges = match find_incoming_edges(&root, &id) {
+                    Ok(edges) => edges,
+                    Err(e) => emit_error(&format, "remove_node_error", &e.to_string()),
+                };
+                match remove_node(&root, options, edges) {
+                    Ok(result) => {
+                        let (edge_action, retarget_to) = match &incoming {
+                            IncomingEdgeAction::Keep => ("keep", None),
+                            IncomingEdgeAction::Remove => ("remove", None),
+                            IncomingEdgeAction::Retarget(t) => ("retarget", Some(t.as_str())),
+                        };
+                        if is_json(&format) {
+                            let incoming_json: Vec<_> = result
+                                .incoming
+                                .iter()
+                                .map(|e| {
+                                    json!({
+                                        "from": e.from_id,
+                                        "edge_type": e.edge_type,
+                                        "version": e.version,
+                                    })
+                                })
+                                .collect();
+                            println!(
+                                "{}",
+                                serde_json::to_string_pretty(&json!({
+                                    "success": true,
+                                    "dry_run": dry_run,
+                                    "id": id,
+                                    "action": if hard { "delete" } else { "archive" },
+                                    "file": result.path.display().to_string(),
+                                    "archived_to": result.archived_to.as_ref().map(|p| p.display().to_string()),
+                                    "incoming": incoming_json,
+                                    "incoming_action": edge_action,
+                                    "retarget_to": retarget_to,
+                                    "edges_updated": result.edges_updated,
+                                }))
+                                .unwrap()
+                            );
+                        } else {
+                            let prefix = if dry_run { "Would " } else { "" };
+                            match &result.archived_to {
+                                Some(dest) => println!(
+                                    "{}",
+                                    format!(
+                                        "{}{} {} -> {}",
+                                        prefix,
+                                        if dry_run { "archive" } else { "Archived" },
+                                        id,
+                                        dest.display()
+                                    )
+                                    .green()
+                                ),
+                                None => println!(
+                                    "{}",
+                                    format!(
+                                        "{}{} {}",
+                                        prefix,
+                                        if dry_run { "delete" } else { "Deleted" },
+                                        id
+                                    )
+                                    .green()
+                                ),
+                            }
+                            println!("{}", format!("File: {}", result.path.display()).dimmed());
+
+                            if result.incoming.is_empty() {
+                                println!("{}", "No incoming edges".dimmed());
+                            } else {
+                                println!();
+                                println!(
+                                    "{}",
+                                    format!("Incoming edges ({}):", result.incoming.len()).bold()
+                                );
+                                let outcome = match (&incoming, dry_run) {
+                                    (IncomingEdgeAction::Keep, _) => "kept".to_string(),
+                                    (IncomingEdgeAction::Remove, true) => {
+                                        "would remove".to_string()
+                                    }
+                                    (IncomingEdgeAction::Remove, false) => "removed".to_string(),
+                                    (IncomingEdgeAction::Retarget(t), true) => {
+                                        format!("would retarget to {}", t)
+                                    }
+                                    (IncomingEdgeAction::Retarget(t), false) => {
+                                        format!("retargeted to {}", t)
+                                    }
+                                };
+                                for edge in &result.incoming {
+                                    println!(
+                                        "  {} --[{}]--> {} {}",
+                                        edge.from_id.cyan(),
+                                        edge.edge_type,
+                                        id,
+                                        format!("({})", outcome).dimmed()
+                                    );
+                                }
+                                if incoming == IncomingEdgeAction::Keep {
+                                    println!();
+                                    println!(
+                                        "{}",
+                                        "These edges will dangle. Use --remove-edges or --retarget <ID> to clean them up."
+                                            .yellow()
+                                    );
+                                }
+                            }
+                        }
+                    }
+                    Err(e) => emit_error(&format, "remove_node_error", &e.to_string()),
+                }
+            }
             RemoveCommands::Edge {
                 from,
                 edge_type,
@@ -2433,6 +3286,115 @@ fn run_command(command: Commands) {
             }
         },
 
+        Commands::Rename {
+            old_id,
+            new_id,
+            format,
+        } => {
+            let root = get_lattice_root();
+
+            let options = RenameNodeOptions {
+                old_id: old_id.clone(),
+                new_id: new_id.clone(),
+            };
+
+            match rename_node(&root, options) {
+                Ok(result) => {
+                    if is_json(&format) {
+                        let updated: Vec<_> = result
+                            .updated_nodes
+                            .iter()
+                            .map(|(id, edges)| json!({ "id": id, "edges": edges }))
+                            .collect();
+                        println!(
+                            "{}",
+                            serde_json::to_string_pretty(&json!({
+                                "success": true,
+                                "old_id": old_id,
+                                "new_id": new_id,
+                                "old_file": result.old_path.display().to_string(),
+                                "new_file": result.new_path.display().to_string(),
+                                "updated_nodes": updated,
+                            }))
+                            .unwrap()
+                        );
+                    } else {
+                        println!("{}", format!("Renamed {} -> {}", old_id, new_id).green());
+                        println!(
+                            "{}",
+                            format!("File: {}", result.new_path.display()).dimmed()
+                        );
+                        if result.updated_nodes.is_empty() {
+                            println!("No incoming edges to rewrite.");
+                        } else {
+                            println!("Rewrote edges on {} node(s):", result.updated_nodes.len());
+                            for (id, edges) in &result.updated_nodes {
+                                println!("  {} ({} edge(s))", id, edges);
+                            }
+                        }
+                    }
+                }
+                Err(e) => emit_error(&format, "rename_error", &e.to_string()),
+            }
+        }
+
+        Commands::Merge {
+            keep_id,
+            drop_id,
+            format,
+        } => {
+            let root = get_lattice_root();
+
+            let options = MergeNodesOptions {
+                keep_id: keep_id.clone(),
+                drop_id: drop_id.clone(),
+            };
+
+            match merge_nodes(&root, options) {
+                Ok(result) => {
+                    if is_json(&format) {
+                        let updated: Vec<_> = result
+                            .updated_nodes
+                            .iter()
+                            .map(|(id, edges)| json!({ "id": id, "edges": edges }))
+                            .collect();
+                        println!(
+                            "{}",
+                            serde_json::to_string_pretty(&json!({
+                                "success": true,
+                                "keep_id": keep_id,
+                                "drop_id": drop_id,
+                                "version": result.new_version,
+                                "tags_added": result.tags_added,
+                                "edges_added": result.edges_added,
+                                "acceptance_added": result.acceptance_added,
+                                "updated_nodes": updated,
+                            }))
+                            .unwrap()
+                        );
+                    } else {
+                        println!(
+                            "{}",
+                            format!(
+                                "Merged {} into {} (now v{})",
+                                drop_id, keep_id, result.new_version
+                            )
+                            .green()
+                        );
+                        println!(
+                            "  Added {} tag(s), {} edge(s), {} acceptance test(s)",
+                            result.tags_added, result.edges_added, result.acceptance_added
+                        );
+                        for (id, edges) in &result.updated_nodes {
+                            println!("  Retargeted {} edge(s) on {}", edges, id);
+                        }
+                        println!("{}", format!("{} marked superseded", drop_id).dimmed());
+                    }
+                }
+                Err(e) => emit_error(&format, "merge_error", &e.to_string()),
+            }
+        }
+
         Commands::List {
             node_type,
             status,
@@ -2614,11 +3576,13 @@ fn run_command(command: Commands) {
             body,
             status,
             priority,
+            estimate,
             confidence,
             tags,
             category,
             files,
             test_command,
+            bump,
             format,
         } => {
             let root = get_lattice_root();
@@ -2626,6 +3590,11 @@ fn run_command(command: Commands) {
             let files = split_csv(files);
             let status = status.map(|s| parse_status(&s));
             let priority = priority.map(|p| parse_priority(&p));
+            let bump = bump.map(|b| {
+                b.parse::<BumpLevel>().unwrap_or_else(|e| {
+                    emit_error(&format, "invalid_bump", &e);
+                })
+            });
 
             let options = EditNodeOptions {
                 node_id: id.clone(),
@@ -2633,27 +3602,31 @@ fn run_command(command: Commands) {
                 body,
                 status,
                 priority,
+                estimate,
                 confidence,
                 tags,
                 category,
                 files,
                 test_command,
+                bump,
             };
 
             match edit_node(&root, options) {
                 Ok(path) => {
+                    let version = load_node(&path).map(|n| n.version).unwrap_or_default();
                     if is_json(&format) {
                         println!(
                             "{}",
                             serde_json::to_string_pretty(&json!({
                                 "success": true,
                                 "id": id,
+                                "version": version,
                                 "file": path.display().to_string(),
                             }))
                             .unwrap()
                         );
                     } else {
-                        println!("{}", format!("Updated {}", id).green());
+                        println!("{}", format!("Updated {} (v{})", id, version).green());
                         println!("{}", format!("File: {}", path.display()).dimmed());
                     }
                 }
@@ -2661,30 +3634,113 @@ fn run_command(command: Commands) {
             }
         }
 
-        Commands::Plan { requirements } => {
+        Commands::Plan {
+            requirements,
+            all,
+            category,
+            tag,
+            priority,
+            format,
+        } => {
             let root = get_lattice_root();
 
+            if !all && (category.is_some() || tag.is_some() || priority.is_some()) {
+                emit_error(
+                    &format,
+                    "invalid_arguments",
+                    "--category, --tag and --priority filter --all; list requirement IDs or add --all",
+                );
+            }
+            let priority = priority.map(|p| {
+                p.parse::<Priority>().unwrap_or_else(|e| {
+                    emit_error(&format, "invalid_priority", &e);
+                })
+            });
+
             match build_node_index(&root) {
                 Ok(index) => {
-                    let plan = generate_plan(&requirements, &index);
-                    print_plan(&plan, &index);
-                }
-                Err(e) => {
-                    eprintln!("{}", format!("Error: {}", e).red());
-                    process::exit(1);
+                    let requirements = if all {
+                        let filter = PlanFilter {
+                            category,
+                            tag,
+                            priority,
+                        };
+                        unresolved_requirements(&index, &filter)
+                    } else {
+                        requirements
+                    };
+                    let plan = generate_plan(&requirements, &index).unwrap_or_else(|cycle| {
+                        emit_error(&format, "dependency_cycle", &cycle.to_string());
+                    });
+                    if is_json(&format) {
+                        let items: Vec<_> = plan
+                            .items
+                            .iter()
+                            .map(|i| {
+                                json!({
+                                    "id": i.id,
+                                    "title": i.title,
+                                    "priority": i.priority.as_ref().map(|p| format!("{:?}", p)),
+                                    "category": i.category,
+                                    "estimate": i.estimate,
+                                    "resolution": i.resolution.as_ref().map(|r| format!("{:?}", r).to_lowercase()),
+                                    "state": i.state.as_str(),
+                                    "implementations": i.implementations,
+                                    "depends_on": i.depends_on,
+                                    "blocked_by": i.blocked_by,
+                                    "order": i.order + 1,
+                                    "wave": i.wave.map(|w| w + 1),
+                                    "unblocks": i.unblocks,
+                                    "earliest_start": i.earliest_start,
+                                })
+                            })
+                            .collect();
+                        println!(
+                            "{}",
+                            serde_json::to_string_pretty(&json!({
+                                "ready": plan.ready,
+                                "blocked": plan.blocked,
+                                "verified": plan.verified,
+                                "in_progress": plan.in_progress,
+                                "waves": plan.waves,
+                                "sequence": plan.items.iter().map(|i| &i.id).collect::<Vec<_>>(),
+                                "schedule": plan.schedule,
+                                "critical_path": {
+                                    "items": plan.critical_path,
+                                    "effort": plan.critical_path_effort,
+                                },
+                                "effort_by_category": plan.effort_by_category,
+                                "unestimated": plan.unestimated,
+                                "items": items,
+                            }))
+                            .unwrap()
+                        );
+                    } else {
+                        print_plan(&plan, &index);
+                    }
                 }
+                Err(e) => emit_error(&format, "load_error", &e.to_string()),
             }
         }
 
         Commands::Drift {
             check,
             acknowledge,
+            explain,
+            transitive,
             format,
         } => {
             let root = get_lattice_root();
 
             // Handle --acknowledge
             if let Some(node_id) = acknowledge {
+                if AT_REF_ROOT.get().is_some() {
+                    emit_error(
+                        &format,
+                        "read_only_ref",
+                        "--acknowledge cannot be combined with --at: past revisions are read-only",
+                    );
+                }
                 match lattice::storage::acknowledge_drift(&root, &node_id) {
                     Ok(path) => {
                         if is_json(&format) {
@@ -2713,24 +3769,57 @@ fn run_command(command: Commands) {
                 return;
             }
 
-            match find_drift(&root) {
-                Ok(reports) => {
-                    if is_json(&format) {
-                        let json_reports: Vec<_> = reports
-                            .iter()
-                            .map(|r| {
-                                let items: Vec<_> = r
-                                    .drift_items
-                                    .iter()
-                                    .map(|i| {
-                                        json!({
-                                            "target_id": i.target_id,
-                                            "bound_version": i.bound_version,
-                                            "current_version": i.current_version,
-                                            "severity": format!("{:?}", i.severity).to_lowercase(),
-                                        })
-                                    })
-                                    .collect();
+            if explain && AT_REF_ROOT.get().is_some() {
+                emit_error(
+                    &format,
+                    "unsupported_with_ref",
+                    "--explain cannot be combined with --at: drift history is read from the working repository",
+                );
+            }
+
+            match build_node_index(&root) {
+                Ok(index) => {
+                    let mut reports = drift_reports(&index);
+                    if explain {
+                        explain_drift(&root, &mut reports);
+                    }
+                    let indirect = if transitive {
+                        find_transitive_drift(&index, &reports)
+                    } else {
+                        Vec::new()
+                    };
+                    if is_sarif(&format) {
+                        let mut findings = sarif::drift_findings(&root, &reports);
+                        findings.extend(sarif::indirect_drift_findings(&root, &indirect));
+                        print_sarif(&sarif::sarif_log(
+                            &root,
+                            &sarif::drift_rules(),
+                            &findings,
+                            None,
+                        ));
+                        if check && !reports.is_empty() {
+                            process::exit(2);
+                        }
+                    } else if is_json(&format) {
+                        let json_reports: Vec<_> = reports
+                            .iter()
+                            .map(|r| {
+                                let items: Vec<_> = r
+                                    .drift_items
+                                    .iter()
+                                    .map(|i| {
+                                        let mut item = json!({
+                                            "target_id": i.target_id,
+                                            "bound_version": i.bound_version,
+                                            "current_version": i.current_version,
+                                            "severity": format!("{:?}", i.severity).to_lowercase(),
+                                        });
+                                        if explain {
+                                            item["explanation"] = json!(i.explanation);
+                                        }
+                                        item
+                                    })
+                                    .collect();
                                 json!({
                                     "node_id": r.node_id,
                                     "node_type": r.node_type,
@@ -2738,15 +3827,27 @@ fn run_command(command: Commands) {
                                 })
                             })
                             .collect();
-                        println!(
-                            "{}",
-                            serde_json::to_string_pretty(&json!({
-                                "has_drift": !reports.is_empty(),
-                                "count": reports.len(),
-                                "reports": json_reports,
-                            }))
-                            .unwrap()
-                        );
+                        let mut output = json!({
+                            "has_drift": !reports.is_empty(),
+                            "count": reports.len(),
+                            "reports": json_reports,
+                        });
+                        if transitive {
+                            output["indirect_count"] = json!(indirect.len());
+                            output["indirect"] = indirect
+                                .iter()
+                                .map(|d| {
+                                    json!({
+                                        "node_id": d.node_id,
+                                        "node_type": d.node_type,
+                                        "source_id": d.source_id,
+                                        "path": d.path,
+                                        "hops": d.hops,
+                                    })
+                                })
+                                .collect();
+                        }
+                        println!("{}", serde_json::to_string_pretty(&output).unwrap());
                         if check && !reports.is_empty() {
                             process::exit(2);
                         }
@@ -2779,6 +3880,25 @@ fn run_command(command: Commands) {
                                     item.current_version,
                                     severity_str
                                 );
+                                if explain {
+                                    print_drift_explanation(item);
+                                }
+                            }
+                            println!();
+                        }
+
+                        if !indirect.is_empty() {
+                            println!(
+                                "{}",
+                                format!("INDIRECT DRIFT ({} nodes):\n", indirect.len()).yellow()
+                            );
+                            for d in &indirect {
+                                println!(
+                                    "{} {}",
+                                    format!("{} ({})", d.node_id, d.node_type).cyan(),
+                                    format!("{} hops from {}", d.hops, d.source_id).dimmed()
+                                );
+                                println!("  {}", d.path.join(" -> "));
                             }
                             println!();
                         }
@@ -2795,13 +3915,16 @@ fn run_command(command: Commands) {
         Commands::Get { id, format } => {
             let root = get_lattice_root();
 
-            match build_node_index(&root) {
-                Ok(index) => {
-                    if let Some(node) = index.get(&id) {
+            match build_indexes(&root) {
+                Ok((index, reverse)) => {
+                    if let Some(node) = lookup_node(&index, &id) {
+                        let incoming = reverse.get(&node.id).map_or(&[][..], |v| v.as_slice());
                         if is_json(&format) {
+                            let mut value = serde_json::to_value(node).unwrap_or_default();
+                            value["incoming"] = json!(incoming);
                             println!(
                                 "{}",
-                                serde_json::to_string_pretty(&node)
+                                serde_json::to_string_pretty(&value)
                                     .unwrap_or_else(|_| "{}".to_string())
                             );
                         } else {
@@ -2834,6 +3957,31 @@ fn run_command(command: Commands) {
                                 println!("{}", summary.dimmed());
                             }
 
+                            // Show who points at this node, grouped by edge type
+                            if !incoming.is_empty() {
+                                let mut by_type: std::collections::BTreeMap<&str, Vec<String>> =
+                                    std::collections::BTreeMap::new();
+                                for r in incoming {
+                                    let version = r.version.as_deref().map(|v| format!(" @{}", v));
+                                    by_type
+                                        .entry(r.edge_type.as_str())
+                                        .or_default()
+                                        .push(format!(
+                                            "{}{}",
+                                            r.from_id,
+                                            version.unwrap_or_default()
+                                        ));
+                                }
+                                println!("{}", format!("Incoming ({}):", incoming.len()).dimmed());
+                                for (edge_type, sources) in by_type {
+                                    println!(
+                                        "{}",
+                                        format!("  {} <- {}", edge_type, sources.join(", "))
+                                            .dimmed()
+                                    );
+                                }
+                            }
+
                             println!(
                                 "{}",
                                 format!("Status: {:?} | Version: {}", node.status, node.version)
@@ -2859,6 +4007,8 @@ fn run_command(command: Commands) {
             title,
             include_internal,
             output,
+            root: graph_root,
+            depth,
         } => {
             let root = get_lattice_root();
 
@@ -2888,6 +4038,13 @@ fn run_command(command: Commands) {
             }
 
             if format == "pages" {
+                if AT_REF_ROOT.get().is_some() {
+                    emit_error(
+                        &format,
+                        "unsupported_with_ref",
+                        "--format pages cannot be combined with --at: publish from the working tree",
+                    );
+                }
                 let output_dir = output.unwrap_or_else(|| "_site".to_string());
 
                 // Derive GitHub Pages URL from git remote
@@ -3044,6 +4201,43 @@ fn run_command(command: Commands) {
                 return;
             }
 
+            if let Ok(graph_format) = format.parse::<GraphFormat>() {
+                let data = LatticeData {
+                    sources: load_nodes_by_type(&root, "sources").unwrap_or_default(),
+                    theses: load_nodes_by_type(&root, "theses").unwrap_or_default(),
+                    requirements: load_nodes_by_type(&root, "requirements").unwrap_or_default(),
+                    implementations: load_nodes_by_type(&root, "implementations")
+                        .unwrap_or_default(),
+                    messages: load_nodes_by_type(&root, "messages").unwrap_or_default(),
+                };
+
+                let options = GraphExportOptions {
+                    format: graph_format,
+                    title,
+                    include_internal,
+                    root: graph_root,
+                    depth,
+                };
+
+                match export_graph(&data, &options) {
+                    Ok(graph) => match output {
+                        Some(path) => {
+                            if let Err(e) = std::fs::write(&path, &graph) {
+                                eprintln!("{}", format!("Error writing {}: {}", path, e).red());
+                                process::exit(1);
+                            }
+                            eprintln!("{}", format!("Graph exported to {}", path).green());
+                        }
+                        None => print!("{}", graph),
+                    },
+                    Err(e) => {
+                        eprintln!("{}", format!("Error: {}", e).red());
+                        process::exit(1);
+                    }
+                }
+                return;
+            }
+
             eprintln!("{}", format!("Unknown format: {}", format).red());
             process::exit(1);
         }
@@ -3324,6 +4518,16 @@ fn run_command(command: Commands) {
 
             let change_pressure = contested_count + drift_count;
 
+            // Nodes downstream of drift are tracked separately from change pressure
+            let indirect_drift_count = {
+                let indirect = find_transitive_drift(&index, &drift_reports(&index));
+                indirect
+                    .iter()
+                    .map(|d| d.node_id.as_str())
+                    .collect::<std::collections::HashSet<_>>()
+                    .len()
+            };
+
             // 3. Code impact: files changed since last .lattice/ commit that are bound in implementations
             let bound_files: std::collections::HashSet<String> = all_nodes
                 .iter()
@@ -3374,12 +4578,14 @@ fn run_command(command: Commands) {
                     (0, 0, Vec::new())
                 };
 
-            // 4. Lint (strict mode only)
-            let lint_issues = if strict {
-                lint_lattice(&root).issues.len()
-            } else {
-                0
-            };
+            // 4. Lint (strict mode only), including team policies
+            let lint_report = strict.then(|| lint_lattice(&root));
+            let lint_issues = lint_report.as_ref().map_or(0, |r| r.issues.len());
+            let mut lint_by_rule: std::collections::BTreeMap<&str, usize> =
+                std::collections::BTreeMap::new();
+            for issue in lint_report.iter().flat_map(|r| &r.issues) {
+                *lint_by_rule.entry(issue.rule.as_str()).or_default() += 1;
+            }
 
             // Read configurable threshold
             let config = load_config(&root);
@@ -3404,7 +4610,76 @@ fn run_command(command: Commands) {
                 "PASS"
             };
 
-            if is_json(&format) {
+            if is_sarif(&format) {
+                let mut rules = sarif::health_rules();
+                rules.extend(sarif::drift_rules());
+                let mut findings = Vec::new();
+                if freshness_gap_hours > threshold {
+                    findings.push(sarif::SarifFinding {
+                        rule_id: sarif::LATTICE_STALE_RULE_ID.to_string(),
+                        level: sarif::SarifLevel::Warning,
+                        message: format!(
+                            "Lattice is {}h behind code changes (threshold {}h)",
+                            freshness_gap_hours, threshold
+                        ),
+                        file: root.join(LATTICE_DIR).join("config.yaml"),
+                        anchor: None,
+                    });
+                }
+                for file in &affected_file_names {
+                    let bound_by: Vec<&str> = all_nodes
+                        .iter()
+                        .filter(|n| match &n.meta {
+                            Some(NodeMeta::Implementation(meta)) => {
+                                meta.files.iter().flatten().any(|f| &f.path == file)
+                            }
+                            _ => false,
+                        })
+                        .map(|n| n.id.as_str())
+                        .collect();
+                    findings.push(sarif::SarifFinding {
+                        rule_id: sarif::BOUND_FILE_CHANGED_RULE_ID.to_string(),
+                        level: if strict {
+                            sarif::SarifLevel::Error
+                        } else {
+                            sarif::SarifLevel::Warning
+                        },
+                        message: format!(
+                            "{} changed since the last .lattice/ update (bound by {})",
+                            file,
+                            bound_by.join(", ")
+                        ),
+                        file: root.join(file),
+                        anchor: None,
+                    });
+                }
+                let mut contested: Vec<&str> = all_nodes
+                    .iter()
+                    .filter(|n| n.node_type == NodeType::Thesis && n.status == Status::Contested)
+                    .map(|n| n.id.as_str())
+                    .collect();
+                contested.sort();
+                for id in contested {
+                    findings.push(sarif::SarifFinding {
+                        rule_id: sarif::CONTESTED_THESIS_RULE_ID.to_string(),
+                        level: sarif::SarifLevel::Warning,
+                        message: format!("{} is contested", id),
+                        file: find_node_path(&root, id).unwrap_or_else(|_| root.join(LATTICE_DIR)),
+                        anchor: Some(lattice::lint::IssueAnchor::Field("status".to_string())),
+                    });
+                }
+                findings.extend(sarif::drift_findings(&root, &drift_reports(&index)));
+                if let Some(report) = &lint_report {
+                    rules.extend(sarif::lint_rules(&config.lint));
+                    findings.extend(sarif::lint_findings(&root, report));
+                }
+                print_sarif(&sarif::sarif_log(
+                    &root,
+                    &rules,
+                    &findings,
+                    Some(json!({ "verdict": verdict, "strict": strict })),
+                ));
+            } else if is_json(&format) {
                 let mut result = json!({
                     "verdict": verdict,
                     "freshness": {
@@ -3415,6 +4690,9 @@ fn run_command(command: Commands) {
                         "drift_items": drift_count,
                         "total": change_pressure,
                     },
+                    "indirect_drift": {
+                        "nodes": indirect_drift_count,
+                    },
                     "code_impact": {
                         "total_files_changed": total_files_changed,
                         "tracked_files_changed": tracked_files_changed,
@@ -3424,7 +4702,11 @@ fn run_command(command: Commands) {
                     "lattice_staged": lattice_staged,
                 });
                 if strict {
-                    result["lint"] = json!({ "issues": lint_issues });
+                    result["lint"] = json!({
+                        "issues": lint_issues,
+                        "errors": lint_report.as_ref().map_or(0, |r| r.errors().len()),
+                        "by_rule": lint_by_rule,
+                    });
                 }
                 println!("{}", serde_json::to_string_pretty(&result).unwrap());
             } else {
@@ -3451,6 +4733,12 @@ fn run_command(command: Commands) {
                 println!("  {}", "Change Pressure:".bold());
                 println!("    Contested theses: {}", contested_count);
                 println!("    Drift items: {}", drift_count);
+                if indirect_drift_count > 0 {
+                    println!(
+                        "    {}",
+                        format!("Indirectly drifted nodes: {}", indirect_drift_count).dimmed()
+                    );
+                }
                 println!();
 
                 println!("  {}", "Code Impact:".bold());
@@ -3481,6 +4769,9 @@ fn run_command(command: Commands) {
                     println!("  {}", "Lint:".bold());
                     if lint_issues > 0 {
                         println!("    {}", format!("{} issues found", lint_issues).red());
+                        for (rule, count) in &lint_by_rule {
+                            println!("      {}: {}", rule, count);
+                        }
                     } else {
                         println!("    {}", "No issues".green());
                     }
@@ -3488,7 +4779,7 @@ fn run_command(command: Commands) {
             }
 
             // Print remediation hint on FAIL (text mode only)
-            if verdict == "FAIL" && !is_json(&format) {
+            if verdict == "FAIL" && !is_json(&format) && !is_sarif(&format) {
                 println!();
                 if tracked_files_changed > 0 && !lattice_staged {
                     println!(
@@ -3515,21 +4806,53 @@ fn run_command(command: Commands) {
         Commands::Lint {
             fix,
             strict,
+            list_rules,
+            changed,
+            since,
             format,
         } => {
             let root = get_lattice_root();
-            let report = lint_lattice(&root);
+            if list_rules {
+                print_lint_rules(&load_config(&root).lint, &format);
+                return;
+            }
+            let mut report = lint_lattice(&root);
+            if changed {
+                match changed_node_ids(&root, since.as_deref()) {
+                    Ok(ids) => report.retain_nodes(&ids),
+                    Err(e) => emit_error(&format, "diff_error", &e.to_string()),
+                }
+            }
 
-            if is_json(&format) {
+            if is_sarif(&format) {
+                let rules = sarif::lint_rules(&load_config(&root).lint);
+                print_sarif(&sarif::sarif_log(
+                    &root,
+                    &rules,
+                    &sarif::lint_findings(&root, &report),
+                    None,
+                ));
+                if fix && !report.fixable().is_empty() {
+                    fix_issues(&root, &report);
+                }
+
+                if report.has_errors() {
+                    process::exit(1);
+                } else if strict && !report.issues.is_empty() {
+                    process::exit(2);
+                }
+            } else if is_json(&format) {
                 let json_issues: Vec<_> = report
                     .issues
                     .iter()
                     .map(|i| {
                         json!({
+                            "rule": i.rule,
                             "file": i.file.display().to_string(),
                             "node_id": i.node_id,
                             "severity": format!("{}", i.severity),
                             "message": i.message,
+                            "suggestion": i.suggestion,
                             "fixable": i.fixable == lattice::lint::Fixable::Yes,
                         })
                     })
@@ -3572,6 +4895,9 @@ fn run_command(command: Commands) {
                         LintSeverity::Warning => format!("{}", issue).yellow().to_string(),
                     };
                     println!("{}", colored_msg);
+                    if let Some(suggestion) = &issue.suggestion {
+                        println!("  {}", format!("suggestion: {}", suggestion).dimmed());
+                    }
                 }
                 println!();
 
@@ -4637,6 +5963,613 @@ fn run_command(command: Commands) {
             }
         }
 
+        Commands::Log {
+            id,
+            version,
+            format,
+        } => {
+            let root = get_lattice_root();
+
+            let revisions = node_history(&root, &id).unwrap_or_else(|e| {
+                emit_error(&format, "log_error", &e.to_string());
+            });
+
+            if let Some(version) = version {
+                let Some(revision) = node_at_version(&revisions, &version) else {
+                    emit_error(
+                        &format,
+                        "version_not_found",
+                        &format!("No committed revision of {} has version {}", id, version),
+                    );
+                };
+                if is_json(&format) {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&json!({
+                            "id": id,
+                            "version": version,
+                            "commit": revision.commit,
+                            "node": revision.node,
+                        }))
+                        .unwrap()
+                    );
+                } else {
+                    println!(
+                        "{}",
+                        format!(
+                            "# {} v{} as of {} ({})",
+                            id,
+                            version,
+                            &revision.commit[..std::cmp::min(8, revision.commit.len())],
+                            revision.date
+                        )
+                        .dimmed()
+                    );
+                    print!(
+                        "{}",
+                        serde_yaml::to_string(&revision.node).unwrap_or_default()
+                    );
+                }
+                return;
+            }
+
+            if is_json(&format) {
+                let entries: Vec<_> = revisions
+                    .iter()
+                    .map(|r| {
+                        json!({
+                            "commit": r.commit,
+                            "author": r.author,
+                            "date": r.date,
+                            "subject": r.subject,
+                            "version": r.node.version,
+                            "fields": r.fields,
+                        })
+                    })
+                    .collect();
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&json!({
+                        "id": id,
+                        "revisions": entries,
+                    }))
+                    .unwrap()
+                );
+            } else if revisions.is_empty() {
+                println!("{}", format!("No committed history for {}.", id).yellow());
+            } else {
+                println!(
+                    "{}",
+                    format!("{} history ({} revisions):\n", id, revisions.len()).bold()
+                );
+                for r in &revisions {
+                    let date = r.date.split('T').next().unwrap_or(&r.date);
+                    println!(
+                        "  {} {} {} {}",
+                        format!("v{:<8}", r.node.version).cyan(),
+                        &r.commit[..std::cmp::min(8, r.commit.len())],
+                        date,
+                        r.subject
+                    );
+                    println!("    {}", format!("by {}", r.author).dimmed());
+                    if !r.fields.is_empty() {
+                        println!(
+                            "    {}",
+                            format!("changed: {}", r.fields.join(", ")).dimmed()
+                        );
+                    }
+                }
+            }
+        }
+
+        Commands::Query {
+            query,
+            limit,
+            format,
+        } => {
+            let root = get_lattice_root();
+
+            let parsed = parse_query(&query).unwrap_or_else(|e| {
+                emit_error(&format, "query_error", &e.to_string());
+            });
+            let index = build_node_index(&root).unwrap_or_else(|e| {
+                emit_error(&format, "load_error", &e.to_string());
+            });
+            let mut results = run_query(&parsed, &index);
+            let total = results.len();
+            if let Some(max) = limit {
+                results.truncate(max);
+            }
+            let truncated = results.len() < total;
+
+            if is_json(&format) {
+                let results: Vec<_> = results
+                    .iter()
+                    .map(|n| {
+                        json!({
+                            "id": n.id,
+                            "type": format!("{:?}", n.node_type).to_lowercase(),
+                            "title": n.title,
+                            "version": n.version,
+                            "status": format!("{:?}", n.status).to_lowercase(),
+                            "priority": n.priority.as_ref().map(|p| format!("{:?}", p)),
+                            "resolution": n.resolution.as_ref().map(|r| format!("{:?}", r.status).to_lowercase()),
+                        })
+                    })
+                    .collect();
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&json!({
+                        "query": query,
+                        "count": total,
+                        "returned": results.len(),
+                        "truncated": truncated,
+                        "results": results,
+                    }))
+                    .unwrap()
+                );
+            } else {
+                if truncated {
+                    println!(
+                        "{}",
+                        format!("Found {} nodes (showing {}):", total, results.len()).bold()
+                    );
+                } else {
+                    println!("{}", format!("Found {} nodes:", total).bold());
+                }
+                for n in &results {
+                    let priority_str = n
+                        .priority
+                        .as_ref()
+                        .map(|p| format!("[{:?}]", p))
+                        .unwrap_or_default();
+                    let resolution_str = n
+                        .resolution
+                        .as_ref()
+                        .map(|r| format!(" ({:?})", r.status).to_lowercase())
+                        .unwrap_or_default();
+                    println!(
+                        "  {} {} {}{}",
+                        n.id.cyan(),
+                        priority_str.yellow(),
+                        n.title,
+                        resolution_str.dimmed()
+                    );
+                }
+            }
+        }
+
+        Commands::Impact { id, depth, format } => {
+            let root = get_lattice_root();
+
+            let index = build_node_index(&root).unwrap_or_else(|e| {
+                emit_error(&format, "load_error", &e.to_string());
+            });
+            let Some(impact) = find_impact(&id, &index, depth) else {
+                emit_error(&format, "not_found", &format!("Node not found: {}", id));
+            };
+            let levels = impact.by_distance();
+
+            if is_json(&format) {
+                let mut by_type = serde_json::Map::new();
+                for node in &impact.nodes {
+                    let count = by_type.entry(node.node_type.clone()).or_insert(json!(0));
+                    *count = json!(count.as_u64().unwrap_or(0) + 1);
+                }
+                let levels: Vec<_> = levels
+                    .iter()
+                    .map(|(distance, nodes)| {
+                        let nodes: Vec<_> = nodes
+                            .iter()
+                            .map(|n| {
+                                json!({
+                                    "id": n.node_id,
+                                    "type": n.node_type,
+                                    "title": n.title,
+                                    "via": n.via,
+                                    "edge_type": n.edge_type,
+                                })
+                            })
+                            .collect();
+                        json!({ "distance": distance, "nodes": nodes })
+                    })
+                    .collect();
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&json!({
+                        "id": impact.root_id,
+                        "total": impact.nodes.len(),
+                        "by_type": by_type,
+                        "levels": levels,
+                        "files": impact.files,
+                    }))
+                    .unwrap()
+                );
+            } else if impact.nodes.is_empty() {
+                println!(
+                    "{}",
+                    format!("Nothing depends on {}.", impact.root_id).green()
+                );
+            } else {
+                println!(
+                    "{}",
+                    format!(
+                        "IMPACT OF {} ({} nodes):\n",
+                        impact.root_id,
+                        impact.nodes.len()
+                    )
+                    .bold()
+                );
+                for (distance, nodes) in &levels {
+                    let label = if *distance == 1 { "edge" } else { "edges" };
+                    println!("{}", format!("{} {} away:", distance, label).cyan());
+                    for n in nodes {
+                        println!("  {} ({}) {}", n.node_id.bold(), n.node_type, n.title);
+                        println!("    {}", format!("{} {}", n.edge_type, n.via).dimmed());
+                    }
+                    println!();
+                }
+                if !impact.files.is_empty() {
+                    println!(
+                        "{}",
+                        format!("Bound files ({}):", impact.files.len()).cyan()
+                    );
+                    for file in &impact.files {
+                        println!("  {}", file);
+                    }
+                }
+            }
+        }
+
+        Commands::Trace {
+            from,
+            to,
+            all,
+            limit,
+            max_depth,
+            format,
+        } => {
+            let root = get_lattice_root();
+
+            let index = build_node_index(&root).unwrap_or_else(|e| {
+                emit_error(&format, "load_error", &e.to_string());
+            });
+            for id in std::iter::once(&from).chain(to.as_ref()) {
+                if lookup_node(&index, id).is_none() {
+                    emit_error(&format, "not_found", &format!("Node not found: {}", id));
+                }
+            }
+            let paths = trace_paths(&from, to.as_deref(), &index, all, limit, max_depth);
+
+            if is_json(&format) {
+                let paths: Vec<_> = paths
+                    .iter()
+                    .map(|path| {
+                        let hops: Vec<_> = path
+                            .iter()
+                            .map(|h| {
+                                json!({
+                                    "from": h.from,
+                                    "edge_type": h.edge_type,
+                                    "to": h.to,
+                                    "bound_version": h.bound_version,
+                                    "current_version": h.current_version,
+                                    "rationale": h.rationale,
+                                    "drift": h.drift.as_ref().map(|d| format!("{:?}", d).to_lowercase()),
+                                })
+                            })
+                            .collect();
+                        json!({
+                            "length": path.len(),
+                            "drifted": path.iter().any(|h| h.drift.is_some()),
+                            "hops": hops,
+                        })
+                    })
+                    .collect();
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&json!({
+                        "from": from,
+                        "to": to,
+                        "path_count": paths.len(),
+                        "paths": paths,
+                    }))
+                    .unwrap()
+                );
+            } else if paths.is_empty() {
+                let msg = match &to {
+                    Some(to) => format!("No path from {} to {}.", from, to),
+                    None => format!("{} has no outgoing edges.", from),
+                };
+                println!("{}", msg.yellow());
+            } else {
+                let target = to.as_deref().unwrap_or("its roots");
+                println!(
+                    "{}",
+                    format!(
+                        "TRACE {} -> {} ({} path{}):\n",
+                        from,
+                        target,
+                        paths.len(),
+                        if paths.len() == 1 { "" } else { "s" }
+                    )
+                    .bold()
+                );
+                for (i, path) in paths.iter().enumerate() {
+                    let mut chain = path[0].from.clone();
+                    for hop in path {
+                        chain.push_str(&format!(" \u{2192} {} \u{2192} {}", hop.edge_type, hop.to));
+                    }
+                    println!("{} {}", format!("{}.", i + 1).cyan(), chain.bold());
+                    for hop in path {
+                        let binding = format!(
+                            "{} {} {}@{}",
+                            hop.from, hop.edge_type, hop.to, hop.bound_version
+                        );
+                        match &hop.drift {
+                            Some(severity) => println!(
+                                "   {} {}",
+                                binding,
+                                format!(
+                                    "DRIFTED -> {} [{}]",
+                                    hop.current_version,
+                                    format!("{:?}", severity).to_lowercase()
+                                )
+                                .red()
+                            ),
+                            None => println!("   {}", binding),
+                        }
+                        if let Some(rationale) = &hop.rationale {
+                            println!("     {}", rationale.dimmed());
+                        }
+                    }
+                    println!();
+                }
+            }
+        }
+
+        Commands::Coverage {
+            check,
+            gaps,
+            format,
+        } => {
+            let root = get_lattice_root();
+            let (index, reverse) = build_indexes(&root).unwrap_or_else(|e| {
+                emit_error(&format, "load_error", &e.to_string());
+            });
+            let thresholds = parse_thresholds(&load_config(&root).coverage_thresholds)
+                .unwrap_or_else(|e| emit_error(&format, "invalid_config", &e));
+            let report = compute_coverage(&index, &reverse, &thresholds);
+            let failures = report.failures();
+
+            if is_json(&format) {
+                let round = |pct: f64| (pct * 10.0).round() / 10.0;
+                let tallies = |map: &std::collections::BTreeMap<String, Tally>| {
+                    map.iter()
+                        .map(|(k, t)| {
+                            (
+                                k.clone(),
+                                json!({
+                                    "total": t.total,
+                                    "covered": t.covered,
+                                    "percent": round(t.percent()),
+                                }),
+                            )
+                        })
+                        .collect::<serde_json::Map<_, _>>()
+                };
+                let metrics: Vec<_> = report
+                    .metrics
+                    .iter()
+                    .map(|m| {
+                        json!({
+                            "metric": m.metric.key(),
+                            "label": m.metric.gap_label(),
+                            "total": m.tally.total,
+                            "covered": m.tally.covered,
+                            "percent": round(m.tally.percent()),
+                            "threshold": m.threshold,
+                            "passed": m.passes(),
+                            "gaps": m.gaps,
+                            "by_category": tallies(&m.by_category),
+                            "by_priority": tallies(&m.by_priority),
+                        })
+                    })
+                    .collect();
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&json!({
+                        "passed": failures.is_empty(),
+                        "failures": failures.iter().map(|m| m.metric.key()).collect::<Vec<_>>(),
+                        "metrics": metrics,
+                    }))
+                    .unwrap()
+                );
+            } else {
+                println!("{}\n", "COVERAGE".bold());
+                for m in &report.metrics {
+                    let missing = m.tally.total - m.tally.covered;
+                    let headline =
+                        format!("{}: {} of {}", m.metric.gap_label(), missing, m.tally.total);
+                    let pct = format!("{:.1}% covered", m.tally.percent());
+                    let pct = match m.threshold {
+                        Some(t) => format!("{}, min {}%", pct, t),
+                        None => pct,
+                    };
+                    let verdict = match (m.threshold, m.passes()) {
+                        (None, _) => String::new(),
+                        (Some(_), true) => format!(" {}", "PASS".green()),
+                        (Some(_), false) => format!(" {}", "FAIL".red().bold()),
+                    };
+                    let headline = if missing == 0 {
+                        headline.green()
+                    } else {
+                        headline.yellow()
+                    };
+                    println!("  {} ({}){}", headline, pct, verdict);
+                    if m.tally.total == 0 {
+                        continue;
+                    }
+                    let slices = |map: &std::collections::BTreeMap<String, Tally>| {
+                        map.iter()
+                            .map(|(k, t)| {
+                                format!("{} {:.0}% ({}/{})", k, t.percent(), t.covered, t.total)
+                            })
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    };
+                    println!("    {} {}", "by category:".dimmed(), slices(&m.by_category));
+                    if m.by_priority.keys().any(|k| k != "none") {
+                        println!("    {} {}", "by priority:".dimmed(), slices(&m.by_priority));
+                    }
+                    if gaps && !m.gaps.is_empty() {
+                        println!("    {} {}", "gaps:".dimmed(), m.gaps.join(", "));
+                    }
+                }
+                if !failures.is_empty() {
+                    println!();
+                    println!(
+                        "{}",
+                        format!(
+                            "{} metric(s) below threshold: {}",
+                            failures.len(),
+                            failures
+                                .iter()
+                                .map(|m| m.metric.key())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )
+                        .red()
+                    );
+                }
+            }
+
+            if check && !failures.is_empty() {
+                process::exit(2);
+            }
+        }
+
+        Commands::Hotspots {
+            since,
+            node_type,
+            limit,
+            format,
+        } => {
+            let root = get_lattice_root();
+            let node_type = node_type.map(|t| match t.to_lowercase().as_str() {
+                "requirement" | "requirements" => NodeType::Requirement,
+                "thesis" | "theses" => NodeType::Thesis,
+                _ => emit_error(
+                    &format,
+                    "invalid_type",
+                    &format!("Invalid type '{}' (expected requirement or thesis)", t),
+                ),
+            });
+            let index = build_node_index(&root).unwrap_or_else(|e| {
+                emit_error(&format, "load_error", &e.to_string());
+            });
+            let churn = git_churn(&root, &since).unwrap_or_else(|e| {
+                emit_error(&format, "git_error", &e.to_string());
+            });
+            let hotspots: Vec<_> = find_hotspots(&index, &churn)
+                .into_iter()
+                .filter(|h| node_type.as_ref().is_none_or(|t| &h.node_type == t))
+                .collect();
+            let total = hotspots.len();
+            let shown = &hotspots[..total.min(limit)];
+
+            if is_json(&format) {
+                let items: Vec<_> = shown
+                    .iter()
+                    .map(|h| {
+                        json!({
+                            "id": h.node_id,
+                            "type": format!("{:?}", h.node_type).to_lowercase(),
+                            "title": h.title,
+                            "score": h.score,
+                            "lines": h.lines,
+                            "commits": h.commits,
+                            "drift": h.drift,
+                            "contested": h.contested,
+                            "implementations": h.implementations,
+                            "files": h.files.iter().map(|(path, lines)| json!({"path": path, "lines": lines})).collect::<Vec<_>>(),
+                        })
+                    })
+                    .collect();
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&json!({
+                        "since": since,
+                        "total": total,
+                        "hotspots": items,
+                    }))
+                    .unwrap()
+                );
+            } else if shown.is_empty() {
+                println!(
+                    "{}",
+                    format!("No churn on bound implementation files since {}.", since).dimmed()
+                );
+            } else {
+                println!("{}", format!("HOTSPOTS (since {})", since).bold());
+                println!(
+                    "{}\n",
+                    "score = lines changed x (1 + 0.5 x drift items) x (2 if contested)".dimmed()
+                );
+                for (rank, h) in shown.iter().enumerate() {
+                    let mut flags = Vec::new();
+                    if h.drift > 0 {
+                        flags.push(format!("{} drift", h.drift).yellow().to_string());
+                    }
+                    if h.contested {
+                        flags.push("contested".red().to_string());
+                    }
+                    let flags = if flags.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" [{}]", flags.join(", "))
+                    };
+                    println!(
+                        "{:>3}. {} {} - {}{}",
+                        rank + 1,
+                        h.node_id.cyan(),
+                        format!("({:.0})", h.score).bold(),
+                        h.title,
+                        flags
+                    );
+                    println!(
+                        "     {}",
+                        format!(
+                            "{} lines, {} commits, {} files via {}",
+                            h.lines,
+                            h.commits,
+                            h.files.len(),
+                            h.implementations.join(", ")
+                        )
+                        .dimmed()
+                    );
+                    let top: Vec<String> = h
+                        .files
+                        .iter()
+                        .take(3)
+                        .map(|(path, lines)| format!("{} ({})", path, lines))
+                        .collect();
+                    let more = if h.files.len() > 3 {
+                        format!(", +{} more", h.files.len() - 3)
+                    } else {
+                        String::new()
+                    };
+                    println!("     {}{}", top.join(", ").dimmed(), more.dimmed());
+                }
+                if total > shown.len() {
+                    println!(
+                        "\n{}",
+                        format!("{} more; use --limit to show them", total - shown.len()).dimmed()
+                    );
+                }
+            }
+        }
+
         Commands::Diff {
             since,
             since_push,
@@ -4793,6 +6726,36 @@ fn run_command(command: Commands) {
             }
         }
 
+        Commands::Index { index_command } => match index_command {
+            IndexCommands::Rebuild { format } => {
+                let root = get_lattice_root();
+
+                match rebuild_id_index(&root) {
+                    Ok(stats) => {
+                        let cache = lattice::id_index::id_index_path(&root)
+                            .map(|p| p.display().to_string());
+                        if is_json(&format) {
+                            println!(
+                                "{}",
+                                serde_json::to_string_pretty(&json!({
+                                    "success": true,
+                                    "total": stats.total,
+                                    "cache": cache,
+                                }))
+                                .unwrap()
+                            );
+                        } else {
+                            println!("{}", format!("Indexed {} nodes", stats.total).green());
+                            if let Some(c) = cache {
+                                println!("{}", format!("Cache: {}", c).dimmed());
+                            }
+                        }
+                    }
+                    Err(e) => emit_error(&format, "index_error", &e.to_string()),
+                }
+            }
+        },
+
         Commands::Help {
             json,
             compact,
@@ -5026,24 +6989,34 @@ mod tests {
             "list",
             "get",
             "search",
+            "query",
             "add requirement",
             "add thesis",
             "add source",
             "add implementation",
             "add edge",
             "add message",
+            "remove node",
             "remove edge",
             "replace edge",
+            "rename",
+            "merge",
             "resolve",
             "edit",
             "verify",
             "refine",
             "diff",
+            "log",
+            "impact",
+            "trace",
+            "hotspots",
             "drift",
             "lint",
+            "index rebuild",
             "freshness",
             "assess",
             "health",
+            "coverage",
             "summary",
             "plan",
             "export",
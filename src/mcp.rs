@@ -3,12 +3,17 @@
 //! Exposes lattice tools via the Model Context Protocol for LLM integration.
 //! Linked requirement: REQ-API-004
 
-use crate::graph::{build_node_index, find_drift};
+use crate::diff::explain_drift;
+use crate::graph::{
+    build_indexes, build_node_index, find_drift, find_impact, find_incoming_edges, lookup_node,
+};
+use crate::query::{parse_query, run_query};
 use crate::search::SearchEngine;
 use crate::storage::{
-    AddImplementationOptions, AddRequirementOptions, GapType, RefineOptions, ResolveOptions,
-    add_implementation, add_requirement, find_lattice_root, load_nodes_by_type, refine_requirement,
-    resolve_node,
+    AddImplementationOptions, AddRequirementOptions, BumpLevel, EditNodeOptions, GapType,
+    IncomingEdgeAction, RefineOptions, RemoveNodeOptions, ResolveOptions, add_implementation,
+    add_requirement, edit_node, find_lattice_root, load_node, load_nodes_by_type,
+    refine_requirement, remove_node, resolve_node,
 };
 use crate::types::{Priority, Resolution, Status};
 use rmcp::ServiceExt;
@@ -191,18 +196,23 @@ impl LatticeServer {
 
     /// Get a specific node by ID
     fn get(&self, id: &str) -> Result<Value, String> {
-        let index = build_node_index(&self.root).map_err(|e| e.to_string())?;
+        let (index, reverse) = build_indexes(&self.root).map_err(|e| e.to_string())?;
 
-        if let Some(node) = index.get(id) {
-            Ok(serde_json::to_value(node).map_err(|e| e.to_string())?)
+        if let Some(node) = lookup_node(&index, id) {
+            let mut value = serde_json::to_value(node).map_err(|e| e.to_string())?;
+            value["incoming"] = json!(reverse.get(&node.id).cloned().unwrap_or_default());
+            Ok(value)
         } else {
             Err(format!("Node not found: {}", id))
         }
     }
 
-    /// Check for drift
-    fn drift(&self) -> Result<Value, String> {
-        let reports = find_drift(&self.root).map_err(|e| e.to_string())?;
+    /// Check for drift, optionally explaining what changed in each target
+    fn drift(&self, explain: bool) -> Result<Value, String> {
+        let mut reports = find_drift(&self.root).map_err(|e| e.to_string())?;
+        if explain {
+            explain_drift(&self.root, &mut reports);
+        }
 
         let result: Vec<Value> = reports
             .iter()
@@ -211,12 +221,16 @@ impl LatticeServer {
                     "node_id": r.node_id,
                     "node_type": r.node_type,
                     "stale_edges": r.drift_items.iter().map(|i| {
-                        json!({
+                        let mut edge = json!({
                             "target_id": i.target_id,
                             "bound_version": i.bound_version,
                             "current_version": i.current_version,
                             "severity": format!("{:?}", i.severity).to_lowercase()
-                        })
+                        });
+                        if explain {
+                            edge["explanation"] = json!(i.explanation);
+                        }
+                        edge
                     }).collect::<Vec<_>>()
                 })
             })
@@ -228,6 +242,71 @@ impl LatticeServer {
         }))
     }
 
+    /// Run a graph query over all nodes
+    fn query(&self, query: &str, limit: Option<usize>) -> Result<Value, String> {
+        let parsed = parse_query(query).map_err(|e| e.to_string())?;
+        let index = build_node_index(&self.root).map_err(|e| e.to_string())?;
+        let mut results = run_query(&parsed, &index);
+        let total = results.len();
+        if let Some(max) = limit {
+            results.truncate(max);
+        }
+        let truncated = results.len() < total;
+
+        let results: Vec<Value> = results
+            .iter()
+            .map(|n| {
+                json!({
+                    "id": n.id,
+                    "type": format!("{:?}", n.node_type).to_lowercase(),
+                    "title": n.title,
+                    "version": n.version,
+                    "status": format!("{:?}", n.status).to_lowercase(),
+                    "priority": n.priority.as_ref().map(|p| format!("{:?}", p)),
+                    "resolution": n.resolution.as_ref().map(|r| format!("{:?}", r.status).to_lowercase())
+                })
+            })
+            .collect();
+
+        Ok(json!({
+            "count": total,
+            "returned": results.len(),
+            "truncated": truncated,
+            "results": results
+        }))
+    }
+
+    /// Everything affected by a change to a node, grouped by distance
+    fn impact(&self, id: &str, depth: Option<usize>) -> Result<Value, String> {
+        let index = build_node_index(&self.root).map_err(|e| e.to_string())?;
+        let impact =
+            find_impact(id, &index, depth).ok_or_else(|| format!("Node not found: {}", id))?;
+
+        let levels: Vec<Value> = impact
+            .by_distance()
+            .iter()
+            .map(|(distance, nodes)| {
+                json!({
+                    "distance": distance,
+                    "nodes": nodes.iter().map(|n| json!({
+                        "id": n.node_id,
+                        "type": n.node_type,
+                        "title": n.title,
+                        "via": n.via,
+                        "edge_type": n.edge_type
+                    })).collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        Ok(json!({
+            "id": impact.root_id,
+            "total": impact.nodes.len(),
+            "levels": levels,
+            "files": impact.files
+        }))
+    }
+
     /// Resolve a requirement
     fn resolve_req(&self, id: &str, status: &str, reason: Option<&str>) -> Result<Value, String> {
         let resolution = match status.to_lowercase().as_str() {
@@ -278,6 +357,7 @@ impl LatticeServer {
             title: params.title,
             body: params.body,
             priority,
+            estimate: params.estimate,
             category: params.category,
             tags: params.tags,
             derives_from: params.derives_from,
@@ -439,6 +519,76 @@ impl LatticeServer {
         }))
     }
 
+    /// Archive or delete a node, handling edges that point at it
+    fn remove_node(&self, params: RemoveNodeParams) -> Result<Value, String> {
+        let incoming = match (params.remove_edges.unwrap_or(false), params.retarget) {
+            (_, Some(new_id)) => IncomingEdgeAction::Retarget(new_id),
+            (true, None) => IncomingEdgeAction::Remove,
+            (false, None) => IncomingEdgeAction::Keep,
+        };
+        let hard = params.hard.unwrap_or(false);
+        let dry_run = params.dry_run.unwrap_or(false);
+
+        let options = RemoveNodeOptions {
+            node_id: params.id.clone(),
+            archive: !hard,
+            incoming,
+            dry_run,
+        };
+
+        let edges = find_incoming_edges(&self.root, &params.id).map_err(|e| e.to_string())?;
+        let result = remove_node(&self.root, options, edges).map_err(|e| e.to_string())?;
+
+        Ok(json!({
+            "success": true,
+            "dry_run": dry_run,
+            "id": params.id,
+            "action": if hard { "delete" } else { "archive" },
+            "file": result.path.display().to_string(),
+            "archived_to": result.archived_to.map(|p| p.display().to_string()),
+            "incoming": result.incoming.iter().map(|e| {
+                json!({
+                    "from": e.from_id,
+                    "edge_type": e.edge_type,
+                    "version": e.version
+                })
+            }).collect::<Vec<_>>(),
+            "edges_updated": result.edges_updated
+        }))
+    }
+
+    /// Edit fields on a node, bumping its version
+    fn edit(&self, params: EditParams) -> Result<Value, String> {
+        let status = params.status.map(|s| s.parse::<Status>()).transpose()?;
+        let priority = params.priority.map(|p| p.parse::<Priority>()).transpose()?;
+        let bump = params.bump.map(|b| b.parse::<BumpLevel>()).transpose()?;
+
+        let options = EditNodeOptions {
+            node_id: params.id.clone(),
+            title: params.title,
+            body: params.body,
+            status,
+            priority,
+            estimate: params.estimate,
+            confidence: params.confidence,
+            tags: params.tags,
+            category: params.category,
+            files: None,
+            test_command: None,
+            bump,
+        };
+
+        let path = edit_node(&self.root, options).map_err(|e| e.to_string())?;
+        let node = load_node(&path).map_err(|e| e.to_string())?;
+
+        Ok(json!({
+            "success": true,
+            "id": params.id,
+            "version": node.version,
+            "file": path.display().to_string()
+        }))
+    }
+
     /// Add an implementation
     fn add_impl(&self, params: AddImplementationParams) -> Result<Value, String> {
         let created_by = format!("agent:mcp-{}", chrono::Utc::now().format("%Y-%m-%d"));
@@ -471,6 +621,8 @@ struct AddRequirementParams {
     title: String,
     body: String,
     priority: String,
+    #[serde(default)]
+    estimate: Option<f64>,
     category: String,
     #[serde(default)]
     tags: Option<Vec<String>>,
@@ -507,6 +659,42 @@ struct RefineParams {
     implementation: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct EditParams {
+    id: String,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    status: Option<String>,
+    #[serde(default)]
+    priority: Option<String>,
+    #[serde(default)]
+    estimate: Option<f64>,
+    #[serde(default)]
+    confidence: Option<f64>,
+    #[serde(default)]
+    tags: Option<Vec<String>>,
+    #[serde(default)]
+    category: Option<String>,
+    #[serde(default)]
+    bump: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RemoveNodeParams {
+    id: String,
+    #[serde(default)]
+    hard: Option<bool>,
+    #[serde(default)]
+    remove_edges: Option<bool>,
+    #[serde(default)]
+    retarget: Option<String>,
+    #[serde(default)]
+    dry_run: Option<bool>,
+}
+
 #[derive(Debug, Deserialize)]
 struct McpSearchParams {
     #[serde(default)]
@@ -581,7 +769,8 @@ fn get_tools() -> Vec<Tool> {
         Tool::new(
             "lattice_get",
             "Retrieve full details of a specific node by ID. Returns title, body, \
-             version, edges, resolution status, and all metadata.",
+             version, edges, resolution status, and all metadata, plus incoming edges: \
+             which nodes (e.g. implementations, messages) point at this one.",
             make_schema(
                 json!({
                     "id": {
@@ -595,8 +784,61 @@ fn get_tools() -> Vec<Tool> {
         Tool::new(
             "lattice_drift",
             "Check for version drift in the knowledge graph. Identifies edges where \
-             the target node version has changed since the edge was created.",
-            make_schema(json!({}), vec![]),
+             the target node version has changed since the edge was created. Set explain \
+             to include the target's old and new title, body and acceptance tests plus a \
+             line diff, read from git history.",
+            make_schema(
+                json!({
+                    "explain": {
+                        "type": "boolean",
+                        "description": "Include what changed in each target since the bound version"
+                    }
+                }),
+                vec![],
+            ),
+        ),
+        Tool::new(
+            "lattice_query",
+            "Answer ad hoc questions with a graph query: a node type (requirements, theses, \
+             sources, implementations, messages, nodes), then 'where' with field predicates \
+             (FIELD = | != | ~ VALUE over id, title, body, type, status, priority, resolution, \
+             category, tag, version, created_by) and edge patterns (->EDGE [TYPE] [(...)] \
+             outgoing, <-EDGE incoming, 'any' for every edge type), combined with and/or/not. \
+             Example: requirements where priority = P0 and not <-satisfies implementation \
+             and ->derives_from thesis(status = contested)",
+            make_schema(
+                json!({
+                    "query": {
+                        "type": "string",
+                        "description": "Query string"
+                    },
+                    "limit": {
+                        "type": "integer",
+                        "description": "Maximum number of results"
+                    }
+                }),
+                vec!["query"],
+            ),
+        ),
+        Tool::new(
+            "lattice_impact",
+            "Blast radius of changing or dropping a node: every node that reaches it \
+             through any incoming edge (derives_from, satisfies, depends_on, ...), grouped \
+             by distance, plus files bound by affected implementations. Use before editing \
+             a thesis or requirement.",
+            make_schema(
+                json!({
+                    "id": {
+                        "type": "string",
+                        "description": "Node ID (e.g., THX-AGENT-NATIVE-TOOLS)"
+                    },
+                    "depth": {
+                        "type": "integer",
+                        "description": "Only follow this many edges back from the node"
+                    }
+                }),
+                vec!["id"],
+            ),
         ),
         Tool::new(
             "lattice_resolve",
@@ -644,6 +886,10 @@ fn get_tools() -> Vec<Tool> {
                         "description": "Priority level",
                         "enum": ["P0", "P1", "P2"]
                     },
+                    "estimate": {
+                        "type": "number",
+                        "description": "Optional effort estimate (points or days)"
+                    },
                     "category": {
                         "type": "string",
                         "description": "Category (e.g., API, CLI, CORE)"
@@ -746,6 +992,94 @@ fn get_tools() -> Vec<Tool> {
                 vec!["parent", "gap_type", "title", "description"],
             ),
         ),
+        Tool::new(
+            "lattice_edit",
+            "Edit fields on an existing node. Bumps the version — by the configured \
+             version_policy, or explicitly with bump (major for breaking rewrites, minor for \
+             meaningful body changes, patch for wording fixes). Bigger bumps raise the drift \
+             severity seen by dependent nodes.",
+            make_schema(
+                json!({
+                    "id": {
+                        "type": "string",
+                        "description": "Node ID to edit"
+                    },
+                    "title": {
+                        "type": "string",
+                        "description": "New title"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "New body"
+                    },
+                    "status": {
+                        "type": "string",
+                        "enum": ["draft", "active", "contested", "deprecated", "superseded"],
+                        "description": "New status"
+                    },
+                    "priority": {
+                        "type": "string",
+                        "enum": ["P0", "P1", "P2"],
+                        "description": "New priority (requirements only)"
+                    },
+                    "estimate": {
+                        "type": "number",
+                        "description": "New effort estimate in points or days (requirements only)"
+                    },
+                    "confidence": {
+                        "type": "number",
+                        "description": "New confidence 0.0-1.0 (theses only)"
+                    },
+                    "tags": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Tags (replaces existing)"
+                    },
+                    "category": {
+                        "type": "string",
+                        "description": "New category"
+                    },
+                    "bump": {
+                        "type": "string",
+                        "enum": ["major", "minor", "patch"],
+                        "description": "Version bump level (default: from config version_policy, else patch)"
+                    }
+                }),
+                vec!["id"],
+            ),
+        ),
+        Tool::new(
+            "lattice_remove_node",
+            "Remove a node from the knowledge graph. Archives it to .lattice/archive/ with \
+             status deprecated (default) or deletes it (hard). Returns every incoming edge; \
+             set remove_edges to drop them or retarget to point them at a replacement node. \
+             Use dry_run first to preview.",
+            make_schema(
+                json!({
+                    "id": {
+                        "type": "string",
+                        "description": "Node ID to remove"
+                    },
+                    "hard": {
+                        "type": "boolean",
+                        "description": "Delete the file instead of archiving it"
+                    },
+                    "remove_edges": {
+                        "type": "boolean",
+                        "description": "Remove incoming edges from referencing nodes"
+                    },
+                    "retarget": {
+                        "type": "string",
+                        "description": "Retarget incoming edges to this node ID instead"
+                    },
+                    "dry_run": {
+                        "type": "boolean",
+                        "description": "Preview what would change without modifying files"
+                    }
+                }),
+                vec!["id"],
+            ),
+        ),
         Tool::new(
             "lattice_search",
             "Search for nodes with flexible filtering. Supports text search, priority/status filtering, \
@@ -889,7 +1223,45 @@ impl ServerHandler for LatticeServer {
                         })?;
                     self.get(id)
                 }
-                "lattice_drift" => self.drift(),
+                "lattice_drift" => self.drift(
+                    arguments
+                        .get("explain")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(false),
+                ),
+                "lattice_query" => {
+                    let query =
+                        arguments
+                            .get("query")
+                            .and_then(|v| v.as_str())
+                            .ok_or_else(|| {
+                                rmcp::model::ErrorData::invalid_params(
+                                    "Missing required parameter: query",
+                                    None,
+                                )
+                            })?;
+                    let limit = arguments
+                        .get("limit")
+                        .and_then(|v| v.as_u64())
+                        .map(|l| l as usize);
+                    self.query(query, limit)
+                }
+                "lattice_impact" => {
+                    let id = arguments
+                        .get("id")
+                        .and_then(|v| v.as_str())
+                        .ok_or_else(|| {
+                            rmcp::model::ErrorData::invalid_params(
+                                "Missing required parameter: id",
+                                None,
+                            )
+                        })?;
+                    let depth = arguments
+                        .get("depth")
+                        .and_then(|v| v.as_u64())
+                        .map(|d| d as usize);
+                    self.impact(id, depth)
+                }
                 "lattice_resolve" => {
                     let id = arguments
                         .get("id")
@@ -933,6 +1305,20 @@ impl ServerHandler for LatticeServer {
                     .map_err(|e| rmcp::model::ErrorData::invalid_params(e.to_string(), None))?;
                     self.refine(params)
                 }
+                "lattice_edit" => {
+                    let params: EditParams = serde_json::from_value(
+                        serde_json::to_value(&arguments).unwrap_or_default(),
+                    )
+                    .map_err(|e| rmcp::model::ErrorData::invalid_params(e.to_string(), None))?;
+                    self.edit(params)
+                }
+                "lattice_remove_node" => {
+                    let params: RemoveNodeParams = serde_json::from_value(
+                        serde_json::to_value(&arguments).unwrap_or_default(),
+                    )
+                    .map_err(|e| rmcp::model::ErrorData::invalid_params(e.to_string(), None))?;
+                    self.remove_node(params)
+                }
                 "lattice_search" => {
                     let params: McpSearchParams = serde_json::from_value(
                         serde_json::to_value(&arguments).unwrap_or_default(),
@@ -997,18 +1383,22 @@ mod tests {
     #[test]
     fn test_get_tools_returns_all_tools() {
         let tools = get_tools();
-        assert_eq!(tools.len(), 9);
+        assert_eq!(tools.len(), 13);
 
         let names: Vec<&str> = tools.iter().map(|t| t.name.as_ref()).collect();
         assert!(names.contains(&"lattice_summary"));
         assert!(names.contains(&"lattice_list"));
         assert!(names.contains(&"lattice_get"));
         assert!(names.contains(&"lattice_drift"));
+        assert!(names.contains(&"lattice_impact"));
+        assert!(names.contains(&"lattice_query"));
         assert!(names.contains(&"lattice_resolve"));
         assert!(names.contains(&"lattice_add_requirement"));
         assert!(names.contains(&"lattice_add_implementation"));
         assert!(names.contains(&"lattice_refine"));
         assert!(names.contains(&"lattice_search"));
+        assert!(names.contains(&"lattice_remove_node"));
+        assert!(names.contains(&"lattice_edit"));
     }
 
     #[test]
@@ -1053,7 +1443,7 @@ mod tests {
     #[test]
     fn test_drift_empty_lattice() {
         let (_temp_dir, server) = setup_test_lattice();
-        let result = server.drift();
+        let result = server.drift(false);
         assert!(result.is_ok());
 
         let value = result.unwrap();
@@ -1061,6 +1451,51 @@ mod tests {
         assert_eq!(value.get("reports").unwrap().as_array().unwrap().len(), 0);
     }
 
+    #[test]
+    fn test_query_reports_syntax_errors() {
+        let (_temp_dir, server) = setup_test_lattice();
+        assert_eq!(server.query("requirements", None).unwrap()["count"], 0);
+        let err = server.query("requirements where", None).unwrap_err();
+        assert!(err.contains("syntax error"));
+    }
+
+    #[test]
+    fn test_query_count_is_total_before_limit() {
+        let (_temp_dir, server) = setup_test_lattice();
+        for id in ["REQ-Q-001", "REQ-Q-002", "REQ-Q-003"] {
+            server
+                .add_req(AddRequirementParams {
+                    id: id.to_string(),
+                    title: "Queried".to_string(),
+                    body: "Body".to_string(),
+                    priority: "P1".to_string(),
+                    estimate: None,
+                    category: "TEST".to_string(),
+                    tags: None,
+                    derives_from: None,
+                    depends_on: None,
+                })
+                .unwrap();
+        }
+
+        let result = server.query("requirements", Some(2)).unwrap();
+        assert_eq!(result["count"], 3);
+        assert_eq!(result["returned"], 2);
+        assert_eq!(result["truncated"], true);
+        assert_eq!(result["results"].as_array().unwrap().len(), 2);
+
+        let result = server.query("requirements", None).unwrap();
+        assert_eq!(result["count"], 3);
+        assert_eq!(result["truncated"], false);
+    }
+
+    #[test]
+    fn test_impact_nonexistent_node() {
+        let (_temp_dir, server) = setup_test_lattice();
+        let result = server.impact("REQ-DOES-NOT-EXIST", None);
+        assert!(result.unwrap_err().contains("Node not found"));
+    }
+
     #[test]
     fn test_resolve_invalid_status() {
         let (_temp_dir, server) = setup_test_lattice();
@@ -1077,6 +1512,7 @@ mod tests {
             title: "Test Requirement".to_string(),
             body: "Test body".to_string(),
             priority: "P5".to_string(), // Invalid
+            estimate: None,
             category: "TEST".to_string(),
             tags: None,
             derives_from: None,
@@ -1095,6 +1531,7 @@ mod tests {
             title: "Test Requirement".to_string(),
             body: "Test body".to_string(),
             priority: "P1".to_string(),
+            estimate: None,
             category: "TEST".to_string(),
             tags: None,
             derives_from: None,
@@ -1162,6 +1599,7 @@ mod tests {
             title: "Searchable Requirement".to_string(),
             body: "This requirement is for testing search".to_string(),
             priority: "P0".to_string(),
+            estimate: None,
             category: "TEST".to_string(),
             tags: Some(vec!["mvp".to_string(), "core".to_string()]),
             derives_from: None,
@@ -1253,6 +1691,7 @@ mod tests {
                 title: "API Requirement".to_string(),
                 body: "API body".to_string(),
                 priority: "P1".to_string(),
+                estimate: None,
                 category: "API".to_string(),
                 tags: None,
                 derives_from: None,
@@ -1265,6 +1704,7 @@ mod tests {
                 title: "CLI Requirement".to_string(),
                 body: "CLI body".to_string(),
                 priority: "P1".to_string(),
+                estimate: None,
                 category: "CLI".to_string(),
                 tags: None,
                 derives_from: None,
@@ -1320,6 +1760,7 @@ mod tests {
                 title: "Has both tags".to_string(),
                 body: "Body".to_string(),
                 priority: "P1".to_string(),
+                estimate: None,
                 category: "TEST".to_string(),
                 tags: Some(vec!["mvp".to_string(), "core".to_string()]),
                 derives_from: None,
@@ -1332,6 +1773,7 @@ mod tests {
                 title: "Has only mvp".to_string(),
                 body: "Body".to_string(),
                 priority: "P1".to_string(),
+                estimate: None,
                 category: "TEST".to_string(),
                 tags: Some(vec!["mvp".to_string()]),
                 derives_from: None,
@@ -1387,6 +1829,7 @@ mod tests {
                 title: "Refinable Requirement".to_string(),
                 body: "A requirement that needs refinement".to_string(),
                 priority: "P0".to_string(),
+                estimate: None,
                 category: "TEST".to_string(),
                 tags: None,
                 derives_from: None,
@@ -1421,6 +1864,7 @@ mod tests {
                 title: "Bad Gap Type".to_string(),
                 body: "Body".to_string(),
                 priority: "P0".to_string(),
+                estimate: None,
                 category: "TEST".to_string(),
                 tags: None,
                 derives_from: None,
@@ -1439,4 +1883,40 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_get_includes_incoming_edges() {
+        let (_temp_dir, server) = setup_test_lattice();
+
+        server
+            .add_req(AddRequirementParams {
+                id: "REQ-IN-001".to_string(),
+                title: "Target".to_string(),
+                body: "Body".to_string(),
+                priority: "P1".to_string(),
+                estimate: None,
+                category: "IN".to_string(),
+                tags: None,
+                derives_from: None,
+                depends_on: None,
+            })
+            .unwrap();
+        server
+            .add_impl(AddImplementationParams {
+                id: "IMP-IN-001".to_string(),
+                title: "Impl".to_string(),
+                body: "Body".to_string(),
+                language: None,
+                files: None,
+                test_command: None,
+                satisfies: Some(vec!["REQ-IN-001".to_string()]),
+            })
+            .unwrap();
+
+        let value = server.get("REQ-IN-001").unwrap();
+        let incoming = value["incoming"].as_array().unwrap();
+        assert_eq!(incoming.len(), 1);
+        assert_eq!(incoming[0]["from_id"], "IMP-IN-001");
+        assert_eq!(incoming[0]["edge_type"], "satisfies");
+    }
 }
@@ -0,0 +1,310 @@
+//! Custom lint policies declared under `lint.policies` in config.yaml.
+//!
+//! A policy selects nodes by type and field values, then requires fields or
+//! edges on every selected node. Violations surface as ordinary lint issues
+//! under the policy's ID, so `lint.rules` overrides and suppressions apply to
+//! them like built-in rules.
+//!
+//! ```yaml
+//! lint:
+//!   policies:
+//!     - id: p0-acceptance
+//!       type: requirement
+//!       when: { priority: P0 }
+//!       require:
+//!         - field: acceptance
+//!       message: P0 requirements need an acceptance test
+//!       severity: error
+//!     - id: risk-sources
+//!       type: thesis
+//!       when: { category: risk }
+//!       require:
+//!         - edge: supported_by
+//!           min: 2
+//!       message: Risk theses need at least two sources
+//! ```
+
+use crate::graph::lookup_node;
+use crate::lint::{LintRule, RuleLevel};
+use crate::storage::EDGE_TYPES;
+use crate::types::{LatticeNode, NodeIndex, NodeType};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+/// A user-defined lint rule.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LintPolicy {
+    /// Rule ID reported on violations; must not reuse a built-in rule ID.
+    pub id: String,
+    /// Node type the policy applies to; absent means every type.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub node_type: Option<NodeType>,
+    /// Field values a node must have to be checked (e.g. `priority: P0`).
+    /// A list field matches if any item equals the value.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub when: BTreeMap<String, Value>,
+    /// Conditions every selected node must meet.
+    pub require: Vec<PolicyCheck>,
+    pub message: String,
+    #[serde(default = "default_severity")]
+    pub severity: RuleLevel,
+}
+
+fn default_severity() -> RuleLevel {
+    RuleLevel::Warning
+}
+
+/// One condition of a policy: a field or an edge type, at least `min` times.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyCheck {
+    /// Field that must be set and non-empty. Looked up on the node first,
+    /// then in its type-specific `meta` (e.g. `test_command`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    /// Edge type the node must have.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edge: Option<String>,
+    /// Count edges of this type pointing at the node instead of from it.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub inbound: bool,
+    /// Minimum list items or edges (default 1).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<usize>,
+}
+
+/// Problems with the policy definitions themselves, one message each.
+pub fn validate_policies(policies: &[LintPolicy]) -> Vec<String> {
+    let mut problems = Vec::new();
+    let mut seen: Vec<&str> = Vec::new();
+    for (i, policy) in policies.iter().enumerate() {
+        let name = format!("lint.policies[{}] '{}'", i, policy.id);
+        if policy.id.is_empty() {
+            problems.push(format!("lint.policies[{}] has no id", i));
+        } else if LintRule::find(&policy.id).is_some() || policy.id == crate::lint::ALL_RULES {
+            problems.push(format!("{} reuses a built-in rule ID", name));
+        } else if seen.contains(&policy.id.as_str()) {
+            problems.push(format!("{} is defined more than once", name));
+        }
+        seen.push(&policy.id);
+
+        if policy.require.is_empty() {
+            problems.push(format!("{} has no require checks", name));
+        }
+        for check in &policy.require {
+            match (&check.field, &check.edge) {
+                (Some(_), None) if check.inbound => {
+                    problems.push(format!("{}: 'inbound' only applies to edge checks", name))
+                }
+                (Some(_), None) => {}
+                (None, Some(edge)) if !EDGE_TYPES.contains(&edge.as_str()) => {
+                    problems.push(format!("{}: unknown edge type '{}'", name, edge))
+                }
+                (None, Some(_)) => {}
+                _ => problems.push(format!(
+                    "{}: each check needs exactly one of 'field' or 'edge'",
+                    name
+                )),
+            }
+        }
+    }
+    problems
+}
+
+/// Nodes violating `policy`, with what each is missing, sorted by node ID.
+/// Only checks that `validate_policies` accepts are evaluated.
+pub fn policy_violations(policy: &LintPolicy, index: &NodeIndex) -> Vec<(String, String)> {
+    let inbound = inbound_edge_counts(index);
+    let mut nodes: Vec<&LatticeNode> = index
+        .values()
+        .filter(|n| policy.node_type.as_ref().is_none_or(|t| *t == n.node_type))
+        .collect();
+    nodes.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut violations = Vec::new();
+    for node in nodes {
+        let Ok(value) = serde_json::to_value(node) else {
+            continue;
+        };
+        let selected = policy.when.iter().all(|(field, expected)| {
+            field_value(&value, field).is_some_and(|v| matches(v, expected))
+        });
+        if !selected {
+            continue;
+        }
+
+        let missing: Vec<String> = policy
+            .require
+            .iter()
+            .filter_map(|check| {
+                let min = check.min.unwrap_or(1);
+                let (label, count) = match (&check.field, &check.edge) {
+                    (Some(_), None) if check.inbound => return None,
+                    (None, Some(edge)) if !EDGE_TYPES.contains(&edge.as_str()) => return None,
+                    (Some(field), None) => {
+                        let count = field_value(&value, field).map(count_items).unwrap_or(0);
+                        (format!("'{}'", field), count)
+                    }
+                    (None, Some(edge)) if check.inbound => {
+                        let count = inbound
+                            .get(&(node.id.as_str(), edge.as_str()))
+                            .copied()
+                            .unwrap_or(0);
+                        (format!("inbound '{}' edges", edge), count)
+                    }
+                    (None, Some(edge)) => {
+                        let count = node.typed_edges().iter().filter(|(t, _)| t == edge).count();
+                        (format!("'{}' edges", edge), count)
+                    }
+                    _ => return None,
+                };
+                (count < min).then(|| format!("{}: {}, needs {}", label, count, min))
+            })
+            .collect();
+        if !missing.is_empty() {
+            violations.push((node.id.clone(), missing.join("; ")));
+        }
+    }
+    violations
+}
+
+/// Edges arriving at each node, by (target ID, edge type).
+fn inbound_edge_counts(index: &NodeIndex) -> HashMap<(&str, &str), usize> {
+    let mut counts = HashMap::new();
+    for node in index.values() {
+        for (edge_type, edge_ref) in node.typed_edges() {
+            if let Some(target) = lookup_node(index, &edge_ref.target) {
+                *counts.entry((target.id.as_str(), edge_type)).or_default() += 1;
+            }
+        }
+    }
+    counts
+}
+
+fn field_value<'a>(node: &'a Value, field: &str) -> Option<&'a Value> {
+    node.get(field)
+        .or_else(|| node.get("meta").and_then(|m| m.get(field)))
+        .filter(|v| !v.is_null())
+}
+
+/// How many values a field holds: list length, or 1 for any non-empty value.
+fn count_items(value: &Value) -> usize {
+    match value {
+        Value::Null => 0,
+        Value::String(s) if s.trim().is_empty() => 0,
+        Value::Array(items) => items.len(),
+        Value::Object(map) => map.len(),
+        _ => 1,
+    }
+}
+
+/// Whether a node's field value satisfies a `when` value. Strings compare
+/// without regard to case so `priority: p0` and `status: Active` work.
+fn matches(actual: &Value, expected: &Value) -> bool {
+    match (actual, expected) {
+        (Value::Array(items), _) => items.iter().any(|item| matches(item, expected)),
+        (_, Value::Array(options)) => options.iter().any(|option| matches(actual, option)),
+        (Value::String(a), Value::String(e)) => a.eq_ignore_ascii_case(e),
+        (Value::Number(a), Value::Number(e)) => a.as_f64() == e.as_f64(),
+        _ => actual == expected,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policies(yaml: &str) -> Vec<LintPolicy> {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn index(yamls: &[&str]) -> NodeIndex {
+        yamls
+            .iter()
+            .map(|y| {
+                let node: LatticeNode = serde_yaml::from_str(y).unwrap();
+                (node.id.clone(), node)
+            })
+            .collect()
+    }
+
+    const HEADER: &str =
+        "body: B\nstatus: active\nversion: '1.0.0'\ncreated_at: '2026-01-01'\ncreated_by: test\n";
+
+    #[test]
+    fn test_policy_violations() {
+        let index = index(&[
+            &format!("id: REQ-A\ntype: requirement\ntitle: A\npriority: P0\n{HEADER}"),
+            &format!(
+                "id: REQ-B\ntype: requirement\ntitle: B\npriority: P0\n{HEADER}acceptance:\n  - id: AT-1\n    given: g\n    when: w\n    then: x\n"
+            ),
+            &format!("id: REQ-C\ntype: requirement\ntitle: C\npriority: P2\n{HEADER}"),
+            &format!(
+                "id: THX-RISK\ntype: thesis\ntitle: R\n{HEADER}meta:\n  category: risk\nedges:\n  supported_by:\n    - target: SRC-A\n      version: '1.0.0'\n"
+            ),
+            &format!("id: SRC-A\ntype: source\ntitle: S\n{HEADER}"),
+            &format!(
+                "id: IMP-A\ntype: implementation\ntitle: I\n{HEADER}meta:\n  test_command: cargo test\n"
+            ),
+            &format!(
+                "id: IMP-B\ntype: implementation\ntitle: I\n{HEADER}meta:\n  language: rust\n"
+            ),
+        ]);
+        let policies = policies(
+            "- id: p0-acceptance\n  type: requirement\n  when: { priority: p0 }\n  require: [{ field: acceptance }]\n  message: P0 needs acceptance\n  severity: error\n\
+             - id: risk-sources\n  type: thesis\n  when: { category: risk }\n  require: [{ edge: supported_by, min: 2 }]\n  message: Risk needs sources\n\
+             - id: impl-tests\n  type: implementation\n  require: [{ field: test_command }]\n  message: Set test_command\n\
+             - id: cited\n  type: source\n  require: [{ edge: supported_by, inbound: true }]\n  message: Cite sources\n",
+        );
+        assert!(validate_policies(&policies).is_empty());
+        assert_eq!(policies[0].severity, RuleLevel::Error);
+        assert_eq!(policies[1].severity, RuleLevel::Warning);
+
+        let found: Vec<Vec<(String, String)>> = policies
+            .iter()
+            .map(|p| policy_violations(p, &index))
+            .collect();
+        assert_eq!(
+            found[0],
+            vec![("REQ-A".to_string(), "'acceptance': 0, needs 1".to_string())]
+        );
+        assert_eq!(
+            found[1],
+            vec![(
+                "THX-RISK".to_string(),
+                "'supported_by' edges: 1, needs 2".to_string()
+            )]
+        );
+        assert_eq!(
+            found[2],
+            vec![(
+                "IMP-B".to_string(),
+                "'test_command': 0, needs 1".to_string()
+            )]
+        );
+        assert!(found[3].is_empty());
+    }
+
+    #[test]
+    fn test_validate_policies() {
+        let policies = policies(
+            "- id: orphan-node\n  require: [{ field: title }]\n  message: m\n\
+             - id: mine\n  require: []\n  message: m\n\
+             - id: mine\n  require: [{ edge: cites }, { field: a, edge: satisfies }, { field: a, inbound: true }]\n  message: m\n",
+        );
+        let index = index(&[&format!("id: REQ-A\ntype: requirement\ntitle: A\n{HEADER}")]);
+        assert!(policy_violations(&policies[2], &index).is_empty());
+        assert_eq!(
+            validate_policies(&policies),
+            vec![
+                "lint.policies[0] 'orphan-node' reuses a built-in rule ID",
+                "lint.policies[1] 'mine' has no require checks",
+                "lint.policies[2] 'mine' is defined more than once",
+                "lint.policies[2] 'mine': unknown edge type 'cites'",
+                "lint.policies[2] 'mine': each check needs exactly one of 'field' or 'edge'",
+                "lint.policies[2] 'mine': 'inbound' only applies to edge checks",
+            ]
+        );
+    }
+}
@@ -0,0 +1,473 @@
+//! Requirement text quality rules, after the INCOSE Guide to Writing
+//! Requirements and EARS: vague wording, missing acceptance tests, passive
+//! voice without an actor, several obligations in one requirement, and long
+//! titles.
+//!
+//! The checks are wording heuristics, so every rule is off by default.
+//! Turn them on under `lint.rules` in config.yaml, and combine with
+//! `lattice lint --changed` to hold only new work to the bar:
+//!
+//! ```yaml
+//! lint:
+//!   rules:
+//!     req-weak-words: warning
+//!     req-missing-acceptance: error
+//! ```
+
+use crate::lint::LintIssue;
+use crate::types::{LatticeNode, NodeType, Status};
+use std::path::PathBuf;
+
+/// Longest title, in characters, before `req-title-length` fires.
+pub const MAX_TITLE_LEN: usize = 80;
+
+/// Vague words and phrases, each with how to make it verifiable.
+const WEAK_WORDS: &[(&str, &str)] = &[
+    ("should", "state the obligation with 'shall' or 'must'"),
+    ("may", "say whether it is required ('shall') or not needed"),
+    ("etc", "list every case explicitly"),
+    ("and so on", "list every case explicitly"),
+    ("and/or", "say whether both, either or exactly one apply"),
+    ("fast", "give a measurable bound, e.g. a time limit"),
+    ("quickly", "give a measurable bound, e.g. a time limit"),
+    ("as possible", "give a measurable bound"),
+    ("minimal", "give a measurable bound"),
+    ("efficient", "give a measurable resource or time bound"),
+    ("easy", "name the user task and how success is measured"),
+    (
+        "user-friendly",
+        "name the user task and how success is measured",
+    ),
+    (
+        "intuitive",
+        "name the user task and how success is measured",
+    ),
+    ("simple", "name the user task and how success is measured"),
+    ("robust", "name the failure conditions it must handle"),
+    ("flexible", "name the variations it must support"),
+    (
+        "appropriate",
+        "state the criterion that decides what is appropriate",
+    ),
+    (
+        "adequate",
+        "state the criterion that decides what is adequate",
+    ),
+    (
+        "sufficient",
+        "state the criterion that decides what is sufficient",
+    ),
+    ("as needed", "state the condition under which it is needed"),
+    ("if possible", "say whether it is required"),
+    ("normally", "state the conditions explicitly"),
+    ("usually", "state the conditions explicitly"),
+    ("some", "give the quantity"),
+    ("several", "give the quantity"),
+    ("various", "list them"),
+    (
+        "tbd",
+        "resolve the open point before the requirement goes active",
+    ),
+];
+
+/// Verbs that introduce an obligation. "will" is left out: it is as often
+/// a statement of fact as a requirement.
+const MODALS: &[&str] = &["shall", "must", "should"];
+
+/// Common requirement verbs. A conjunct starting with one of these is a new
+/// verb phrase ("validate input and log errors"), not a second object
+/// ("return nodes and edges").
+const ACTION_VERBS: &[&str] = &[
+    "accept",
+    "add",
+    "alert",
+    "allow",
+    "apply",
+    "archive",
+    "authenticate",
+    "block",
+    "build",
+    "cache",
+    "calculate",
+    "check",
+    "clear",
+    "close",
+    "collect",
+    "compare",
+    "compute",
+    "create",
+    "delete",
+    "deny",
+    "detect",
+    "disable",
+    "display",
+    "download",
+    "emit",
+    "enable",
+    "encrypt",
+    "ensure",
+    "exit",
+    "export",
+    "fail",
+    "fetch",
+    "filter",
+    "generate",
+    "handle",
+    "hide",
+    "import",
+    "keep",
+    "load",
+    "lock",
+    "log",
+    "merge",
+    "monitor",
+    "notify",
+    "open",
+    "parse",
+    "persist",
+    "print",
+    "prompt",
+    "provide",
+    "publish",
+    "read",
+    "rebuild",
+    "record",
+    "reject",
+    "reload",
+    "remove",
+    "render",
+    "request",
+    "reset",
+    "resolve",
+    "restart",
+    "restore",
+    "retry",
+    "return",
+    "run",
+    "save",
+    "scan",
+    "schedule",
+    "search",
+    "send",
+    "show",
+    "sort",
+    "start",
+    "stop",
+    "store",
+    "sync",
+    "track",
+    "transform",
+    "update",
+    "upload",
+    "validate",
+    "verify",
+    "warn",
+    "write",
+];
+
+const BE_FORMS: &[&str] = &["be", "is", "are", "was", "were", "been", "being"];
+
+/// Common irregular past participles; regular ones end in "-ed".
+const IRREGULAR_PARTICIPLES: &[&str] = &[
+    "built", "done", "drawn", "found", "given", "held", "kept", "known", "left", "made", "met",
+    "paid", "put", "read", "run", "seen", "sent", "set", "shown", "sold", "taken", "told",
+    "thrown", "written",
+];
+
+/// Quality issues for one node. Only active requirements are checked:
+/// resolved, deprecated and superseded ones are left alone.
+pub fn quality_issues(node: &LatticeNode) -> Vec<LintIssue> {
+    if node.node_type != NodeType::Requirement
+        || node.resolution.is_some()
+        || matches!(node.status, Status::Deprecated | Status::Superseded)
+    {
+        return Vec::new();
+    }
+    let issue = |rule: &'static str, message: String, suggestion: &str| {
+        LintIssue::new(
+            rule,
+            PathBuf::from(format!("<{}>", node.id)),
+            Some(node.id.clone()),
+            message,
+        )
+        .suggest(suggestion)
+    };
+    let mut issues = Vec::new();
+
+    for (field, text) in [("title", &node.title), ("body", &node.body)] {
+        let text = text.to_lowercase();
+        for (word, suggestion) in WEAK_WORDS {
+            if contains_phrase(&text, word) {
+                issues.push(
+                    issue(
+                        "req-weak-words",
+                        format!("Weak wording '{}' in {}", word, field),
+                        &format!("Replace '{}': {}", word, suggestion),
+                    )
+                    .at_field(field),
+                );
+            }
+        }
+    }
+
+    if node.acceptance.as_ref().is_none_or(|a| a.is_empty()) {
+        issues.push(
+            issue(
+                "req-missing-acceptance",
+                "No acceptance tests".to_string(),
+                "Add acceptance entries with given/when/then that show the requirement is met",
+            )
+            .at_field("id"),
+        );
+    }
+
+    let sentences = sentences(&node.body);
+    if let Some(phrase) = sentences.iter().find_map(|s| passive_without_actor(s)) {
+        issues.push(
+            issue(
+                "req-passive-voice",
+                format!("Passive voice without an actor: '{}'", phrase),
+                "Name who acts, e.g. 'The system shall <verb> ...' or 'The user can ...'",
+            )
+            .at_field("body"),
+        );
+    }
+
+    if let Some((modal, verbs)) = sentences.iter().find_map(|s| compound_obligation(s)) {
+        issues.push(
+            issue(
+                "req-compound",
+                format!(
+                    "'{}' joins {} obligations ({}); a requirement should hold one",
+                    modal,
+                    verbs.len(),
+                    verbs.join(", ")
+                ),
+                "Split into one requirement per obligation, linked with depends_on or extends",
+            )
+            .at_field("body"),
+        );
+    }
+
+    let title_len = node.title.chars().count();
+    if title_len > MAX_TITLE_LEN {
+        issues.push(
+            issue(
+                "req-title-length",
+                format!("Title is {} characters (max {})", title_len, MAX_TITLE_LEN),
+                "Shorten the title to a short noun phrase and move the detail into the body",
+            )
+            .at_field("title"),
+        );
+    }
+
+    issues
+}
+
+/// Whether lowercase `text` contains `phrase` as whole words.
+fn contains_phrase(text: &str, phrase: &str) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    text.match_indices(phrase).any(|(start, _)| {
+        let end = start + phrase.len();
+        !text[..start].ends_with(is_word) && !text[end..].starts_with(is_word)
+    })
+}
+
+/// Sentences of a body, with line breaks folded into spaces.
+fn sentences(body: &str) -> Vec<String> {
+    body.split(['.', '!', '?', ';'])
+        .map(|s| s.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Lowercase word tokens; hyphens and slashes stay inside words.
+fn words(sentence: &str) -> Vec<String> {
+    sentence
+        .split(|c: char| !(c.is_alphanumeric() || c == '-' || c == '/' || c == '_'))
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// The first "be + past participle" phrase of a sentence with no "by"
+/// naming who acts, e.g. "is validated".
+fn passive_without_actor(sentence: &str) -> Option<String> {
+    let words = words(sentence);
+    if words.iter().any(|w| w == "by") {
+        return None;
+    }
+    words.iter().enumerate().find_map(|(i, be)| {
+        if !BE_FORMS.contains(&be.as_str()) {
+            return None;
+        }
+        // Allow one adverb between, as in "is automatically saved"
+        let mut j = i + 1;
+        if words.get(j).is_some_and(|w| w.ends_with("ly")) {
+            j += 1;
+        }
+        let participle = words.get(j)?;
+        let is_participle = (participle.len() > 4 && participle.ends_with("ed"))
+            || IRREGULAR_PARTICIPLES.contains(&participle.as_str());
+        is_participle.then(|| words[i..=j].join(" "))
+    })
+}
+
+/// The modal and its verbs when one sentence joins several verb phrases
+/// with "and"/"or" under a single obligation, e.g. ("shall", [validate, log,
+/// retry]) for "The CLI shall validate input and log errors and retry".
+fn compound_obligation(sentence: &str) -> Option<(String, Vec<String>)> {
+    let words = words(sentence);
+    let modal = words.iter().position(|w| MODALS.contains(&w.as_str()))?;
+    // Skip "not", "also", "then" and adverbs to reach a verb
+    let verb_at = |mut i: usize| {
+        while words
+            .get(i)
+            .is_some_and(|w| matches!(w.as_str(), "not" | "also" | "then") || w.ends_with("ly"))
+        {
+            i += 1;
+        }
+        i
+    };
+
+    let first = verb_at(modal + 1);
+    let mut verbs = vec![words.get(first)?.clone()];
+    for (i, word) in words.iter().enumerate().skip(first + 1) {
+        if !matches!(word.as_str(), "and" | "or" | "and/or") {
+            continue;
+        }
+        let mut next = verb_at(i + 1);
+        // "... and must log ..." restates the obligation
+        let restated = words
+            .get(next)
+            .is_some_and(|w| MODALS.contains(&w.as_str()));
+        if restated {
+            next = verb_at(next + 1);
+        }
+        if let Some(verb) = words.get(next)
+            && (restated || ACTION_VERBS.contains(&verb.as_str()))
+        {
+            verbs.push(verb.clone());
+        }
+    }
+    (verbs.len() > 1).then(|| (words[modal].clone(), verbs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn requirement(title: &str, body: &str, acceptance: bool) -> LatticeNode {
+        let mut yaml = format!(
+            "id: REQ-Q\ntype: requirement\ntitle: \"{title}\"\nbody: \"{body}\"\nstatus: active\nversion: '1.0.0'\ncreated_at: '2026-01-01'\ncreated_by: test\npriority: P1\n"
+        );
+        if acceptance {
+            yaml.push_str("acceptance:\n  - id: AT-1\n    given: g\n    when: w\n    then: t\n");
+        }
+        serde_yaml::from_str(&yaml).unwrap()
+    }
+
+    fn rules(node: &LatticeNode) -> Vec<(String, String)> {
+        quality_issues(node)
+            .into_iter()
+            .map(|i| (i.rule, i.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_quality_issues() {
+        let clean = requirement(
+            "Search returns ranked results",
+            "The CLI shall return search results within 200 ms for 10,000 nodes.",
+            true,
+        );
+        assert!(quality_issues(&clean).is_empty());
+
+        let vague = requirement(
+            "Search should be fast, easy, user-friendly and flexible enough for every kind of query etc",
+            "Results are cached.\nThe CLI shall stay user-friendly and rebuild the index on change.",
+            false,
+        );
+        let found = rules(&vague);
+        let weak: Vec<&str> = found
+            .iter()
+            .filter(|(r, _)| r == "req-weak-words")
+            .map(|(_, m)| m.as_str())
+            .collect();
+        assert_eq!(
+            weak,
+            vec![
+                "Weak wording 'should' in title",
+                "Weak wording 'etc' in title",
+                "Weak wording 'fast' in title",
+                "Weak wording 'easy' in title",
+                "Weak wording 'user-friendly' in title",
+                "Weak wording 'flexible' in title",
+                "Weak wording 'user-friendly' in body",
+            ]
+        );
+        let other: Vec<&(String, String)> = found
+            .iter()
+            .filter(|(r, _)| r != "req-weak-words")
+            .collect();
+        assert_eq!(other[0].0, "req-missing-acceptance");
+        assert_eq!(other[1].1, "Passive voice without an actor: 'are cached'");
+        assert_eq!(
+            other[2].1,
+            "'shall' joins 2 obligations (stay, rebuild); a requirement should hold one"
+        );
+        assert_eq!(other[3].1, "Title is 90 characters (max 80)");
+        assert!(
+            quality_issues(&vague)
+                .iter()
+                .all(|i| i.suggestion.is_some() && i.anchor.is_some())
+        );
+    }
+
+    #[test]
+    fn test_passive_and_phrases() {
+        assert_eq!(
+            passive_without_actor("The node is automatically saved"),
+            Some("is automatically saved".to_string())
+        );
+        assert_eq!(passive_without_actor("Errors are shown by the CLI"), None);
+        assert_eq!(passive_without_actor("The CLI is ready"), None);
+        assert!(contains_phrase("it may, if possible, work", "if possible"));
+        assert!(!contains_phrase("somewhere maybe", "some"));
+        assert!(!contains_phrase("somewhere maybe", "may"));
+
+        assert_eq!(
+            compound_obligation("The CLI shall validate input and log errors and retry"),
+            Some((
+                "shall".to_string(),
+                vec![
+                    "validate".to_string(),
+                    "log".to_string(),
+                    "retry".to_string()
+                ]
+            ))
+        );
+        assert_eq!(
+            compound_obligation("It must read the file and then must not write it"),
+            Some((
+                "must".to_string(),
+                vec!["read".to_string(), "write".to_string()]
+            ))
+        );
+        assert_eq!(
+            compound_obligation("The export shall return nodes and edges"),
+            None
+        );
+        // One obligation per sentence, and "will" describes rather than requires
+        let split = requirement(
+            "Export",
+            "The CLI shall export JSON. It must exit with status 0. Readers will load it and render it.",
+            true,
+        );
+        assert!(rules(&split).iter().all(|(r, _)| r != "req-compound"));
+
+        let mut deprecated = requirement("Fast", "Should be quick", false);
+        deprecated.status = Status::Deprecated;
+        assert!(quality_issues(&deprecated).is_empty());
+    }
+}
@@ -0,0 +1,579 @@
+//! Graph query language for ad hoc questions over the lattice.
+//!
+//! A query names a node type and an optional `where` clause combining field
+//! predicates and edge patterns with `and`, `or`, `not` and parentheses:
+//!
+//! ```text
+//! requirements where priority = P0
+//!     and not <-satisfies implementation
+//!     and ->derives_from thesis(status = contested)
+//! ```
+//!
+//! - Node types: `sources`, `theses`, `requirements`, `implementations`,
+//!   `messages` (singular forms work too), or `nodes` / `*` for any type.
+//! - Field predicates: `FIELD = VALUE`, `FIELD != VALUE`, `FIELD ~ VALUE`
+//!   (case-insensitive contains). Fields: id, title, body, type, status,
+//!   priority, resolution, category, tag, version, created_by; `id` also
+//!   matches former IDs left by `lattice rename`. `none` matches
+//!   an absent priority, resolution or category; `resolution = unresolved`
+//!   is the same as `resolution = none`.
+//! - Edge patterns: `->EDGE [TYPE] [(WHERE)]` matches nodes with an outgoing
+//!   edge of that type to a node of that type satisfying the nested clause;
+//!   `<-EDGE ...` matches nodes some such node points at. `any` matches
+//!   every edge type.
+
+use crate::graph::{ReverseIndex, build_reverse_index, lookup_node};
+use crate::storage::EDGE_TYPES;
+use crate::types::{LatticeNode, NodeIndex, NodeType};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum QueryError {
+    #[error("query syntax error at column {column}: {message}")]
+    Syntax { column: usize, message: String },
+}
+
+/// Fields a predicate can test.
+const FIELDS: &[&str] = &[
+    "id",
+    "title",
+    "body",
+    "type",
+    "status",
+    "priority",
+    "resolution",
+    "category",
+    "tag",
+    "version",
+    "created_by",
+];
+
+/// A parsed query: which nodes to consider and the clause they must satisfy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    /// `None` matches every node type.
+    pub node_type: Option<NodeType>,
+    pub filter: Option<Expr>,
+}
+
+/// Comparison operator in a field predicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Eq,
+    Ne,
+    Contains,
+}
+
+/// Edge direction relative to the node being tested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Outgoing,
+    Incoming,
+}
+
+/// A `where` clause.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Field {
+        field: String,
+        op: Op,
+        value: String,
+    },
+    Edge {
+        direction: Direction,
+        /// `None` matches every edge type.
+        edge_type: Option<String>,
+        /// `None` matches every node type at the other end.
+        node_type: Option<NodeType>,
+        filter: Option<Box<Expr>>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Str(String),
+    LParen,
+    RParen,
+    Out,
+    In,
+    Eq,
+    Ne,
+    Tilde,
+}
+
+fn syntax(column: usize, message: impl Into<String>) -> QueryError {
+    QueryError::Syntax {
+        column,
+        message: message.into(),
+    }
+}
+
+/// Split a query into tokens, each with its 1-based column.
+fn tokenize(input: &str) -> Result<Vec<(Token, usize)>, QueryError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some(&(i, c)) = chars.peek() {
+        let column = i + 1;
+        let mut two = |next: char, token: Token| {
+            chars.next();
+            if chars.peek().map(|&(_, c)| c) == Some(next) {
+                chars.next();
+                Ok((token, column))
+            } else {
+                Err(syntax(column, format!("expected '{}' after '{}'", next, c)))
+            }
+        };
+        let token = match c {
+            c if c.is_whitespace() => {
+                chars.next();
+                continue;
+            }
+            '(' => {
+                chars.next();
+                (Token::LParen, column)
+            }
+            ')' => {
+                chars.next();
+                (Token::RParen, column)
+            }
+            '=' => {
+                chars.next();
+                (Token::Eq, column)
+            }
+            '~' => {
+                chars.next();
+                (Token::Tilde, column)
+            }
+            '!' => two('=', Token::Ne)?,
+            '<' => two('-', Token::In)?,
+            '-' if input[i..].starts_with("->") => two('>', Token::Out)?,
+            '"' | '\'' => {
+                chars.next();
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, ch)) if ch == c => break,
+                        Some((_, ch)) => value.push(ch),
+                        None => return Err(syntax(column, "unterminated string")),
+                    }
+                }
+                (Token::Str(value), column)
+            }
+            c if c.is_alphanumeric() || "_-.*:@/".contains(c) => {
+                let mut word = String::new();
+                while let Some(&(_, ch)) = chars.peek() {
+                    if ch.is_alphanumeric() || "_-.*:@/".contains(ch) {
+                        word.push(ch);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                (Token::Word(word), column)
+            }
+            other => return Err(syntax(column, format!("unexpected character '{}'", other))),
+        };
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+/// Node type named by a query word; `Some(None)` means any type.
+fn parse_node_type(word: &str) -> Option<Option<NodeType>> {
+    match word.to_lowercase().as_str() {
+        "source" | "sources" => Some(Some(NodeType::Source)),
+        "thesis" | "theses" => Some(Some(NodeType::Thesis)),
+        "requirement" | "requirements" => Some(Some(NodeType::Requirement)),
+        "implementation" | "implementations" => Some(Some(NodeType::Implementation)),
+        "message" | "messages" => Some(Some(NodeType::Message)),
+        "node" | "nodes" | "*" => Some(None),
+        _ => None,
+    }
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    end_column: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn column(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .map_or(self.end_column, |(_, col)| *col)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).map(|(t, _)| t.clone());
+        self.pos += 1;
+        token
+    }
+
+    fn keyword(&mut self, keyword: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Word(w)) if w.eq_ignore_ascii_case(keyword)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: Token, what: &str) -> Result<(), QueryError> {
+        let column = self.column();
+        if self.next().as_ref() == Some(&token) {
+            Ok(())
+        } else {
+            Err(syntax(column, format!("expected {}", what)))
+        }
+    }
+
+    fn or_expr(&mut self) -> Result<Expr, QueryError> {
+        let mut left = self.and_expr()?;
+        while self.keyword("or") {
+            left = Expr::Or(Box::new(left), Box::new(self.and_expr()?));
+        }
+        Ok(left)
+    }
+
+    fn and_expr(&mut self) -> Result<Expr, QueryError> {
+        let mut left = self.unary()?;
+        while self.keyword("and") {
+            left = Expr::And(Box::new(left), Box::new(self.unary()?));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Expr, QueryError> {
+        if self.keyword("not") {
+            return Ok(Expr::Not(Box::new(self.unary()?)));
+        }
+        let column = self.column();
+        match self.next() {
+            Some(Token::LParen) => {
+                let expr = self.or_expr()?;
+                self.expect(Token::RParen, "')'")?;
+                Ok(expr)
+            }
+            Some(Token::Out) => self.edge(Direction::Outgoing),
+            Some(Token::In) => self.edge(Direction::Incoming),
+            Some(Token::Word(field)) => self.predicate(field.to_lowercase(), column),
+            _ => Err(syntax(
+                column,
+                "expected a field predicate, edge pattern (->edge or <-edge), 'not' or '('",
+            )),
+        }
+    }
+
+    fn predicate(&mut self, field: String, column: usize) -> Result<Expr, QueryError> {
+        let field = if field == "tags" {
+            "tag".to_string()
+        } else {
+            field
+        };
+        if !FIELDS.contains(&field.as_str()) {
+            return Err(syntax(
+                column,
+                format!("unknown field '{}' (fields: {})", field, FIELDS.join(", ")),
+            ));
+        }
+
+        let op_column = self.column();
+        let op = match self.next() {
+            Some(Token::Eq) => Op::Eq,
+            Some(Token::Ne) => Op::Ne,
+            Some(Token::Tilde) => Op::Contains,
+            _ => return Err(syntax(op_column, "expected '=', '!=' or '~'")),
+        };
+
+        let value_column = self.column();
+        match self.next() {
+            Some(Token::Word(value)) | Some(Token::Str(value)) => {
+                Ok(Expr::Field { field, op, value })
+            }
+            _ => Err(syntax(value_column, "expected a value")),
+        }
+    }
+
+    fn edge(&mut self, direction: Direction) -> Result<Expr, QueryError> {
+        let column = self.column();
+        let edge_type = match self.next() {
+            Some(Token::Word(w)) if w.eq_ignore_ascii_case("any") => None,
+            Some(Token::Word(w)) if EDGE_TYPES.contains(&w.as_str()) => Some(w),
+            _ => {
+                return Err(syntax(
+                    column,
+                    format!("expected an edge type or 'any' ({})", EDGE_TYPES.join(", ")),
+                ));
+            }
+        };
+
+        let mut node_type = None;
+        if let Some(Token::Word(w)) = self.peek()
+            && let Some(parsed) = parse_node_type(w)
+        {
+            node_type = parsed;
+            self.pos += 1;
+        }
+
+        let filter = if self.peek() == Some(&Token::LParen) {
+            self.pos += 1;
+            let expr = self.or_expr()?;
+            self.expect(Token::RParen, "')'")?;
+            Some(Box::new(expr))
+        } else {
+            None
+        };
+
+        Ok(Expr::Edge {
+            direction,
+            edge_type,
+            node_type,
+            filter,
+        })
+    }
+}
+
+/// Parse a query string.
+pub fn parse_query(input: &str) -> Result<Query, QueryError> {
+    let mut parser = Parser {
+        tokens: tokenize(input)?,
+        pos: 0,
+        end_column: input.len() + 1,
+    };
+
+    let column = parser.column();
+    let node_type = match parser.next() {
+        Some(Token::Word(w)) => parse_node_type(&w).ok_or_else(|| {
+            syntax(
+                column,
+                format!(
+                    "unknown node type '{}' (use sources, theses, requirements, implementations, messages or nodes)",
+                    w
+                ),
+            )
+        })?,
+        _ => return Err(syntax(column, "expected a node type")),
+    };
+
+    let filter = if parser.keyword("where") {
+        Some(parser.or_expr()?)
+    } else {
+        None
+    };
+
+    if parser.peek().is_some() {
+        return Err(syntax(parser.column(), "unexpected input after query"));
+    }
+
+    Ok(Query { node_type, filter })
+}
+
+/// Nodes in the index matching the query, sorted by ID.
+pub fn run_query<'a>(query: &Query, index: &'a NodeIndex) -> Vec<&'a LatticeNode> {
+    let reverse = build_reverse_index(index);
+    let mut matches: Vec<&LatticeNode> = index
+        .values()
+        .filter(|n| query.node_type.as_ref().is_none_or(|t| n.node_type == *t))
+        .filter(|n| {
+            query
+                .filter
+                .as_ref()
+                .is_none_or(|f| matches(f, n, index, &reverse))
+        })
+        .collect();
+    matches.sort_by(|a, b| a.id.cmp(&b.id));
+    matches
+}
+
+fn matches(expr: &Expr, node: &LatticeNode, index: &NodeIndex, reverse: &ReverseIndex) -> bool {
+    match expr {
+        Expr::And(a, b) => matches(a, node, index, reverse) && matches(b, node, index, reverse),
+        Expr::Or(a, b) => matches(a, node, index, reverse) || matches(b, node, index, reverse),
+        Expr::Not(e) => !matches(e, node, index, reverse),
+        Expr::Field { field, op, value } => field_matches(node, field, *op, value),
+        Expr::Edge {
+            direction,
+            edge_type,
+            node_type,
+            filter,
+        } => {
+            let wanted = |ty: &str| edge_type.as_deref().is_none_or(|e| e == ty);
+            let other_matches = |other: &LatticeNode| {
+                node_type.as_ref().is_none_or(|t| other.node_type == *t)
+                    && filter
+                        .as_ref()
+                        .is_none_or(|f| matches(f, other, index, reverse))
+            };
+            match direction {
+                Direction::Outgoing => node.typed_edges().into_iter().any(|(ty, edge)| {
+                    wanted(ty) && lookup_node(index, &edge.target).is_some_and(other_matches)
+                }),
+                Direction::Incoming => reverse.get(&node.id).into_iter().flatten().any(|r| {
+                    wanted(&r.edge_type) && index.get(&r.from_id).is_some_and(other_matches)
+                }),
+            }
+        }
+    }
+}
+
+/// Values a field holds; several for tags, none when the field is absent.
+fn field_values(node: &LatticeNode, field: &str) -> Vec<String> {
+    let lower = |s: String| vec![s.to_lowercase()];
+    match field {
+        "id" => std::iter::once(node.id.clone())
+            .chain(node.aliases.iter().flatten().cloned())
+            .collect(),
+        "title" => vec![node.title.clone()],
+        "body" => vec![node.body.clone()],
+        "type" => lower(format!("{:?}", node.node_type)),
+        "status" => lower(format!("{:?}", node.status)),
+        "priority" => node
+            .priority
+            .as_ref()
+            .map_or_else(Vec::new, |p| vec![format!("{:?}", p)]),
+        "resolution" => node
+            .resolution
+            .as_ref()
+            .map_or_else(Vec::new, |r| lower(format!("{:?}", r.status))),
+        "category" => node.category.clone().into_iter().collect(),
+        "tag" => node.tags.clone().unwrap_or_default(),
+        "version" => vec![node.version.clone()],
+        "created_by" => vec![node.created_by.clone()],
+        _ => Vec::new(),
+    }
+}
+
+fn field_matches(node: &LatticeNode, field: &str, op: Op, value: &str) -> bool {
+    let values = field_values(node, field);
+    let value = value.to_lowercase();
+    let absent = value == "none" || (field == "resolution" && value == "unresolved");
+    let value = match field {
+        "type" => parse_node_type(&value)
+            .flatten()
+            .map_or(value, |t| format!("{:?}", t).to_lowercase()),
+        _ => value,
+    };
+
+    let equal = if absent {
+        values.is_empty()
+    } else {
+        values.iter().any(|v| v.to_lowercase() == value)
+    };
+    match op {
+        Op::Eq => equal,
+        Op::Ne => !equal,
+        Op::Contains => values.iter().any(|v| v.to_lowercase().contains(&value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::*;
+
+    /// THX-OK (active) and THX-BAD (contested); REQ-A and REQ-B are P0 and
+    /// derive from THX-BAD, REQ-C is P1; IMP-B satisfies REQ-B.
+    fn sample_index() -> NodeIndex {
+        let bad = LatticeNode {
+            status: Status::Contested,
+            ..LatticeNode::test_node("THX-BAD", NodeType::Thesis)
+        };
+        let mut nodes = vec![LatticeNode::test_node("THX-OK", NodeType::Thesis), bad];
+        for (id, priority, thesis) in [
+            ("REQ-A", Priority::P0, "THX-BAD"),
+            ("REQ-B", Priority::P0, "THX-BAD"),
+            ("REQ-C", Priority::P1, "THX-OK"),
+        ] {
+            nodes.push(LatticeNode {
+                priority: Some(priority),
+                tags: Some(vec!["auth".to_string()]),
+                edges: Some(Edges {
+                    derives_from: Some(vec![EdgeReference::test_edge(thesis, "1.0.0")]),
+                    ..Default::default()
+                }),
+                ..LatticeNode::test_node(id, NodeType::Requirement)
+            });
+        }
+        nodes.push(LatticeNode {
+            edges: Some(Edges {
+                satisfies: Some(vec![EdgeReference::test_edge("REQ-B", "1.0.0")]),
+                ..Default::default()
+            }),
+            ..LatticeNode::test_node("IMP-B", NodeType::Implementation)
+        });
+        nodes.into_iter().map(|n| (n.id.clone(), n)).collect()
+    }
+
+    fn ids(query: &str, index: &NodeIndex) -> Vec<String> {
+        let query = parse_query(query).unwrap();
+        run_query(&query, index)
+            .iter()
+            .map(|n| n.id.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_query_unsatisfied_p0_from_contested_thesis() {
+        let index = sample_index();
+        assert_eq!(
+            ids(
+                "requirements where priority = P0 and not <-satisfies implementation \
+                 and ->derives_from thesis(status = contested)",
+                &index
+            ),
+            vec!["REQ-A"]
+        );
+    }
+
+    #[test]
+    fn test_query_fields_and_boolean_operators() {
+        let index = sample_index();
+        assert_eq!(ids("theses", &index), vec!["THX-BAD", "THX-OK"]);
+        assert_eq!(
+            ids("requirements where priority = p1 or id = REQ-A", &index),
+            vec!["REQ-A", "REQ-C"]
+        );
+        assert_eq!(
+            ids("nodes where tag = AUTH and not (priority = P0)", &index),
+            vec!["REQ-C"]
+        );
+        assert_eq!(ids("* where title ~ \"imp\"", &index), vec!["IMP-B"]);
+        assert_eq!(
+            ids("requirements where resolution = unresolved", &index).len(),
+            3
+        );
+        assert_eq!(ids("theses where priority != none", &index).len(), 0);
+        assert_eq!(
+            ids("theses where <-any requirement(priority = P1)", &index),
+            vec!["THX-OK"]
+        );
+    }
+
+    #[test]
+    fn test_parse_query_reports_errors_with_column() {
+        assert_eq!(
+            parse_query("widgets").unwrap_err(),
+            syntax(
+                1,
+                "unknown node type 'widgets' (use sources, theses, requirements, implementations, messages or nodes)"
+            )
+        );
+        let err = parse_query("requirements where colour = red").unwrap_err();
+        assert!(matches!(err, QueryError::Syntax { column: 20, .. }));
+        assert!(parse_query("requirements where ->likes thesis").is_err());
+        assert!(parse_query("requirements where (priority = P0").is_err());
+        assert!(parse_query("requirements where title = \"open").is_err());
+        assert!(parse_query("requirements extra").is_err());
+    }
+}
@@ -0,0 +1,467 @@
+//! SARIF 2.1.0 output for `lint`, `drift` and `health`, so findings can be
+//! uploaded to GitHub code scanning and other static-analysis dashboards.
+//!
+//! Each finding points at the node's YAML file and, where the offending
+//! field or edge can be found in it, the line it is on.
+
+use crate::graph::{DriftReport, DriftSeverity, IndirectDrift};
+use crate::lint::{
+    IssueAnchor, LINT_RULES, LintConfig, LintReport, LintRule, LintSeverity, RuleLevel,
+};
+use crate::storage::{EDGE_TYPES, LATTICE_DIR, find_node_path};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+pub const SARIF_VERSION: &str = "2.1.0";
+
+/// SARIF result level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SarifLevel {
+    Error,
+    Warning,
+    Note,
+    None,
+}
+
+impl SarifLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SarifLevel::Error => "error",
+            SarifLevel::Warning => "warning",
+            SarifLevel::Note => "note",
+            SarifLevel::None => "none",
+        }
+    }
+}
+
+impl From<&LintSeverity> for SarifLevel {
+    fn from(severity: &LintSeverity) -> Self {
+        match severity {
+            LintSeverity::Error => SarifLevel::Error,
+            LintSeverity::Warning => SarifLevel::Warning,
+        }
+    }
+}
+
+impl From<RuleLevel> for SarifLevel {
+    fn from(level: RuleLevel) -> Self {
+        match level {
+            RuleLevel::Off => SarifLevel::None,
+            RuleLevel::Warning => SarifLevel::Warning,
+            RuleLevel::Error => SarifLevel::Error,
+        }
+    }
+}
+
+/// A rule in the tool's rule table.
+#[derive(Debug, Clone)]
+pub struct SarifRule {
+    pub id: String,
+    pub description: String,
+    pub level: SarifLevel,
+}
+
+/// A single finding.
+#[derive(Debug, Clone)]
+pub struct SarifFinding {
+    pub rule_id: String,
+    pub level: SarifLevel,
+    pub message: String,
+    /// File the finding is in, absolute or relative to the lattice root.
+    pub file: PathBuf,
+    pub anchor: Option<IssueAnchor>,
+}
+
+/// Rule for direct drift findings.
+pub const DRIFT_RULE_ID: &str = "drift";
+/// Rule for nodes downstream of drift (`drift --transitive`).
+pub const INDIRECT_DRIFT_RULE_ID: &str = "indirect-drift";
+
+/// Rules for drift findings.
+pub fn drift_rules() -> Vec<SarifRule> {
+    vec![
+        SarifRule {
+            id: DRIFT_RULE_ID.to_string(),
+            description: "An edge is bound to an older version of its target".to_string(),
+            level: SarifLevel::Warning,
+        },
+        SarifRule {
+            id: INDIRECT_DRIFT_RULE_ID.to_string(),
+            description: "A node depends on a drifted node through other edges".to_string(),
+            level: SarifLevel::Note,
+        },
+    ]
+}
+
+/// Rule for theses with contested status (`health`).
+pub const CONTESTED_THESIS_RULE_ID: &str = "contested-thesis";
+/// Rule for bound code files changed without a lattice update (`health`).
+pub const BOUND_FILE_CHANGED_RULE_ID: &str = "bound-file-changed";
+/// Rule for a lattice older than the freshness threshold (`health`).
+pub const LATTICE_STALE_RULE_ID: &str = "lattice-stale";
+
+/// Rules for the signals `health` combines, besides drift and lint.
+pub fn health_rules() -> Vec<SarifRule> {
+    vec![
+        SarifRule {
+            id: CONTESTED_THESIS_RULE_ID.to_string(),
+            description: "A thesis is contested and awaits resolution".to_string(),
+            level: SarifLevel::Warning,
+        },
+        SarifRule {
+            id: BOUND_FILE_CHANGED_RULE_ID.to_string(),
+            description: "A file bound by an implementation changed since the last lattice update"
+                .to_string(),
+            level: SarifLevel::Warning,
+        },
+        SarifRule {
+            id: LATTICE_STALE_RULE_ID.to_string(),
+            description: "The lattice lags code changes by more than the freshness threshold"
+                .to_string(),
+            level: SarifLevel::Warning,
+        },
+    ]
+}
+
+/// Built-in lint rules and config policies at their configured levels.
+pub fn lint_rules(config: &LintConfig) -> Vec<SarifRule> {
+    let level = |id: &str| config.level(id).map_or(SarifLevel::None, SarifLevel::from);
+    LINT_RULES
+        .iter()
+        .map(|r| SarifRule {
+            id: r.id.to_string(),
+            description: r.description.to_string(),
+            level: level(r.id),
+        })
+        .chain(
+            config
+                .policies
+                .iter()
+                .filter(|p| LintRule::find(&p.id).is_none())
+                .map(|p| SarifRule {
+                    id: p.id.clone(),
+                    description: p.message.clone(),
+                    level: level(&p.id),
+                }),
+        )
+        .collect()
+}
+
+/// Findings for every issue in a lint report.
+pub fn lint_findings(root: &Path, report: &LintReport) -> Vec<SarifFinding> {
+    let lattice_dir = root.join(LATTICE_DIR);
+    report
+        .issues
+        .iter()
+        .map(|issue| {
+            // Cross-node issues carry a "<ID>" placeholder instead of a path
+            let file = match &issue.node_id {
+                Some(id) if !issue.file.starts_with(&lattice_dir) => {
+                    find_node_path(root, id).unwrap_or_else(|_| issue.file.clone())
+                }
+                _ => issue.file.clone(),
+            };
+            SarifFinding {
+                rule_id: issue.rule.clone(),
+                level: SarifLevel::from(&issue.severity),
+                message: match &issue.suggestion {
+                    Some(suggestion) => format!("{}\nSuggestion: {}", issue.message, suggestion),
+                    None => issue.message.clone(),
+                },
+                file,
+                anchor: issue.anchor.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Findings for drifted edges: major changes are errors, minor changes
+/// warnings and patch changes notes.
+pub fn drift_findings(root: &Path, reports: &[DriftReport]) -> Vec<SarifFinding> {
+    reports
+        .iter()
+        .flat_map(|report| {
+            let file = find_node_path(root, &report.node_id)
+                .unwrap_or_else(|_| PathBuf::from(LATTICE_DIR));
+            report.drift_items.iter().map(move |item| SarifFinding {
+                rule_id: DRIFT_RULE_ID.to_string(),
+                level: match item.severity {
+                    DriftSeverity::Major => SarifLevel::Error,
+                    DriftSeverity::Minor => SarifLevel::Warning,
+                    DriftSeverity::Patch => SarifLevel::Note,
+                },
+                message: format!(
+                    "{} is bound to {}@{}, which is now {} ({} change)",
+                    report.node_id,
+                    item.target_id,
+                    item.bound_version,
+                    item.current_version,
+                    format!("{:?}", item.severity).to_lowercase()
+                ),
+                file: file.clone(),
+                anchor: Some(IssueAnchor::Edge {
+                    edge_type: None,
+                    target: item.target_id.clone(),
+                }),
+            })
+        })
+        .collect()
+}
+
+/// Findings for nodes downstream of drift, as notes on each node's file.
+pub fn indirect_drift_findings(root: &Path, indirect: &[IndirectDrift]) -> Vec<SarifFinding> {
+    indirect
+        .iter()
+        .map(|d| SarifFinding {
+            rule_id: INDIRECT_DRIFT_RULE_ID.to_string(),
+            level: SarifLevel::Note,
+            message: format!(
+                "{} is {} hops from drifted {}: {}",
+                d.node_id,
+                d.hops,
+                d.source_id,
+                d.path.join(" -> ")
+            ),
+            file: find_node_path(root, &d.node_id).unwrap_or_else(|_| PathBuf::from(LATTICE_DIR)),
+            anchor: d.path.get(1).map(|next| IssueAnchor::Edge {
+                edge_type: None,
+                target: next.clone(),
+            }),
+        })
+        .collect()
+}
+
+/// Build a SARIF log with one run. Rules that no finding uses are kept so
+/// dashboards can show what was checked.
+pub fn sarif_log(
+    root: &Path,
+    rules: &[SarifRule],
+    findings: &[SarifFinding],
+    properties: Option<Value>,
+) -> Value {
+    let mut rules: Vec<SarifRule> = rules.to_vec();
+    for finding in findings {
+        if !rules.iter().any(|r| r.id == finding.rule_id) {
+            rules.push(SarifRule {
+                id: finding.rule_id.clone(),
+                description: finding.rule_id.clone(),
+                level: finding.level,
+            });
+        }
+    }
+
+    let mut contents: HashMap<PathBuf, Option<String>> = HashMap::new();
+    let results: Vec<Value> = findings
+        .iter()
+        .map(|finding| {
+            let path = if finding.file.is_absolute() {
+                finding.file.clone()
+            } else {
+                root.join(&finding.file)
+            };
+            let uri = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            let line = finding.anchor.as_ref().and_then(|anchor| {
+                contents
+                    .entry(path.clone())
+                    .or_insert_with(|| std::fs::read_to_string(&path).ok())
+                    .as_deref()
+                    .and_then(|content| anchor_line(content, anchor))
+            });
+
+            let mut location = json!({
+                "physicalLocation": {
+                    "artifactLocation": { "uri": uri, "uriBaseId": "%SRCROOT%" },
+                },
+            });
+            if let Some(line) = line {
+                location["physicalLocation"]["region"] = json!({ "startLine": line });
+            }
+            json!({
+                "ruleId": finding.rule_id,
+                "ruleIndex": rules.iter().position(|r| r.id == finding.rule_id),
+                "level": finding.level.as_str(),
+                "message": { "text": finding.message },
+                "locations": [location],
+            })
+        })
+        .collect();
+
+    let rules: Vec<Value> = rules
+        .iter()
+        .map(|r| {
+            json!({
+                "id": r.id,
+                "shortDescription": { "text": r.description },
+                "defaultConfiguration": { "level": r.level.as_str() },
+            })
+        })
+        .collect();
+
+    let mut run = json!({
+        "tool": {
+            "driver": {
+                "name": "lattice",
+                "version": env!("CARGO_PKG_VERSION"),
+                "informationUri": "https://github.com/forkzero/lattice",
+                "rules": rules,
+            }
+        },
+        "results": results,
+    });
+    if let Some(properties) = properties {
+        run["properties"] = properties;
+    }
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [run],
+    })
+}
+
+/// The 1-based line of a node file an anchor points at, if present.
+pub fn anchor_line(content: &str, anchor: &IssueAnchor) -> Option<usize> {
+    match anchor {
+        IssueAnchor::Line(line) => Some(*line),
+        IssueAnchor::Field(field) => content
+            .lines()
+            .position(|l| {
+                l.strip_prefix(field.as_str())
+                    .is_some_and(|rest| rest.starts_with(':'))
+            })
+            .map(|i| i + 1),
+        IssueAnchor::Edge { edge_type, target } => {
+            let mut current: Option<&str> = None;
+            for (i, line) in content.lines().enumerate() {
+                let trimmed = line.trim_start();
+                if let Some(key) = trimmed.strip_suffix(':')
+                    && EDGE_TYPES.contains(&key)
+                {
+                    current = Some(key);
+                    continue;
+                }
+                let Some(value) = trimmed
+                    .trim_start_matches("- ")
+                    .strip_prefix("target:")
+                    .map(|v| v.trim().trim_matches(|c| c == '"' || c == '\''))
+                else {
+                    continue;
+                };
+                if value == target
+                    && current.is_some()
+                    && edge_type.as_deref().is_none_or(|t| current == Some(t))
+                {
+                    return Some(i + 1);
+                }
+            }
+            None
+        }
+        IssueAnchor::Value { key, value } => content
+            .lines()
+            .position(|l| {
+                l.trim_start()
+                    .trim_start_matches("- ")
+                    .strip_prefix(key.as_str())
+                    .and_then(|rest| rest.strip_prefix(':'))
+                    .is_some_and(|v| v.trim().trim_matches(|c| c == '"' || c == '\'') == value)
+            })
+            .map(|i| i + 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NODE: &str = "id: REQ-A\ntype: requirement\ntitle: A\nversion: 1.0\nedges:\n  derives_from:\n  - target: THX-A\n    version: 1.0.0\n  depends_on:\n    - target: 'REQ-B'\n    - target: THX-A\n";
+
+    #[test]
+    fn test_anchor_line() {
+        let field = |f: &str| IssueAnchor::Field(f.to_string());
+        let edge = |t: Option<&str>, target: &str| IssueAnchor::Edge {
+            edge_type: t.map(str::to_string),
+            target: target.to_string(),
+        };
+        assert_eq!(anchor_line(NODE, &field("version")), Some(4));
+        assert_eq!(anchor_line(NODE, &field("priority")), None);
+        assert_eq!(anchor_line(NODE, &edge(None, "THX-A")), Some(7));
+        assert_eq!(
+            anchor_line(NODE, &edge(Some("depends_on"), "REQ-B")),
+            Some(10)
+        );
+        assert_eq!(
+            anchor_line(NODE, &edge(Some("depends_on"), "THX-A")),
+            Some(11)
+        );
+        assert_eq!(anchor_line(NODE, &edge(Some("satisfies"), "THX-A")), None);
+        assert_eq!(anchor_line(NODE, &IssueAnchor::Line(3)), Some(3));
+        let value = IssueAnchor::Value {
+            key: "path".to_string(),
+            value: "src/lib.rs".to_string(),
+        };
+        assert_eq!(
+            anchor_line("meta:\n  files:\n  - path: 'src/lib.rs'\n", &value),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn test_sarif_log() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path();
+        let req_dir = root.join(LATTICE_DIR).join("requirements");
+        std::fs::create_dir_all(&req_dir).unwrap();
+        std::fs::write(req_dir.join("req-a.yaml"), NODE).unwrap();
+
+        let findings = vec![
+            SarifFinding {
+                rule_id: "invalid-version".to_string(),
+                level: SarifLevel::Warning,
+                message: "Invalid semver version: '1.0'".to_string(),
+                file: req_dir.join("req-a.yaml"),
+                anchor: Some(IssueAnchor::Field("version".to_string())),
+            },
+            SarifFinding {
+                rule_id: "lattice-stale".to_string(),
+                level: SarifLevel::Note,
+                message: "Lattice is 100h behind code changes".to_string(),
+                file: PathBuf::from(".lattice/config.yaml"),
+                anchor: None,
+            },
+        ];
+        let rules = lint_rules(&LintConfig::default());
+        let log = sarif_log(root, &rules, &findings, Some(json!({ "verdict": "WARN" })));
+
+        assert_eq!(log["version"], "2.1.0");
+        let run = &log["runs"][0];
+        assert_eq!(run["properties"]["verdict"], "WARN");
+        let driver_rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(driver_rules.len(), LINT_RULES.len() + 1);
+
+        let first = &run["results"][0];
+        assert_eq!(first["ruleId"], "invalid-version");
+        assert_eq!(
+            driver_rules[first["ruleIndex"].as_u64().unwrap() as usize]["id"],
+            "invalid-version"
+        );
+        let location = &first["locations"][0]["physicalLocation"];
+        assert_eq!(
+            location["artifactLocation"]["uri"],
+            ".lattice/requirements/req-a.yaml"
+        );
+        assert_eq!(location["region"]["startLine"], 4);
+
+        let second = &run["results"][1];
+        assert_eq!(second["level"], "note");
+        assert_eq!(
+            second["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            ".lattice/config.yaml"
+        );
+        assert!(second["locations"][0]["physicalLocation"]["region"].is_null());
+    }
+}
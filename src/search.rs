@@ -7,7 +7,7 @@
 //!
 //! Linked requirement: REQ-API-008
 
-use crate::graph::build_node_index;
+use crate::graph::{build_node_index, lookup_node};
 use crate::storage::{load_all_nodes, load_nodes_by_type};
 use crate::types::{Edges, LatticeNode, Resolution};
 use serde::{Deserialize, Serialize};
@@ -140,9 +140,9 @@ impl SearchEngine {
         };
 
         let index = build_node_index(&self.root).map_err(|e| e.to_string())?;
-        let source_node = index
-            .get(related_to_id)
+        let source_node = lookup_node(&index, related_to_id)
             .ok_or_else(|| format!("Node not found: {}", related_to_id))?;
+        let related_to_id = source_node.id.as_str();
 
         let mut related = HashSet::new();
 
@@ -357,10 +357,15 @@ fn matches_filters(
     related_ids: Option<&HashSet<String>>,
 ) -> bool {
     // ID prefix filter
-    if let Some(ref prefix) = params.id_prefix
-        && !node.id.to_uppercase().starts_with(&prefix.to_uppercase())
-    {
-        return false;
+    if let Some(ref prefix) = params.id_prefix {
+        let prefix = prefix.to_uppercase();
+        let matches_alias = node
+            .aliases
+            .as_ref()
+            .is_some_and(|a| a.iter().any(|x| x.to_uppercase().starts_with(&prefix)));
+        if !node.id.to_uppercase().starts_with(&prefix) && !matches_alias {
+            return false;
+        }
     }
 
     // Graph proximity filter
@@ -916,23 +921,9 @@ mod tests {
 
     fn make_node(id: &str, title: &str, body: &str) -> LatticeNode {
         LatticeNode {
-            id: id.to_string(),
-            node_type: NodeType::Requirement,
             title: title.to_string(),
             body: body.to_string(),
-            status: Status::Active,
-            version: "1.0.0".to_string(),
-            created_at: "2024-01-01".to_string(),
-            created_by: "test".to_string(),
-            requested_by: None,
-            priority: None,
-            category: None,
-            tags: None,
-            acceptance: None,
-            visibility: None,
-            resolution: None,
-            meta: None,
-            edges: None,
+            ..LatticeNode::test_node(id, NodeType::Requirement)
         }
     }
 
@@ -1362,6 +1353,7 @@ mod tests {
 
         let req1 = LatticeNode {
             id: "REQ-TEST-001".to_string(),
+            aliases: None,
             node_type: NodeType::Requirement,
             title: "Drift detection for version changes".to_string(),
             body: "The system must detect when node versions drift from edge bindings.".to_string(),
@@ -1370,17 +1362,20 @@ mod tests {
             created_at: "2024-01-01".to_string(),
             created_by: "test".to_string(),
             priority: Some(Priority::P0),
+            estimate: None,
             category: Some("CORE".to_string()),
             tags: Some(vec!["drift".to_string()]),
             requested_by: None,
             acceptance: None,
             visibility: None,
+            lint_ignore: None,
             resolution: None,
             meta: None,
             edges: None,
         };
         let req2 = LatticeNode {
             id: "REQ-TEST-002".to_string(),
+            aliases: None,
             node_type: NodeType::Requirement,
             title: "Export narrative for stakeholders".to_string(),
             body: "Support exporting the lattice as a readable narrative document.".to_string(),
@@ -1389,17 +1384,20 @@ mod tests {
             created_at: "2024-01-01".to_string(),
             created_by: "test".to_string(),
             priority: Some(Priority::P1),
+            estimate: None,
             category: Some("EXPORT".to_string()),
             tags: Some(vec!["export".to_string()]),
             requested_by: None,
             acceptance: None,
             visibility: None,
+            lint_ignore: None,
             resolution: None,
             meta: None,
             edges: None,
         };
         let req3 = LatticeNode {
             id: "REQ-TEST-003".to_string(),
+            aliases: None,
             node_type: NodeType::Requirement,
             title: "Search and filter nodes".to_string(),
             body: "Provide search capabilities with keyword and tag filtering.".to_string(),
@@ -1408,11 +1406,13 @@ mod tests {
             created_at: "2024-01-01".to_string(),
             created_by: "test".to_string(),
             priority: Some(Priority::P1),
+            estimate: None,
             category: Some("API".to_string()),
             tags: Some(vec!["search".to_string()]),
             requested_by: None,
             acceptance: None,
             visibility: None,
+            lint_ignore: None,
             resolution: None,
             meta: None,
             edges: None,
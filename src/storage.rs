@@ -2,10 +2,12 @@
 //!
 //! Linked requirements: REQ-CORE-004, REQ-CLI-002, REQ-AGENT-002
 
+use crate::id_index::{NODE_TYPE_DIRS, lookup_node_path, refreshed_id_index};
 use crate::types::{
     ConfidenceEntry, EdgeReference, Edges, LatticeNode, MessageMeta, NodeMeta, NodeType, Priority,
     Reliability, Resolution, ResolutionInfo, SourceMeta, Status, ThesisCategory, ThesisMeta,
 };
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -126,6 +128,17 @@ pub struct LatticeConfig {
     pub schema_version: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub freshness_threshold_hours: Option<u64>,
+    /// Version bump applied by `edit` per changed field (e.g. `body: minor`).
+    /// The key `default` covers fields not listed; unconfigured edits bump patch.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub version_policy: BTreeMap<String, BumpLevel>,
+    /// Minimum coverage percentage per metric for `lattice coverage --check`
+    /// (e.g. `requirements_implemented: 80`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub coverage_thresholds: BTreeMap<String, f64>,
+    /// Rule levels and suppressions for `lattice lint`.
+    #[serde(default, skip_serializing_if = "crate::lint::LintConfig::is_empty")]
+    pub lint: crate::lint::LintConfig,
 }
 
 /// The current schema version. Bump when .lattice/ format changes.
@@ -179,13 +192,22 @@ pub fn check_schema_version(root: &Path) -> SchemaCheck {
     }
 }
 
-/// Read config.yaml from a lattice root.
+/// Read config.yaml from a lattice root. A missing file gives the defaults;
+/// a file that does not parse is reported on stderr and also gives the
+/// defaults.
 pub fn load_config(root: &Path) -> LatticeConfig {
     let config_path = root.join(LATTICE_DIR).join("config.yaml");
-    fs::read_to_string(&config_path)
-        .ok()
-        .and_then(|content| serde_yaml::from_str(&content).ok())
-        .unwrap_or_default()
+    let Ok(content) = fs::read_to_string(&config_path) else {
+        return LatticeConfig::default();
+    };
+    serde_yaml::from_str(&content).unwrap_or_else(|e| {
+        eprintln!(
+            "Warning: ignoring {}, using defaults: {}",
+            config_path.display(),
+            e
+        );
+        LatticeConfig::default()
+    })
 }
 
 fn build_config_yaml(info: &GitRepoInfo) -> String {
@@ -319,6 +341,17 @@ pub fn save_node(path: &Path, node: &LatticeNode) -> Result<(), StorageError> {
 
 /// Load all nodes of a specific type from the lattice.
 pub fn load_nodes_by_type(root: &Path, node_type: &str) -> Result<Vec<LatticeNode>, StorageError> {
+    Ok(load_node_files(root, node_type)?
+        .into_iter()
+        .map(|(_, node)| node)
+        .collect())
+}
+
+/// Load all nodes of a specific type along with the file each came from.
+fn load_node_files(
+    root: &Path,
+    node_type: &str,
+) -> Result<Vec<(PathBuf, LatticeNode)>, StorageError> {
     let type_dir = root.join(LATTICE_DIR).join(node_type);
     if !type_dir.exists() {
         return Ok(Vec::new());
@@ -336,7 +369,7 @@ pub fn load_nodes_by_type(root: &Path, node_type: &str) -> Result<Vec<LatticeNod
         })
     {
         match load_node(entry.path()) {
-            Ok(node) => nodes.push(node),
+            Ok(node) => nodes.push((entry.path().to_path_buf(), node)),
             Err(e) => eprintln!("Warning: failed to load {}: {}", entry.path().display(), e),
         }
     }
@@ -345,17 +378,16 @@ pub fn load_nodes_by_type(root: &Path, node_type: &str) -> Result<Vec<LatticeNod
 }
 
 /// Load all nodes from the lattice.
+///
+/// The node files are listed by the ID index (see `id_index`), which is
+/// brought up to date and persisted on the way.
 pub fn load_all_nodes(root: &Path) -> Result<Vec<LatticeNode>, StorageError> {
     let mut all_nodes = Vec::new();
-    for type_name in &[
-        "sources",
-        "theses",
-        "requirements",
-        "implementations",
-        "messages",
-    ] {
-        let nodes = load_nodes_by_type(root, type_name)?;
-        all_nodes.extend(nodes);
+    for path in refreshed_id_index(root).paths(root) {
+        match load_node(&path) {
+            Ok(node) => all_nodes.push(node),
+            Err(e) => eprintln!("Warning: failed to load {}: {}", path.display(), e),
+        }
     }
     Ok(all_nodes)
 }
@@ -366,6 +398,8 @@ pub struct AddRequirementOptions {
     pub title: String,
     pub body: String,
     pub priority: Priority,
+    /// Effort estimate (points or days).
+    pub estimate: Option<f64>,
     pub category: String,
     pub tags: Option<Vec<String>>,
     pub derives_from: Option<Vec<String>>,
@@ -445,12 +479,63 @@ fn slugify(s: &str, max_len: usize) -> String {
         .collect()
 }
 
+/// Canonical file path for a node, derived from its type, ID and (for
+/// requirements) category and title.
+fn canonical_node_path(root: &Path, node: &LatticeNode) -> PathBuf {
+    let lattice_dir = root.join(LATTICE_DIR);
+    let id = node.id.to_lowercase();
+    match node.node_type {
+        NodeType::Requirement => {
+            let category_dir = node
+                .category
+                .as_deref()
+                .or_else(|| node.id.split('-').nth(1))
+                .unwrap_or("uncategorized")
+                .to_lowercase();
+            let id_number = node.id.split('-').next_back().unwrap_or("000");
+            let file_name = format!("{}-{}.yaml", id_number, slugify(&node.title, 40));
+            lattice_dir
+                .join("requirements")
+                .join(category_dir)
+                .join(file_name)
+        }
+        NodeType::Thesis => lattice_dir
+            .join("theses")
+            .join(format!("{}.yaml", id.trim_start_matches("thx-"))),
+        NodeType::Source => lattice_dir
+            .join("sources")
+            .join(format!("{}.yaml", id.trim_start_matches("src-"))),
+        NodeType::Implementation => lattice_dir
+            .join("implementations")
+            .join(format!("{}.yaml", id.trim_start_matches("imp-"))),
+        NodeType::Message => lattice_dir.join("messages").join(format!(
+            "{}.yaml",
+            slugify(id.trim_start_matches("msg-"), 40)
+        )),
+    }
+}
+
+/// Reject estimates that cannot be summed into a plan.
+fn check_estimate(estimate: f64) -> Result<(), StorageError> {
+    if estimate.is_finite() && estimate >= 0.0 {
+        Ok(())
+    } else {
+        Err(StorageError::InvalidField(format!(
+            "Estimate must be a non-negative number, got {}",
+            estimate
+        )))
+    }
+}
+
 /// Add a requirement to the lattice.
 pub fn add_requirement(
     root: &Path,
     options: AddRequirementOptions,
 ) -> Result<PathBuf, StorageError> {
     check_duplicate_id(root, &options.id)?;
+    if let Some(estimate) = options.estimate {
+        check_estimate(estimate)?;
+    }
     let now = chrono::Utc::now().to_rfc3339();
 
     let edges = Edges {
@@ -461,6 +546,7 @@ pub fn add_requirement(
 
     let node = LatticeNode {
         id: options.id.clone(),
+        aliases: None,
         node_type: NodeType::Requirement,
         title: options.title.clone(),
         body: options.body,
@@ -470,24 +556,18 @@ pub fn add_requirement(
         created_by: options.created_by,
         requested_by: get_git_user(),
         priority: Some(options.priority),
+        estimate: options.estimate,
         category: Some(options.category.clone()),
         tags: options.tags,
         acceptance: None,
         visibility: None,
+        lint_ignore: None,
         resolution: None,
         meta: None,
         edges: Some(edges),
     };
 
-    let category_dir = options.category.to_lowercase();
-    let id_number = options.id.split('-').next_back().unwrap_or("000");
-    let slug = slugify(&options.title, 40);
-    let file_name = format!("{}-{}.yaml", id_number, slug);
-    let file_path = root
-        .join(LATTICE_DIR)
-        .join("requirements")
-        .join(&category_dir)
-        .join(&file_name);
+    let file_path = canonical_node_path(root, &node);
 
     save_node(&file_path, &node)?;
     Ok(file_path)
@@ -505,6 +585,7 @@ pub fn add_thesis(root: &Path, options: AddThesisOptions) -> Result<PathBuf, Sto
 
     let node = LatticeNode {
         id: options.id.clone(),
+        aliases: None,
         node_type: NodeType::Thesis,
         title: options.title,
         body: options.body,
@@ -514,10 +595,12 @@ pub fn add_thesis(root: &Path, options: AddThesisOptions) -> Result<PathBuf, Sto
         created_by: options.created_by,
         requested_by: get_git_user(),
         priority: None,
+        estimate: None,
         category: None,
         tags: None,
         acceptance: None,
         visibility: None,
+        lint_ignore: None,
         resolution: None,
         meta: Some(NodeMeta::Thesis(ThesisMeta {
             category: options.category,
@@ -530,13 +613,7 @@ pub fn add_thesis(root: &Path, options: AddThesisOptions) -> Result<PathBuf, Sto
         edges: Some(edges),
     };
 
-    let slug = options
-        .id
-        .to_lowercase()
-        .trim_start_matches("thx-")
-        .to_string();
-    let file_name = format!("{}.yaml", slug);
-    let file_path = root.join(LATTICE_DIR).join("theses").join(&file_name);
+    let file_path = canonical_node_path(root, &node);
 
     save_node(&file_path, &node)?;
     Ok(file_path)
@@ -550,6 +627,7 @@ pub fn add_source(root: &Path, options: AddSourceOptions) -> Result<PathBuf, Sto
 
     let node = LatticeNode {
         id: options.id.clone(),
+        aliases: None,
         node_type: NodeType::Source,
         title: options.title,
         body: options.body,
@@ -559,10 +637,12 @@ pub fn add_source(root: &Path, options: AddSourceOptions) -> Result<PathBuf, Sto
         created_by: options.created_by,
         requested_by: get_git_user(),
         priority: None,
+        estimate: None,
         category: None,
         tags: None,
         acceptance: None,
         visibility: None,
+        lint_ignore: None,
         resolution: None,
         meta: Some(NodeMeta::Source(SourceMeta {
             url: options.url,
@@ -573,13 +653,7 @@ pub fn add_source(root: &Path, options: AddSourceOptions) -> Result<PathBuf, Sto
         edges: None,
     };
 
-    let slug = options
-        .id
-        .to_lowercase()
-        .trim_start_matches("src-")
-        .to_string();
-    let file_name = format!("{}.yaml", slug);
-    let file_path = root.join(LATTICE_DIR).join("sources").join(&file_name);
+    let file_path = canonical_node_path(root, &node);
 
     save_node(&file_path, &node)?;
     Ok(file_path)
@@ -610,6 +684,7 @@ pub fn add_implementation(
 
     let node = LatticeNode {
         id: options.id.clone(),
+        aliases: None,
         node_type: NodeType::Implementation,
         title: options.title,
         body: options.body,
@@ -619,10 +694,12 @@ pub fn add_implementation(
         created_by: options.created_by,
         requested_by: get_git_user(),
         priority: None,
+        estimate: None,
         category: None,
         tags: None,
         acceptance: None,
         visibility: None,
+        lint_ignore: None,
         resolution: None,
         meta: Some(NodeMeta::Implementation(crate::types::ImplementationMeta {
             language: options.language,
@@ -632,16 +709,7 @@ pub fn add_implementation(
         edges: Some(edges),
     };
 
-    let slug = options
-        .id
-        .to_lowercase()
-        .trim_start_matches("imp-")
-        .to_string();
-    let file_name = format!("{}.yaml", slug);
-    let file_path = root
-        .join(LATTICE_DIR)
-        .join("implementations")
-        .join(&file_name);
+    let file_path = canonical_node_path(root, &node);
 
     save_node(&file_path, &node)?;
     Ok(file_path)
@@ -672,6 +740,7 @@ pub fn add_message(root: &Path, options: AddMessageOptions) -> Result<PathBuf, S
 
     let node = LatticeNode {
         id: options.id.clone(),
+        aliases: None,
         node_type: NodeType::Message,
         title: options.title,
         body: options.body,
@@ -681,10 +750,12 @@ pub fn add_message(root: &Path, options: AddMessageOptions) -> Result<PathBuf, S
         created_by: options.created_by,
         requested_by: get_git_user(),
         priority: None,
+        estimate: None,
         category: None,
         tags: options.tags,
         acceptance: None,
         visibility: None,
+        lint_ignore: None,
         resolution: None,
         meta: Some(NodeMeta::Message(MessageMeta {
             persona: options.persona,
@@ -693,51 +764,18 @@ pub fn add_message(root: &Path, options: AddMessageOptions) -> Result<PathBuf, S
         edges: Some(edges),
     };
 
-    let slug = options
-        .id
-        .to_lowercase()
-        .trim_start_matches("msg-")
-        .to_string();
-    let file_name = format!("{}.yaml", slugify(&slug, 40));
-    let file_path = root.join(LATTICE_DIR).join("messages").join(&file_name);
+    let file_path = canonical_node_path(root, &node);
 
     save_node(&file_path, &node)?;
     Ok(file_path)
 }
 
 /// Find the file path for a node by ID.
+///
+/// Backed by the persistent ID index (see `id_index`), so only files that
+/// changed since the last lookup are re-parsed.
 pub fn find_node_path(root: &Path, node_id: &str) -> Result<PathBuf, StorageError> {
-    let lattice_dir = root.join(LATTICE_DIR);
-
-    for type_name in &[
-        "sources",
-        "theses",
-        "requirements",
-        "implementations",
-        "messages",
-    ] {
-        let type_dir = lattice_dir.join(type_name);
-        if !type_dir.exists() {
-            continue;
-        }
-
-        for entry in WalkDir::new(&type_dir)
-            .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| {
-                e.path()
-                    .extension()
-                    .map(|ext| ext == "yaml" || ext == "yml")
-                    .unwrap_or(false)
-            })
-        {
-            if load_node(entry.path()).is_ok_and(|node| node.id == node_id) {
-                return Ok(entry.path().to_path_buf());
-            }
-        }
-    }
-
-    Err(StorageError::NodeNotFound(node_id.to_string()))
+    lookup_node_path(root, node_id)
 }
 
 /// Options for resolving a node.
@@ -773,48 +811,103 @@ pub struct EditNodeOptions {
     pub body: Option<String>,
     pub status: Option<Status>,
     pub priority: Option<Priority>,
+    pub estimate: Option<f64>,
     pub confidence: Option<f64>,
     pub tags: Option<Vec<String>>,
     pub category: Option<String>,
     pub files: Option<Vec<String>>,
     pub test_command: Option<String>,
+    /// Explicit bump level; overrides the config `version_policy` when set.
+    pub bump: Option<BumpLevel>,
+}
+
+/// Semantic version component to bump when a node changes.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum BumpLevel {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl std::str::FromStr for BumpLevel {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "patch" => Ok(BumpLevel::Patch),
+            "minor" => Ok(BumpLevel::Minor),
+            "major" => Ok(BumpLevel::Major),
+            _ => Err(format!(
+                "Invalid bump level: {}. Use major, minor, or patch",
+                s
+            )),
+        }
+    }
+}
+
+impl BumpLevel {
+    /// Pick the bump level for a set of changed fields from the configured
+    /// policy: the largest level among the fields, falling back to the
+    /// policy's `default` entry and then to patch.
+    pub fn from_policy(policy: &BTreeMap<String, BumpLevel>, changed_fields: &[&str]) -> Self {
+        let fallback = policy.get("default").copied().unwrap_or(BumpLevel::Patch);
+        changed_fields
+            .iter()
+            .map(|f| policy.get(*f).copied().unwrap_or(fallback))
+            .max()
+            .unwrap_or(fallback)
+    }
 }
 
-/// Bump the patch component of a semver version string.
-fn bump_patch_version(version: &str) -> String {
-    match semver::Version::parse(version) {
-        Ok(mut v) => {
-            v.patch += 1;
-            v.to_string()
+/// Bump a semver version string at the given level, resetting lower components.
+fn bump_version(version: &str, level: BumpLevel) -> String {
+    let mut v = semver::Version::parse(version).unwrap_or(semver::Version::new(1, 0, 0));
+    match level {
+        BumpLevel::Patch => v.patch += 1,
+        BumpLevel::Minor => {
+            v.minor += 1;
+            v.patch = 0;
+        }
+        BumpLevel::Major => {
+            v.major += 1;
+            v.minor = 0;
+            v.patch = 0;
         }
-        Err(_) => "1.0.1".to_string(),
     }
+    v.pre = semver::Prerelease::EMPTY;
+    v.build = semver::BuildMetadata::EMPTY;
+    v.to_string()
 }
 
-/// Edit fields on an existing node, auto-bumping the patch version.
+/// Edit fields on an existing node, auto-bumping its version.
+///
+/// The bump level is `options.bump` if given, otherwise derived from the
+/// changed fields via the config `version_policy` (patch when unconfigured).
 pub fn edit_node(root: &Path, options: EditNodeOptions) -> Result<PathBuf, StorageError> {
     let path = find_node_path(root, &options.node_id)?;
     let mut node = load_node(&path)?;
 
-    let mut changed = false;
+    let mut changed_fields: Vec<&str> = Vec::new();
 
     if let Some(title) = options.title
         && title != node.title
     {
         node.title = title;
-        changed = true;
+        changed_fields.push("title");
     }
     if let Some(body) = options.body
         && body != node.body
     {
         node.body = body;
-        changed = true;
+        changed_fields.push("body");
     }
     if let Some(new_status) = options.status
         && new_status != node.status
     {
         node.status = new_status;
-        changed = true;
+        changed_fields.push("status");
     }
     if let Some(new_priority) = options.priority {
         if node.node_type != NodeType::Requirement {
@@ -824,7 +917,19 @@ pub fn edit_node(root: &Path, options: EditNodeOptions) -> Result<PathBuf, Stora
         }
         if node.priority.as_ref() != Some(&new_priority) {
             node.priority = Some(new_priority);
-            changed = true;
+            changed_fields.push("priority");
+        }
+    }
+    if let Some(new_estimate) = options.estimate {
+        if node.node_type != NodeType::Requirement {
+            return Err(StorageError::InvalidField(
+                "Estimate can only be set on requirement nodes".to_string(),
+            ));
+        }
+        check_estimate(new_estimate)?;
+        if node.estimate != Some(new_estimate) {
+            node.estimate = Some(new_estimate);
+            changed_fields.push("estimate");
         }
     }
     if let Some(new_confidence) = options.confidence {
@@ -844,7 +949,7 @@ pub fn edit_node(root: &Path, options: EditNodeOptions) -> Result<PathBuf, Stora
                     updated_at: chrono::Utc::now().to_rfc3339(),
                 });
                 meta.confidence = Some(new_confidence);
-                changed = true;
+                changed_fields.push("confidence");
             }
         }
     }
@@ -852,13 +957,13 @@ pub fn edit_node(root: &Path, options: EditNodeOptions) -> Result<PathBuf, Stora
         && node.tags.as_ref() != Some(&tags)
     {
         node.tags = Some(tags);
-        changed = true;
+        changed_fields.push("tags");
     }
     if let Some(category) = options.category
         && node.category.as_ref() != Some(&category)
     {
         node.category = Some(category);
-        changed = true;
+        changed_fields.push("category");
     }
     if options.files.is_some() || options.test_command.is_some() {
         if node.node_type != NodeType::Implementation {
@@ -905,24 +1010,27 @@ pub fn edit_node(root: &Path, options: EditNodeOptions) -> Result<PathBuf, Stora
                 .collect();
             if impl_meta.files.as_ref() != Some(&new_files) {
                 impl_meta.files = Some(new_files);
-                changed = true;
+                changed_fields.push("files");
             }
         }
         if let Some(test_cmd) = options.test_command
             && impl_meta.test_command.as_deref() != Some(&test_cmd)
         {
             impl_meta.test_command = Some(test_cmd);
-            changed = true;
+            changed_fields.push("test_command");
         }
 
         node.meta = Some(NodeMeta::Implementation(impl_meta));
     }
 
-    if !changed {
+    if changed_fields.is_empty() {
         return Ok(path);
     }
 
-    node.version = bump_patch_version(&node.version);
+    let level = options.bump.unwrap_or_else(|| {
+        BumpLevel::from_policy(&load_config(root).version_policy, &changed_fields)
+    });
+    node.version = bump_version(&node.version, level);
     // Re-snapshot edge target versions when content changes
     re_snapshot_edges(root, &mut node);
     save_node(&path, &node)?;
@@ -939,12 +1047,13 @@ fn re_snapshot_edges(root: &Path, node: &mut LatticeNode) {
         Some(e) => e,
         None => return,
     };
+    let index = refreshed_id_index(root);
 
     macro_rules! update_versions {
         ($field:ident) => {
             if let Some(vec) = &mut edges.$field {
                 for edge in vec.iter_mut() {
-                    if let Ok(target_path) = find_node_path(root, &edge.target) {
+                    if let Some(target_path) = index.path_for(root, &edge.target) {
                         if let Ok(target_node) = load_node(&target_path) {
                             edge.version = Some(target_node.version.clone());
                         }
@@ -1097,6 +1206,7 @@ pub fn refine_requirement(
     let now = chrono::Utc::now().to_rfc3339();
     let sub_node = LatticeNode {
         id: sub_id.clone(),
+        aliases: None,
         node_type: NodeType::Requirement,
         title: options.title.clone(),
         body,
@@ -1106,10 +1216,12 @@ pub fn refine_requirement(
         created_by: options.created_by,
         requested_by: get_git_user(),
         priority: Some(priority),
+        estimate: None,
         category: Some(category.clone()),
         tags: Some(vec!["refinement".to_string(), options.gap_type.to_string()]),
         acceptance: None,
         visibility: None,
+        lint_ignore: None,
         resolution: None,
         meta: None,
         edges: Some(edges),
@@ -1515,6 +1627,419 @@ pub fn verify_implementation(root: &Path, options: VerifyOptions) -> Result<Path
     Ok(impl_path)
 }
 
+/// Subdirectory of `.lattice/` that holds archived nodes. Node loaders,
+/// lint, and diff all skip it, so archived nodes drop out of the graph.
+pub const ARCHIVE_DIR: &str = "archive";
+
+/// What to do with edges on other nodes that point at a removed node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IncomingEdgeAction {
+    /// Leave the edges in place (they will dangle and be reported by lint).
+    Keep,
+    /// Delete the edges from their source nodes.
+    Remove,
+    /// Point the edges at another node, pinned to its current version.
+    Retarget(String),
+}
+
+/// Options for removing a node.
+pub struct RemoveNodeOptions {
+    pub node_id: String,
+    /// Move the node to `.lattice/archive/` with status `deprecated` instead of deleting it.
+    pub archive: bool,
+    pub incoming: IncomingEdgeAction,
+    /// Report what would change without touching any files.
+    pub dry_run: bool,
+}
+
+/// An edge on another node that targets a given node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncomingEdge {
+    pub from_id: String,
+    pub edge_type: String,
+    pub version: Option<String>,
+}
+
+/// Result of removing a node.
+#[derive(Debug)]
+pub struct RemoveNodeResult {
+    /// Where the node lived before removal.
+    pub path: PathBuf,
+    /// Where the node was (or would be) archived to, if archiving.
+    pub archived_to: Option<PathBuf>,
+    /// Every edge on another node that targeted the removed node, under its
+    /// ID or a former ID.
+    pub incoming: Vec<IncomingEdge>,
+    /// Number of incoming edges removed or retargeted (0 on a dry run).
+    pub edges_updated: usize,
+}
+
+/// Remove a node from the lattice, either archiving or deleting it, and
+/// clean up edges on other nodes that point at it. `incoming` lists those
+/// edges, as found by `graph::find_incoming_edges`.
+pub fn remove_node(
+    root: &Path,
+    options: RemoveNodeOptions,
+    incoming: Vec<IncomingEdge>,
+) -> Result<RemoveNodeResult, StorageError> {
+    let path = find_node_path(root, &options.node_id)?;
+
+    if let IncomingEdgeAction::Retarget(new_id) = &options.incoming {
+        if *new_id == options.node_id {
+            return Err(StorageError::InvalidField(
+                "Cannot retarget edges to the node being removed".to_string(),
+            ));
+        }
+        find_node_path(root, new_id)?;
+    }
+
+    let removed = load_node(&path)?;
+
+    let lattice_dir = root.join(LATTICE_DIR);
+    let archived_to = if options.archive {
+        let rel = path.strip_prefix(&lattice_dir).unwrap_or(&path);
+        let dest = lattice_dir.join(ARCHIVE_DIR).join(rel);
+        if dest.exists() {
+            return Err(StorageError::AlreadyExists(format!(
+                "Archive already contains {}",
+                dest.display()
+            )));
+        }
+        Some(dest)
+    } else {
+        None
+    };
+
+    if options.dry_run {
+        return Ok(RemoveNodeResult {
+            path,
+            archived_to,
+            incoming,
+            edges_updated: 0,
+        });
+    }
+
+    let mut edges_updated = 0;
+    for edge in &incoming {
+        if options.incoming == IncomingEdgeAction::Keep {
+            continue;
+        }
+        // The edges as written, which may address a former ID
+        let from = load_node(&find_node_path(root, &edge.from_id)?)?;
+        let mut targets: Vec<String> = from
+            .typed_edges()
+            .into_iter()
+            .filter(|(t, e)| *t == edge.edge_type && removed.answers_to(&e.target))
+            .map(|(_, e)| e.target.clone())
+            .collect();
+        targets.dedup();
+        for (i, target) in targets.into_iter().enumerate() {
+            let remove = match &options.incoming {
+                IncomingEdgeAction::Retarget(new_id) if i == 0 => {
+                    // Drop the edge instead of creating a self-edge or a duplicate
+                    *new_id == edge.from_id
+                        || from
+                            .typed_edges()
+                            .iter()
+                            .any(|(t, e)| *t == edge.edge_type && e.target == *new_id)
+                }
+                _ => true,
+            };
+            if remove {
+                remove_edge(
+                    root,
+                    RemoveEdgeOptions {
+                        from_id: edge.from_id.clone(),
+                        edge_type: edge.edge_type.clone(),
+                        to_id: target,
+                    },
+                )?;
+            } else if let IncomingEdgeAction::Retarget(new_id) = &options.incoming {
+                replace_edge(
+                    root,
+                    ReplaceEdgeOptions {
+                        from_id: edge.from_id.clone(),
+                        edge_type: edge.edge_type.clone(),
+                        old_to_id: target,
+                        new_to_id: new_id.clone(),
+                        rationale: None,
+                    },
+                )?;
+            }
+        }
+        edges_updated += 1;
+    }
+
+    match &archived_to {
+        Some(dest) => {
+            let mut node = load_node(&path)?;
+            node.status = Status::Deprecated;
+            save_node(dest, &node)?;
+            fs::remove_file(&path)?;
+        }
+        None => fs::remove_file(&path)?,
+    }
+
+    Ok(RemoveNodeResult {
+        path,
+        archived_to,
+        incoming,
+        edges_updated,
+    })
+}
+
+/// Options for renaming a node.
+pub struct RenameNodeOptions {
+    pub old_id: String,
+    pub new_id: String,
+}
+
+/// Result of renaming a node.
+#[derive(Debug)]
+pub struct RenameNodeResult {
+    pub old_path: PathBuf,
+    pub new_path: PathBuf,
+    /// Nodes whose edges were rewritten, with the number of edges changed on each.
+    pub updated_nodes: Vec<(String, usize)>,
+}
+
+/// Give a node a new ID, move its file to the canonical path for that ID,
+/// and rewrite every edge that targeted the old ID. Pinned edge versions are
+/// kept as-is and the old ID is recorded in `aliases` so it still resolves.
+pub fn rename_node(
+    root: &Path,
+    options: RenameNodeOptions,
+) -> Result<RenameNodeResult, StorageError> {
+    let old_id = options.old_id.as_str();
+    let new_id = options.new_id.as_str();
+    if old_id == new_id {
+        return Err(StorageError::InvalidField(
+            "New ID is the same as the old ID".to_string(),
+        ));
+    }
+    let prefix = |id: &str| id.split('-').next().unwrap_or("").to_uppercase();
+    if prefix(old_id) != prefix(new_id) {
+        return Err(StorageError::InvalidField(format!(
+            "Cannot change ID prefix from '{}' to '{}'",
+            prefix(old_id),
+            prefix(new_id)
+        )));
+    }
+
+    let old_path = find_node_path(root, old_id)?;
+    check_duplicate_id(root, new_id)?;
+    let mut node = load_node(&old_path)?;
+
+    // A requirement filed under its ID's category moves with the ID
+    if node.node_type == NodeType::Requirement
+        && let (Some(old_cat), Some(new_cat)) = (old_id.split('-').nth(1), new_id.split('-').nth(1))
+        && node
+            .category
+            .as_deref()
+            .is_some_and(|c| c.eq_ignore_ascii_case(old_cat))
+    {
+        node.category = Some(new_cat.to_string());
+    }
+
+    node.id = new_id.to_string();
+    let mut aliases = node.aliases.take().unwrap_or_default();
+    aliases.retain(|a| a != new_id);
+    if !aliases.iter().any(|a| a == old_id) {
+        aliases.push(old_id.to_string());
+    }
+    node.aliases = Some(aliases);
+    if let Some(edges) = &mut node.edges {
+        edges.retarget(old_id, new_id, None);
+    }
+
+    let new_path = canonical_node_path(root, &node);
+    if new_path != old_path && new_path.exists() {
+        return Err(StorageError::AlreadyExists(format!(
+            "File already exists at {}",
+            new_path.display()
+        )));
+    }
+
+    let mut updated_nodes = Vec::new();
+    for type_name in NODE_TYPE_DIRS {
+        for (path, mut other) in load_node_files(root, type_name)? {
+            if path == old_path {
+                continue;
+            }
+            let count = other
+                .edges
+                .as_mut()
+                .map_or(0, |e| e.retarget(old_id, new_id, None));
+            if count > 0 {
+                save_node(&path, &other)?;
+                updated_nodes.push((other.id, count));
+            }
+        }
+    }
+
+    save_node(&new_path, &node)?;
+    if new_path != old_path {
+        fs::remove_file(&old_path)?;
+    }
+
+    Ok(RenameNodeResult {
+        old_path,
+        new_path,
+        updated_nodes,
+    })
+}
+
+/// Options for merging one node into another.
+pub struct MergeNodesOptions {
+    pub keep_id: String,
+    pub drop_id: String,
+}
+
+/// Result of merging two nodes.
+#[derive(Debug)]
+pub struct MergeNodesResult {
+    pub keep_path: PathBuf,
+    pub drop_path: PathBuf,
+    /// Version of the surviving node after the merge.
+    pub new_version: String,
+    pub tags_added: usize,
+    pub edges_added: usize,
+    pub acceptance_added: usize,
+    /// Nodes whose edges were retargeted from the dropped node, with the
+    /// number of edges changed on each.
+    pub updated_nodes: Vec<(String, usize)>,
+}
+
+/// Merge a duplicate node into the one being kept.
+///
+/// Tags, outgoing edges and acceptance tests of the dropped node are unioned
+/// into the kept node, whose version is bumped. Every edge elsewhere that
+/// targeted the dropped node (by ID or alias) is retargeted to the kept node
+/// and pinned to its new version. The dropped
+/// node stays on disk as `superseded`, and the kept node gains a
+/// `supersedes` edge to it so the history remains traceable.
+pub fn merge_nodes(
+    root: &Path,
+    options: MergeNodesOptions,
+) -> Result<MergeNodesResult, StorageError> {
+    let keep_id = options.keep_id.as_str();
+    let drop_id = options.drop_id.as_str();
+    if keep_id == drop_id {
+        return Err(StorageError::InvalidField(
+            "Cannot merge a node into itself".to_string(),
+        ));
+    }
+
+    let keep_path = find_node_path(root, keep_id)?;
+    let drop_path = find_node_path(root, drop_id)?;
+    let mut keep = load_node(&keep_path)?;
+    let mut dropped = load_node(&drop_path)?;
+    if keep.node_type != dropped.node_type {
+        return Err(StorageError::InvalidField(format!(
+            "Cannot merge {:?} '{}' into {:?} '{}'",
+            dropped.node_type, drop_id, keep.node_type, keep_id
+        )));
+    }
+
+    let mut tags_added = 0;
+    if let Some(drop_tags) = &dropped.tags {
+        let tags = keep.tags.get_or_insert_with(Vec::new);
+        for tag in drop_tags {
+            if !tags.contains(tag) {
+                tags.push(tag.clone());
+                tags_added += 1;
+            }
+        }
+    }
+
+    let mut acceptance_added = 0;
+    if let Some(drop_tests) = &dropped.acceptance {
+        let tests = keep.acceptance.get_or_insert_with(Vec::new);
+        for test in drop_tests {
+            let duplicate = tests
+                .iter()
+                .any(|t| t.given == test.given && t.when == test.when && t.then == test.then);
+            if duplicate {
+                continue;
+            }
+            let mut test = test.clone();
+            if tests.iter().any(|t| t.id == test.id) {
+                test.id = format!("{}-{}", test.id, drop_id);
+            }
+            tests.push(test);
+            acceptance_added += 1;
+        }
+    }
+
+    // Union outgoing edges, leaving out edges between the two nodes
+    let keep_names: Vec<&str> = std::iter::once(keep_id)
+        .chain(keep.aliases.iter().flatten().map(String::as_str))
+        .collect();
+    let drop_names: Vec<String> = std::iter::once(drop_id)
+        .chain(dropped.aliases.iter().flatten().map(String::as_str))
+        .map(str::to_string)
+        .collect();
+    let mut drop_edges = dropped.edges.clone().unwrap_or_default();
+    for name in keep_names
+        .iter()
+        .copied()
+        .chain(drop_names.iter().map(String::as_str))
+    {
+        drop_edges.remove_target(name);
+    }
+    let keep_edges = keep.edges.get_or_insert_with(Edges::default);
+    for name in &drop_names {
+        keep_edges.remove_target(name);
+    }
+    let edges_added = keep_edges.union(&drop_edges);
+
+    let supersedes = keep_edges.supersedes.get_or_insert_with(Vec::new);
+    supersedes.push(EdgeReference {
+        target: drop_id.to_string(),
+        version: Some(dropped.version.clone()),
+        rationale: Some(format!("Merged into {}", keep_id)),
+    });
+
+    keep.version = bump_version(&keep.version, BumpLevel::Patch);
+    dropped.status = Status::Superseded;
+
+    let mut updated_nodes = Vec::new();
+    for type_name in NODE_TYPE_DIRS {
+        for (path, mut other) in load_node_files(root, type_name)? {
+            if path == keep_path || path == drop_path {
+                continue;
+            }
+            let Some(edges) = &mut other.edges else {
+                continue;
+            };
+            let count: usize = drop_names
+                .iter()
+                .map(|name| edges.retarget(name, keep_id, Some(&keep.version)))
+                .sum();
+            if count > 0 {
+                // Collapse edges that now duplicate an existing one to the kept node
+                let retargeted = std::mem::take(edges);
+                edges.union(&retargeted);
+                save_node(&path, &other)?;
+                updated_nodes.push((other.id, count));
+            }
+        }
+    }
+
+    save_node(&keep_path, &keep)?;
+    save_node(&drop_path, &dropped)?;
+
+    Ok(MergeNodesResult {
+        keep_path,
+        drop_path,
+        new_version: keep.version,
+        tags_added,
+        edges_added,
+        acceptance_added,
+        updated_nodes,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1638,6 +2163,7 @@ mod tests {
 
         let node = crate::types::LatticeNode {
             id: "REQ-RT".to_string(),
+            aliases: None,
             node_type: crate::types::NodeType::Requirement,
             title: "Roundtrip Test".to_string(),
             body: "Test body".to_string(),
@@ -1647,10 +2173,12 @@ mod tests {
             created_by: "test".to_string(),
             requested_by: None,
             priority: Some(crate::types::Priority::P0),
+            estimate: None,
             category: Some("CORE".to_string()),
             tags: Some(vec!["test".to_string()]),
             acceptance: None,
             visibility: None,
+            lint_ignore: None,
             resolution: None,
             meta: None,
             edges: None,
@@ -1740,6 +2268,7 @@ mod tests {
             title: "Test Requirement".to_string(),
             body: "Body text".to_string(),
             priority: crate::types::Priority::P0,
+            estimate: None,
             category: "TEST".to_string(),
             tags: Some(vec!["tag1".to_string()]),
             derives_from: None,
@@ -1767,6 +2296,7 @@ mod tests {
             title: "First".to_string(),
             body: "Body".to_string(),
             priority: crate::types::Priority::P0,
+            estimate: None,
             category: "TEST".to_string(),
             tags: None,
             derives_from: None,
@@ -1781,6 +2311,7 @@ mod tests {
             title: "Second with same ID".to_string(),
             body: "Body".to_string(),
             priority: crate::types::Priority::P1,
+            estimate: None,
             category: "TEST".to_string(),
             tags: None,
             derives_from: None,
@@ -1804,6 +2335,7 @@ mod tests {
             title: "Resolvable".to_string(),
             body: "Body".to_string(),
             priority: crate::types::Priority::P1,
+            estimate: None,
             category: "TEST".to_string(),
             tags: None,
             derives_from: None,
@@ -1848,6 +2380,7 @@ mod tests {
             title: "Parent Requirement".to_string(),
             body: "Parent body".to_string(),
             priority: crate::types::Priority::P0,
+            estimate: None,
             category: "TEST".to_string(),
             tags: None,
             derives_from: Some(vec!["THX-001".to_string()]),
@@ -1905,6 +2438,7 @@ mod tests {
             title: "Clarification Parent".to_string(),
             body: "Body".to_string(),
             priority: crate::types::Priority::P1,
+            estimate: None,
             category: "TEST".to_string(),
             tags: None,
             derives_from: None,
@@ -1941,6 +2475,7 @@ mod tests {
             title: "Gap Parent".to_string(),
             body: "Body".to_string(),
             priority: crate::types::Priority::P0,
+            estimate: None,
             category: "TEST".to_string(),
             tags: None,
             derives_from: None,
@@ -1996,6 +2531,7 @@ mod tests {
             title: "Sequential Parent".to_string(),
             body: "Body".to_string(),
             priority: crate::types::Priority::P0,
+            estimate: None,
             category: "TEST".to_string(),
             tags: None,
             derives_from: None,
@@ -2125,6 +2661,7 @@ mod tests {
             title: "Edge target".to_string(),
             body: "Body".to_string(),
             priority: crate::types::Priority::P1,
+            estimate: None,
             category: "TEST".to_string(),
             tags: None,
             derives_from: None,
@@ -2177,6 +2714,7 @@ mod tests {
             title: "Upsert target".to_string(),
             body: "Body".to_string(),
             priority: crate::types::Priority::P0,
+            estimate: None,
             category: "TEST".to_string(),
             tags: None,
             derives_from: None,
@@ -2311,6 +2849,7 @@ mod tests {
                 title: "Original".to_string(),
                 body: "Original body".to_string(),
                 priority: crate::types::Priority::P1,
+                estimate: None,
                 category: "TEST".to_string(),
                 tags: None,
                 derives_from: None,
@@ -2329,11 +2868,13 @@ mod tests {
                 body: Some("New body".to_string()),
                 status: None,
                 priority: None,
+                estimate: None,
                 confidence: None,
                 tags: None,
                 category: None,
                 files: None,
                 test_command: None,
+                bump: None,
             },
         )
         .unwrap();
@@ -2357,6 +2898,7 @@ mod tests {
                 title: "Tags test".to_string(),
                 body: "Body".to_string(),
                 priority: crate::types::Priority::P1,
+                estimate: None,
                 category: "OLD".to_string(),
                 tags: None,
                 derives_from: None,
@@ -2375,11 +2917,13 @@ mod tests {
                 body: None,
                 status: None,
                 priority: None,
+                estimate: None,
                 confidence: None,
                 tags: Some(vec!["alpha".to_string(), "beta".to_string()]),
                 category: Some("NEW".to_string()),
                 files: None,
                 test_command: None,
+                bump: None,
             },
         )
         .unwrap();
@@ -2393,6 +2937,46 @@ mod tests {
         assert_eq!(node.version, "1.0.1");
     }
 
+    #[test]
+    fn test_requirement_estimate_add_and_edit() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        init_lattice(root, false).unwrap();
+
+        let options = |estimate| AddRequirementOptions {
+            id: "REQ-EST-001".to_string(),
+            title: "Sized".to_string(),
+            body: "Body".to_string(),
+            priority: crate::types::Priority::P1,
+            estimate,
+            category: "EST".to_string(),
+            tags: None,
+            derives_from: None,
+            depends_on: None,
+            status: crate::types::Status::Active,
+            created_by: "test".to_string(),
+        };
+        assert!(matches!(
+            add_requirement(root, options(Some(-1.0))),
+            Err(StorageError::InvalidField(_))
+        ));
+        let path = add_requirement(root, options(Some(3.0))).unwrap();
+        assert_eq!(load_node(&path).unwrap().estimate, Some(3.0));
+
+        let edit = |estimate| EditNodeOptions {
+            estimate: Some(estimate),
+            ..edit_opts("REQ-EST-001")
+        };
+        let path = edit_node(root, edit(5.5)).unwrap();
+        let node = load_node(&path).unwrap();
+        assert_eq!(node.estimate, Some(5.5));
+        assert_eq!(node.version, "1.0.1");
+        assert!(matches!(
+            edit_node(root, edit(f64::NAN)),
+            Err(StorageError::InvalidField(_))
+        ));
+    }
+
     #[test]
     fn test_edit_node_no_changes() {
         let dir = TempDir::new().unwrap();
@@ -2406,6 +2990,7 @@ mod tests {
                 title: "Same".to_string(),
                 body: "Same body".to_string(),
                 priority: crate::types::Priority::P1,
+                estimate: None,
                 category: "TEST".to_string(),
                 tags: None,
                 derives_from: None,
@@ -2424,11 +3009,13 @@ mod tests {
                 body: None,
                 status: None,
                 priority: None,
+                estimate: None,
                 confidence: None,
                 tags: None,
                 category: None,
                 files: None,
                 test_command: None,
+                bump: None,
             },
         )
         .unwrap();
@@ -2450,6 +3037,7 @@ mod tests {
                 title: "Priority test".to_string(),
                 body: "Body".to_string(),
                 priority: crate::types::Priority::P1,
+                estimate: None,
                 category: "TEST".to_string(),
                 tags: None,
                 derives_from: None,
@@ -2468,11 +3056,13 @@ mod tests {
                 body: None,
                 status: None,
                 priority: Some(crate::types::Priority::P0),
+                estimate: None,
                 confidence: None,
                 tags: None,
                 category: None,
                 files: None,
                 test_command: None,
+                bump: None,
             },
         )
         .unwrap();
@@ -2511,11 +3101,13 @@ mod tests {
                 body: None,
                 status: None,
                 priority: Some(crate::types::Priority::P0),
+                estimate: None,
                 confidence: None,
                 tags: None,
                 category: None,
                 files: None,
                 test_command: None,
+                bump: None,
             },
         );
         assert!(result.is_err());
@@ -2557,11 +3149,13 @@ mod tests {
                 body: None,
                 status: None,
                 priority: None,
+                estimate: None,
                 confidence: None,
                 tags: None,
                 category: None,
                 files: Some(vec!["src/new.rs".to_string(), "src/lib.rs".to_string()]),
                 test_command: None,
+                bump: None,
             },
         )
         .unwrap();
@@ -2605,11 +3199,13 @@ mod tests {
                 body: None,
                 status: None,
                 priority: None,
+                estimate: None,
                 confidence: None,
                 tags: None,
                 category: None,
                 files: None,
                 test_command: Some("cargo test -- --run".to_string()),
+                bump: None,
             },
         )
         .unwrap();
@@ -2652,11 +3248,13 @@ mod tests {
                 body: None,
                 status: None,
                 priority: None,
+                estimate: None,
                 confidence: None,
                 tags: None,
                 category: None,
                 files: Some(vec!["src/foo.rs".to_string()]),
                 test_command: None,
+                bump: None,
             },
         );
         assert!(result.is_err());
@@ -2679,6 +3277,7 @@ mod tests {
             title: "Remove target".to_string(),
             body: "Body".to_string(),
             priority: crate::types::Priority::P1,
+            estimate: None,
             category: "TEST".to_string(),
             tags: None,
             derives_from: None,
@@ -2815,6 +3414,7 @@ mod tests {
                     title: format!("Target {}", id),
                     body: "Body".to_string(),
                     priority: crate::types::Priority::P1,
+                    estimate: None,
                     category: "TEST".to_string(),
                     tags: None,
                     derives_from: None,
@@ -2884,6 +3484,7 @@ mod tests {
                     title: format!("Target {}", id),
                     body: "Body".to_string(),
                     priority: crate::types::Priority::P1,
+                    estimate: None,
                     category: "TEST".to_string(),
                     tags: None,
                     derives_from: None,
@@ -2952,6 +3553,7 @@ mod tests {
                     title: format!("Target {}", id),
                     body: "Body".to_string(),
                     priority: crate::types::Priority::P1,
+                    estimate: None,
                     category: "TEST".to_string(),
                     tags: None,
                     derives_from: None,
@@ -3017,6 +3619,7 @@ mod tests {
             title: "Target".to_string(),
             body: "Body".to_string(),
             priority: crate::types::Priority::P1,
+            estimate: None,
             category: "TEST".to_string(),
             tags: None,
             derives_from: None,
@@ -3072,6 +3675,7 @@ mod tests {
                 title: "Target".to_string(),
                 body: "Body".to_string(),
                 priority: crate::types::Priority::P1,
+                estimate: None,
                 category: "TEST".to_string(),
                 tags: None,
                 derives_from: None,
@@ -3089,6 +3693,7 @@ mod tests {
                 title: "Source".to_string(),
                 body: "Body".to_string(),
                 priority: crate::types::Priority::P1,
+                estimate: None,
                 category: "TEST".to_string(),
                 tags: None,
                 derives_from: None,
@@ -3114,11 +3719,13 @@ mod tests {
                 body: None,
                 status: None,
                 priority: None,
+                estimate: None,
                 confidence: None,
                 tags: None,
                 category: None,
                 files: None,
                 test_command: None,
+                bump: None,
             },
         )
         .unwrap();
@@ -3137,11 +3744,13 @@ mod tests {
                 body: None,
                 status: None,
                 priority: None,
+                estimate: None,
                 confidence: None,
                 tags: None,
                 category: None,
                 files: None,
                 test_command: None,
+                bump: None,
             },
         )
         .unwrap();
@@ -3165,6 +3774,7 @@ mod tests {
                 title: "Target".to_string(),
                 body: "Body".to_string(),
                 priority: crate::types::Priority::P1,
+                estimate: None,
                 category: "TEST".to_string(),
                 tags: None,
                 derives_from: None,
@@ -3182,6 +3792,7 @@ mod tests {
                 title: "Source".to_string(),
                 body: "Body".to_string(),
                 priority: crate::types::Priority::P1,
+                estimate: None,
                 category: "TEST".to_string(),
                 tags: None,
                 derives_from: None,
@@ -3201,11 +3812,13 @@ mod tests {
                 body: None,
                 status: None,
                 priority: None,
+                estimate: None,
                 confidence: None,
                 tags: None,
                 category: None,
                 files: None,
                 test_command: None,
+                bump: None,
             },
         )
         .unwrap();
@@ -3242,6 +3855,7 @@ mod tests {
                 title: "No edges".to_string(),
                 body: "Body".to_string(),
                 priority: crate::types::Priority::P1,
+                estimate: None,
                 category: "TEST".to_string(),
                 tags: None,
                 derives_from: None,
@@ -3256,4 +3870,552 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("has no edges"));
     }
+
+    fn setup_remove_node_lattice(root: &Path) {
+        init_lattice(root, false).unwrap();
+        for id in ["REQ-RMN-001", "REQ-RMN-002"] {
+            add_requirement(
+                root,
+                AddRequirementOptions {
+                    id: id.to_string(),
+                    title: format!("Requirement {}", id),
+                    body: "Body".to_string(),
+                    priority: crate::types::Priority::P1,
+                    estimate: None,
+                    category: "TEST".to_string(),
+                    tags: None,
+                    derives_from: None,
+                    depends_on: None,
+                    status: crate::types::Status::Active,
+                    created_by: "test".to_string(),
+                },
+            )
+            .unwrap();
+        }
+        add_implementation(
+            root,
+            AddImplementationOptions {
+                id: "IMP-RMN-001".to_string(),
+                title: "Impl".to_string(),
+                body: "Impl".to_string(),
+                language: None,
+                files: None,
+                test_command: None,
+                satisfies: Some(vec!["REQ-RMN-001".to_string()]),
+                status: crate::types::Status::Active,
+                created_by: "test".to_string(),
+            },
+        )
+        .unwrap();
+    }
+
+    /// Remove a node the way the CLI does, passing the edges that target it.
+    fn remove_with_incoming(
+        root: &Path,
+        options: RemoveNodeOptions,
+    ) -> Result<RemoveNodeResult, StorageError> {
+        let incoming = crate::graph::find_incoming_edges(root, &options.node_id)?;
+        remove_node(root, options, incoming)
+    }
+
+    #[test]
+    fn test_remove_node_archive_and_retarget() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        setup_remove_node_lattice(root);
+
+        let result = remove_with_incoming(
+            root,
+            RemoveNodeOptions {
+                node_id: "REQ-RMN-001".to_string(),
+                archive: true,
+                incoming: IncomingEdgeAction::Retarget("REQ-RMN-002".to_string()),
+                dry_run: false,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(result.incoming.len(), 1);
+        assert_eq!(result.incoming[0].from_id, "IMP-RMN-001");
+        assert_eq!(result.incoming[0].edge_type, "satisfies");
+        assert_eq!(result.edges_updated, 1);
+        assert!(!result.path.exists());
+
+        let archived = load_node(result.archived_to.as_ref().unwrap()).unwrap();
+        assert_eq!(archived.status, crate::types::Status::Deprecated);
+        assert!(
+            result
+                .archived_to
+                .unwrap()
+                .starts_with(root.join(LATTICE_DIR).join(ARCHIVE_DIR))
+        );
+
+        // Archived node is gone from the graph; the edge now points at the replacement
+        assert!(find_node_path(root, "REQ-RMN-001").is_err());
+        let imp = load_node(&find_node_path(root, "IMP-RMN-001").unwrap()).unwrap();
+        let satisfies = imp.edges.unwrap().satisfies.unwrap();
+        assert_eq!(satisfies[0].target, "REQ-RMN-002");
+        assert_eq!(satisfies[0].version.as_deref(), Some("1.0.0"));
+    }
+
+    #[test]
+    fn test_remove_node_hard_delete_removes_edges() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        setup_remove_node_lattice(root);
+
+        let result = remove_with_incoming(
+            root,
+            RemoveNodeOptions {
+                node_id: "REQ-RMN-001".to_string(),
+                archive: false,
+                incoming: IncomingEdgeAction::Remove,
+                dry_run: false,
+            },
+        )
+        .unwrap();
+
+        assert!(result.archived_to.is_none());
+        assert!(!root.join(LATTICE_DIR).join(ARCHIVE_DIR).exists());
+        let imp = load_node(&find_node_path(root, "IMP-RMN-001").unwrap()).unwrap();
+        assert!(imp.edges.unwrap().satisfies.is_none());
+    }
+
+    #[test]
+    fn test_remove_node_dry_run_changes_nothing() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        setup_remove_node_lattice(root);
+
+        let result = remove_with_incoming(
+            root,
+            RemoveNodeOptions {
+                node_id: "REQ-RMN-001".to_string(),
+                archive: true,
+                incoming: IncomingEdgeAction::Remove,
+                dry_run: true,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(result.incoming.len(), 1);
+        assert_eq!(result.edges_updated, 0);
+        assert!(result.path.exists());
+        assert!(!result.archived_to.unwrap().exists());
+        assert_eq!(
+            crate::graph::find_incoming_edges(root, "REQ-RMN-001")
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_remove_node_follows_aliases() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        setup_remove_node_lattice(root);
+
+        // REQ-RMN-001 was once REQ-OLD, and the implementation still uses both IDs
+        let req_path = find_node_path(root, "REQ-RMN-001").unwrap();
+        let mut req = load_node(&req_path).unwrap();
+        req.aliases = Some(vec!["REQ-OLD".to_string()]);
+        save_node(&req_path, &req).unwrap();
+        let imp_path = find_node_path(root, "IMP-RMN-001").unwrap();
+        let mut imp = load_node(&imp_path).unwrap();
+        let satisfies = imp.edges.as_mut().unwrap().satisfies.as_mut().unwrap();
+        satisfies.push(EdgeReference {
+            target: "REQ-OLD".to_string(),
+            version: Some("1.0.0".to_string()),
+            rationale: None,
+        });
+        save_node(&imp_path, &imp).unwrap();
+
+        let result = remove_with_incoming(
+            root,
+            RemoveNodeOptions {
+                node_id: "REQ-RMN-001".to_string(),
+                archive: false,
+                incoming: IncomingEdgeAction::Retarget("REQ-RMN-002".to_string()),
+                dry_run: false,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(result.incoming.len(), 1);
+        assert_eq!(result.edges_updated, 1);
+        let imp = load_node(&imp_path).unwrap();
+        let targets: Vec<String> = imp
+            .edges
+            .unwrap()
+            .satisfies
+            .unwrap()
+            .into_iter()
+            .map(|e| e.target)
+            .collect();
+        assert_eq!(targets, vec!["REQ-RMN-002"]);
+    }
+
+    #[test]
+    fn test_remove_node_retarget_to_missing_node_fails() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        setup_remove_node_lattice(root);
+
+        let result = remove_with_incoming(
+            root,
+            RemoveNodeOptions {
+                node_id: "REQ-RMN-001".to_string(),
+                archive: true,
+                incoming: IncomingEdgeAction::Retarget("REQ-NOPE".to_string()),
+                dry_run: false,
+            },
+        );
+        assert!(matches!(result, Err(StorageError::NodeNotFound(_))));
+        assert!(find_node_path(root, "REQ-RMN-001").is_ok());
+    }
+
+    #[test]
+    fn test_rename_node_rewrites_incoming_edges() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        setup_remove_node_lattice(root);
+
+        let result = rename_node(
+            root,
+            RenameNodeOptions {
+                old_id: "REQ-RMN-001".to_string(),
+                new_id: "REQ-RMN-010".to_string(),
+            },
+        )
+        .unwrap();
+
+        assert!(!result.old_path.exists());
+        assert!(
+            result
+                .new_path
+                .ends_with("requirements/test/010-requirement-req-rmn-001.yaml")
+        );
+        assert_eq!(result.updated_nodes, vec![("IMP-RMN-001".to_string(), 1)]);
+
+        let renamed = load_node(&result.new_path).unwrap();
+        assert_eq!(renamed.id, "REQ-RMN-010");
+        assert_eq!(renamed.aliases, Some(vec!["REQ-RMN-001".to_string()]));
+        assert_eq!(renamed.version, "1.0.0");
+        assert!(renamed.answers_to("REQ-RMN-001"));
+
+        let imp = load_node(&find_node_path(root, "IMP-RMN-001").unwrap()).unwrap();
+        let satisfies = &imp.edges.unwrap().satisfies.unwrap()[0];
+        assert_eq!(satisfies.target, "REQ-RMN-010");
+        assert_eq!(satisfies.version.as_deref(), Some("1.0.0"));
+    }
+
+    #[test]
+    fn test_rename_node_moves_category_with_id() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        setup_remove_node_lattice(root);
+        let mut node = load_node(&find_node_path(root, "REQ-RMN-002").unwrap()).unwrap();
+        node.category = Some("RMN".to_string());
+        save_node(&find_node_path(root, "REQ-RMN-002").unwrap(), &node).unwrap();
+
+        let result = rename_node(
+            root,
+            RenameNodeOptions {
+                old_id: "REQ-RMN-002".to_string(),
+                new_id: "REQ-CORE-002".to_string(),
+            },
+        )
+        .unwrap();
+
+        assert!(
+            result
+                .new_path
+                .ends_with("requirements/core/002-requirement-req-rmn-002.yaml")
+        );
+        let renamed = load_node(&result.new_path).unwrap();
+        assert_eq!(renamed.category.as_deref(), Some("CORE"));
+    }
+
+    #[test]
+    fn test_rename_node_rejects_conflicts() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        setup_remove_node_lattice(root);
+
+        let existing = rename_node(
+            root,
+            RenameNodeOptions {
+                old_id: "REQ-RMN-001".to_string(),
+                new_id: "REQ-RMN-002".to_string(),
+            },
+        );
+        assert!(matches!(existing, Err(StorageError::AlreadyExists(_))));
+
+        let wrong_prefix = rename_node(
+            root,
+            RenameNodeOptions {
+                old_id: "REQ-RMN-001".to_string(),
+                new_id: "THX-RMN-001".to_string(),
+            },
+        );
+        assert!(matches!(wrong_prefix, Err(StorageError::InvalidField(_))));
+    }
+
+    #[test]
+    fn test_merge_nodes_unions_and_supersedes() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        setup_remove_node_lattice(root);
+
+        let drop_path = find_node_path(root, "REQ-RMN-002").unwrap();
+        let mut dropped = load_node(&drop_path).unwrap();
+        dropped.tags = Some(vec!["auth".to_string()]);
+        dropped.edges = Some(Edges {
+            depends_on: Some(vec![EdgeReference {
+                target: "REQ-RMN-001".to_string(),
+                version: Some("1.0.0".to_string()),
+                rationale: None,
+            }]),
+            ..Default::default()
+        });
+        dropped.acceptance = Some(vec![crate::types::AcceptanceTest {
+            id: "AT-1".to_string(),
+            given: "a user".to_string(),
+            when: "they log in".to_string(),
+            then: "a session is created".to_string(),
+            verification: None,
+        }]);
+        save_node(&drop_path, &dropped).unwrap();
+        add_implementation(
+            root,
+            AddImplementationOptions {
+                id: "IMP-RMN-002".to_string(),
+                title: "Impl".to_string(),
+                body: "Impl".to_string(),
+                language: None,
+                files: None,
+                test_command: None,
+                satisfies: Some(vec!["REQ-RMN-002".to_string()]),
+                status: crate::types::Status::Active,
+                created_by: "test".to_string(),
+            },
+        )
+        .unwrap();
+
+        let result = merge_nodes(
+            root,
+            MergeNodesOptions {
+                keep_id: "REQ-RMN-001".to_string(),
+                drop_id: "REQ-RMN-002".to_string(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(result.new_version, "1.0.1");
+        assert_eq!(result.tags_added, 1);
+        assert_eq!(result.acceptance_added, 1);
+        // The dropped node's depends_on edge pointed at the survivor, so it is not copied
+        assert_eq!(result.edges_added, 0);
+        assert_eq!(result.updated_nodes, vec![("IMP-RMN-002".to_string(), 1)]);
+
+        let keep = load_node(&result.keep_path).unwrap();
+        assert_eq!(keep.tags, Some(vec!["auth".to_string()]));
+        let supersedes = keep.edges.unwrap().supersedes.unwrap();
+        assert_eq!(supersedes[0].target, "REQ-RMN-002");
+
+        let dropped = load_node(&result.drop_path).unwrap();
+        assert_eq!(dropped.status, crate::types::Status::Superseded);
+
+        let imp = load_node(&find_node_path(root, "IMP-RMN-002").unwrap()).unwrap();
+        let satisfies = imp.edges.unwrap().satisfies.unwrap();
+        assert_eq!(satisfies[0].target, "REQ-RMN-001");
+        assert_eq!(satisfies[0].version.as_deref(), Some("1.0.1"));
+    }
+
+    #[test]
+    fn test_merge_nodes_repins_edges_to_aliases() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        setup_remove_node_lattice(root);
+
+        let drop_path = find_node_path(root, "REQ-RMN-002").unwrap();
+        let mut dropped = load_node(&drop_path).unwrap();
+        dropped.version = "2.4.0".to_string();
+        dropped.aliases = Some(vec!["REQ-OLD-002".to_string()]);
+        save_node(&drop_path, &dropped).unwrap();
+
+        // An edge still naming the dropped node's former ID, pinned to its history
+        let imp_path = find_node_path(root, "IMP-RMN-001").unwrap();
+        let mut imp = load_node(&imp_path).unwrap();
+        imp.edges.as_mut().unwrap().validates = Some(vec![EdgeReference {
+            target: "REQ-OLD-002".to_string(),
+            version: Some("2.4.0".to_string()),
+            rationale: None,
+        }]);
+        save_node(&imp_path, &imp).unwrap();
+
+        let result = merge_nodes(
+            root,
+            MergeNodesOptions {
+                keep_id: "REQ-RMN-001".to_string(),
+                drop_id: "REQ-RMN-002".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(result.updated_nodes, vec![("IMP-RMN-001".to_string(), 1)]);
+
+        let edges = load_node(&imp_path).unwrap().edges.unwrap();
+        let validates = edges.validates.unwrap();
+        assert_eq!(validates[0].target, "REQ-RMN-001");
+        assert_eq!(
+            validates[0].version.as_deref(),
+            Some(result.new_version.as_str())
+        );
+        // Edges already pointing at the kept node keep their pin
+        assert_eq!(
+            edges.satisfies.unwrap()[0].version.as_deref(),
+            Some("1.0.0")
+        );
+    }
+
+    #[test]
+    fn test_merge_nodes_collapses_duplicate_incoming_edges() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        setup_remove_node_lattice(root);
+        add_edge(
+            root,
+            AddEdgeOptions {
+                from_id: "IMP-RMN-001".to_string(),
+                edge_type: "satisfies".to_string(),
+                to_id: "REQ-RMN-002".to_string(),
+                rationale: None,
+            },
+        )
+        .unwrap();
+
+        merge_nodes(
+            root,
+            MergeNodesOptions {
+                keep_id: "REQ-RMN-001".to_string(),
+                drop_id: "REQ-RMN-002".to_string(),
+            },
+        )
+        .unwrap();
+
+        let imp = load_node(&find_node_path(root, "IMP-RMN-001").unwrap()).unwrap();
+        let satisfies = imp.edges.unwrap().satisfies.unwrap();
+        assert_eq!(satisfies.len(), 1);
+        assert_eq!(satisfies[0].target, "REQ-RMN-001");
+    }
+
+    #[test]
+    fn test_merge_nodes_rejects_mismatched_types() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        setup_remove_node_lattice(root);
+
+        let result = merge_nodes(
+            root,
+            MergeNodesOptions {
+                keep_id: "REQ-RMN-001".to_string(),
+                drop_id: "IMP-RMN-001".to_string(),
+            },
+        );
+        assert!(matches!(result, Err(StorageError::InvalidField(_))));
+    }
+
+    fn edit_opts(node_id: &str) -> EditNodeOptions {
+        EditNodeOptions {
+            node_id: node_id.to_string(),
+            title: None,
+            body: None,
+            status: None,
+            priority: None,
+            estimate: None,
+            confidence: None,
+            tags: None,
+            category: None,
+            files: None,
+            test_command: None,
+            bump: None,
+        }
+    }
+
+    #[test]
+    fn test_edit_node_explicit_bump_level() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        setup_remove_node_lattice(root);
+
+        let path = edit_node(
+            root,
+            EditNodeOptions {
+                body: Some("Rewritten".to_string()),
+                bump: Some(BumpLevel::Major),
+                ..edit_opts("REQ-RMN-001")
+            },
+        )
+        .unwrap();
+        assert_eq!(load_node(&path).unwrap().version, "2.0.0");
+
+        let path = edit_node(
+            root,
+            EditNodeOptions {
+                body: Some("Extended".to_string()),
+                bump: Some(BumpLevel::Minor),
+                ..edit_opts("REQ-RMN-001")
+            },
+        )
+        .unwrap();
+        assert_eq!(load_node(&path).unwrap().version, "2.1.0");
+    }
+
+    #[test]
+    fn test_edit_node_version_policy_from_config() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        setup_remove_node_lattice(root);
+        let config_path = root.join(LATTICE_DIR).join("config.yaml");
+        let mut config = fs::read_to_string(&config_path).unwrap();
+        config.push_str("version_policy:\n  body: minor\n  title: patch\n");
+        fs::write(&config_path, config).unwrap();
+
+        let path = edit_node(
+            root,
+            EditNodeOptions {
+                title: Some("Renamed".to_string()),
+                ..edit_opts("REQ-RMN-001")
+            },
+        )
+        .unwrap();
+        assert_eq!(load_node(&path).unwrap().version, "1.0.1");
+
+        let path = edit_node(
+            root,
+            EditNodeOptions {
+                title: Some("Renamed again".to_string()),
+                body: Some("New body".to_string()),
+                ..edit_opts("REQ-RMN-001")
+            },
+        )
+        .unwrap();
+        assert_eq!(load_node(&path).unwrap().version, "1.1.0");
+    }
+
+    #[test]
+    fn test_bump_level_from_policy() {
+        let mut policy = BTreeMap::new();
+        assert_eq!(BumpLevel::from_policy(&policy, &["body"]), BumpLevel::Patch);
+        policy.insert("default".to_string(), BumpLevel::Minor);
+        policy.insert("tags".to_string(), BumpLevel::Patch);
+        assert_eq!(BumpLevel::from_policy(&policy, &["tags"]), BumpLevel::Patch);
+        assert_eq!(
+            BumpLevel::from_policy(&policy, &["tags", "body"]),
+            BumpLevel::Minor
+        );
+        assert_eq!("MAJOR".parse::<BumpLevel>(), Ok(BumpLevel::Major));
+        assert!("huge".parse::<BumpLevel>().is_err());
+    }
 }
@@ -0,0 +1,182 @@
+//! Find where a symbol is defined in a source file, for checking the
+//! `functions` listed on implementation file bindings.
+//!
+//! This is a line-based heuristic rather than a parser: a name counts as
+//! defined when it follows a definition keyword (`fn`, `def`, `func`,
+//! `function`, `class`, ...) or, in TypeScript, opens a method definition.
+
+use std::path::Path;
+
+/// Languages whose definitions can be recognised.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    Rust,
+    TypeScript,
+    Python,
+    Go,
+}
+
+impl Language {
+    /// Language of a file by extension; JavaScript is read as TypeScript.
+    pub fn from_path(path: &Path) -> Option<Language> {
+        match path.extension()?.to_str()? {
+            "rs" => Some(Language::Rust),
+            "ts" | "tsx" | "mts" | "cts" | "js" | "jsx" | "mjs" | "cjs" => {
+                Some(Language::TypeScript)
+            }
+            "py" | "pyi" => Some(Language::Python),
+            "go" => Some(Language::Go),
+            _ => None,
+        }
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            Language::Rust => &[
+                "fn",
+                "struct",
+                "enum",
+                "trait",
+                "type",
+                "const",
+                "static",
+                "mod",
+                "union",
+                "macro_rules",
+            ],
+            Language::TypeScript => &[
+                "function",
+                "class",
+                "interface",
+                "type",
+                "enum",
+                "const",
+                "let",
+                "var",
+                "namespace",
+            ],
+            Language::Python => &["def", "class"],
+            Language::Go => &["func", "type", "var", "const"],
+        }
+    }
+}
+
+/// Modifiers that may precede a TypeScript method name.
+const TS_MODIFIERS: &[&str] = &[
+    "public",
+    "private",
+    "protected",
+    "static",
+    "async",
+    "readonly",
+    "abstract",
+    "override",
+    "get",
+    "set",
+    "export",
+    "default",
+];
+
+/// Whether `content` defines `name`. Qualified names such as
+/// `Type::method` or `Class.method` are looked up by their last segment.
+pub fn defines(language: Language, content: &str, name: &str) -> bool {
+    let name = name
+        .rsplit("::")
+        .next()
+        .and_then(|n| n.rsplit('.').next())
+        .unwrap_or(name)
+        .trim_end_matches("()");
+    if name.is_empty() {
+        return false;
+    }
+    content
+        .lines()
+        .any(|line| defines_on_line(language, line, name))
+}
+
+fn defines_on_line(language: Language, line: &str, name: &str) -> bool {
+    let mut line = line.trim_start();
+    // Go methods: skip the receiver in `func (r *T) Name(`
+    if language == Language::Go
+        && let Some(rest) = line.strip_prefix("func (")
+        && let Some(close) = rest.find(')')
+    {
+        return identifiers(&rest[close + 1..]).first() == Some(&name);
+    }
+
+    let tokens = identifiers(line);
+    if tokens
+        .windows(2)
+        .any(|pair| language.keywords().contains(&pair[0]) && pair[1] == name)
+    {
+        return true;
+    }
+
+    if language == Language::TypeScript {
+        // Method shorthand: `async name(args) {`
+        let mut rest = line;
+        while let Some(modifier) = TS_MODIFIERS.iter().find(|m| {
+            rest.strip_prefix(**m)
+                .is_some_and(|r| r.starts_with(char::is_whitespace))
+        }) {
+            rest = rest[modifier.len()..].trim_start();
+        }
+        line = rest;
+        if let Some(after) = line.strip_prefix(name) {
+            let after = after.trim_start();
+            return (after.starts_with('(') || after.starts_with('<'))
+                && line.trim_end().ends_with('{');
+        }
+    }
+    false
+}
+
+/// Identifier tokens of a line, in order.
+fn identifiers(line: &str) -> Vec<&str> {
+    line.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_from_path() {
+        assert_eq!(
+            Language::from_path(Path::new("src/lib.rs")),
+            Some(Language::Rust)
+        );
+        assert_eq!(
+            Language::from_path(Path::new("web/app.tsx")),
+            Some(Language::TypeScript)
+        );
+        assert_eq!(Language::from_path(Path::new("Makefile")), None);
+    }
+
+    #[test]
+    fn test_defines() {
+        let rust = "pub struct Index;\nimpl Index {\n    pub(crate) async fn build_node_index<'a>(x: &str) {}\n}\nmacro_rules! bail { () => {} }\n";
+        assert!(defines(Language::Rust, rust, "build_node_index"));
+        assert!(defines(Language::Rust, rust, "Index::build_node_index"));
+        assert!(defines(Language::Rust, rust, "Index"));
+        assert!(defines(Language::Rust, rust, "bail"));
+        assert!(!defines(Language::Rust, rust, "build_node"));
+
+        let ts = "export async function loadNodes() {}\nexport const parse = (s: string) => s;\nclass Store {\n  private async save(node: Node): Promise<void> {\n    save(node);\n  }\n}\n";
+        assert!(defines(Language::TypeScript, ts, "loadNodes"));
+        assert!(defines(Language::TypeScript, ts, "parse"));
+        assert!(defines(Language::TypeScript, ts, "Store.save"));
+        assert!(!defines(Language::TypeScript, ts, "node"));
+
+        let py = "class Lattice:\n    async def load(self):\n        return helper()\n";
+        assert!(defines(Language::Python, py, "Lattice.load"));
+        assert!(!defines(Language::Python, py, "helper"));
+
+        let go = "func (s *Store) Save(n Node) error {\n\treturn write(n)\n}\nfunc write[T any](n T) error { return nil }\n";
+        assert!(defines(Language::Go, go, "Save"));
+        assert!(defines(Language::Go, go, "write"));
+        assert!(!defines(Language::Go, go, "Store"));
+    }
+}
@@ -123,6 +123,16 @@ impl EdgeReference {
     pub fn version_or_default(&self) -> &str {
         self.version.as_deref().unwrap_or("1.0.0")
     }
+
+    /// An edge to `target` bound at `version`, for unit tests.
+    #[cfg(test)]
+    pub(crate) fn test_edge(target: &str, version: &str) -> Self {
+        EdgeReference {
+            target: target.to_string(),
+            version: Some(version.to_string()),
+            rationale: None,
+        }
+    }
 }
 
 /// Edges container for different relationship types.
@@ -156,6 +166,106 @@ pub struct Edges {
     pub grounded_in: Option<Vec<EdgeReference>>,
 }
 
+impl Edges {
+    /// Point every edge (of any type) targeting `old` at `new` instead,
+    /// keeping rationales. Pinned versions are kept, or re-pinned to
+    /// `version` when given. Returns the number rewritten.
+    pub fn retarget(&mut self, old: &str, new: &str, version: Option<&str>) -> usize {
+        let mut count = 0;
+        macro_rules! retarget_in {
+            ($field:ident) => {
+                if let Some(vec) = &mut self.$field {
+                    for edge in vec.iter_mut().filter(|e| e.target == old) {
+                        edge.target = new.to_string();
+                        if let Some(version) = version {
+                            edge.version = Some(version.to_string());
+                        }
+                        count += 1;
+                    }
+                }
+            };
+        }
+        retarget_in!(supported_by);
+        retarget_in!(derives_from);
+        retarget_in!(depends_on);
+        retarget_in!(satisfies);
+        retarget_in!(extends);
+        retarget_in!(reveals_gap_in);
+        retarget_in!(challenges);
+        retarget_in!(validates);
+        retarget_in!(conflicts_with);
+        retarget_in!(supersedes);
+        retarget_in!(rebuts);
+        retarget_in!(concedes);
+        retarget_in!(grounded_in);
+        count
+    }
+
+    /// Add every edge from `other` whose (type, target) is not already
+    /// present. Returns the number of edges added.
+    pub fn union(&mut self, other: &Edges) -> usize {
+        let mut count = 0;
+        macro_rules! union_in {
+            ($field:ident) => {
+                if let Some(incoming) = other.$field.as_ref().filter(|v| !v.is_empty()) {
+                    let vec = self.$field.get_or_insert_with(Vec::new);
+                    for edge in incoming {
+                        if !vec.iter().any(|e| e.target == edge.target) {
+                            vec.push(edge.clone());
+                            count += 1;
+                        }
+                    }
+                }
+            };
+        }
+        union_in!(supported_by);
+        union_in!(derives_from);
+        union_in!(depends_on);
+        union_in!(satisfies);
+        union_in!(extends);
+        union_in!(reveals_gap_in);
+        union_in!(challenges);
+        union_in!(validates);
+        union_in!(conflicts_with);
+        union_in!(supersedes);
+        union_in!(rebuts);
+        union_in!(concedes);
+        union_in!(grounded_in);
+        count
+    }
+
+    /// Drop every edge (of any type) targeting `target`. Returns the number removed.
+    pub fn remove_target(&mut self, target: &str) -> usize {
+        let mut count = 0;
+        macro_rules! remove_in {
+            ($field:ident) => {
+                if let Some(vec) = &mut self.$field {
+                    let before = vec.len();
+                    vec.retain(|e| e.target != target);
+                    count += before - vec.len();
+                    if vec.is_empty() {
+                        self.$field = None;
+                    }
+                }
+            };
+        }
+        remove_in!(supported_by);
+        remove_in!(derives_from);
+        remove_in!(depends_on);
+        remove_in!(satisfies);
+        remove_in!(extends);
+        remove_in!(reveals_gap_in);
+        remove_in!(challenges);
+        remove_in!(validates);
+        remove_in!(conflicts_with);
+        remove_in!(supersedes);
+        remove_in!(rebuts);
+        remove_in!(concedes);
+        remove_in!(grounded_in);
+        count
+    }
+}
+
 /// Acceptance test for requirements.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AcceptanceTest {
@@ -245,6 +355,9 @@ pub struct ImplementationMeta {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LatticeNode {
     pub id: String,
+    /// Former IDs of this node, recorded by `lattice rename`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aliases: Option<Vec<String>>,
     #[serde(rename = "type")]
     pub node_type: NodeType,
     pub title: String,
@@ -259,6 +372,9 @@ pub struct LatticeNode {
     // Optional fields depending on node type
     #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<Priority>,
+    /// Size of the work, in whatever unit the team plans in (points or days).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub category: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -267,6 +383,9 @@ pub struct LatticeNode {
     pub acceptance: Option<Vec<AcceptanceTest>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub visibility: Option<String>,
+    /// Lint rule IDs suppressed for this node (`all` suppresses every rule).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lint_ignore: Option<Vec<String>>,
 
     // Resolution status (for requirements)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -340,6 +459,71 @@ impl LatticeNode {
         }
         refs
     }
+
+    /// Whether this node is identified by `id`, either directly or via an alias.
+    pub fn answers_to(&self, id: &str) -> bool {
+        self.id == id
+            || self
+                .aliases
+                .as_ref()
+                .is_some_and(|a| a.iter().any(|x| x == id))
+    }
+
+    /// Get all edge references from this node, paired with their edge type name.
+    pub fn typed_edges(&self) -> Vec<(&'static str, &EdgeReference)> {
+        let mut refs = Vec::new();
+        if let Some(edges) = &self.edges {
+            macro_rules! collect {
+                ($field:ident) => {
+                    if let Some(e) = &edges.$field {
+                        refs.extend(e.iter().map(|r| (stringify!($field), r)));
+                    }
+                };
+            }
+            collect!(supported_by);
+            collect!(derives_from);
+            collect!(depends_on);
+            collect!(satisfies);
+            collect!(extends);
+            collect!(reveals_gap_in);
+            collect!(challenges);
+            collect!(validates);
+            collect!(conflicts_with);
+            collect!(supersedes);
+            collect!(rebuts);
+            collect!(concedes);
+            collect!(grounded_in);
+        }
+        refs
+    }
+
+    /// A bare active node for unit tests: titled with its ID, version
+    /// 1.0.0, no edges. Override fields with struct update syntax.
+    #[cfg(test)]
+    pub(crate) fn test_node(id: &str, node_type: NodeType) -> Self {
+        LatticeNode {
+            id: id.to_string(),
+            aliases: None,
+            node_type,
+            title: id.to_string(),
+            body: String::new(),
+            status: Status::Active,
+            version: "1.0.0".to_string(),
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            created_by: "test".to_string(),
+            requested_by: None,
+            priority: None,
+            estimate: None,
+            category: None,
+            tags: None,
+            acceptance: None,
+            visibility: None,
+            lint_ignore: None,
+            resolution: None,
+            meta: None,
+            edges: None,
+        }
+    }
 }
 
 /// Node index type alias.